@@ -22,9 +22,9 @@ pub fn derive_schema(input: proc_macro::TokenStream) -> proc_macro::TokenStream
 
         quote::quote! {
             #[allow(non_upper_case_globals)]
-            #vis const #col_name: ::xql::item::ColumnRef<'static> = ::xql::item::ColumnRef::TableColumn(
-                xql::item::Ident(stringify!(#table_name)),
-                xql::item::Ident(stringify!(#col_name)),
+            #vis const #col_name: ::qians_xql::item::ColumnRef<'static> = ::qians_xql::item::ColumnRef::TableColumn(
+                ::qians_xql::item::Ident::new(stringify!(#table_name)),
+                ::qians_xql::item::Ident::new(stringify!(#col_name)),
             );
         }
     });
@@ -34,32 +34,32 @@ pub fn derive_schema(input: proc_macro::TokenStream) -> proc_macro::TokenStream
     let column_array = fields.iter().map(|f| {
         let col_name = &f.ident;
         quote::quote! {
-            ::xql::item::ColumnRef::TableColumn(
-                xql::item::Ident(stringify!(#table_name)),
-                xql::item::Ident(stringify!(#col_name)),
+            ::qians_xql::item::ColumnRef::TableColumn(
+                ::qians_xql::item::Ident::new(stringify!(#table_name)),
+                ::qians_xql::item::Ident::new(stringify!(#col_name)),
             )
         }
     });
 
     let expanded = quote::quote! {
         #[allow(non_upper_case_globals)]
-        #vis const #name: ::xql::item::TableRef<'static> = ::xql::item::TableRef::Table(
-            ::xql::item::Ident(stringify!(#table_name)),
+        #vis const #name: ::qians_xql::item::TableRef<'static> = ::qians_xql::item::TableRef::Table(
+            ::qians_xql::item::Ident::new(stringify!(#table_name)),
         );
 
         impl #name {
             #(#column_names)*
         }
 
-        impl ::xql::Schema<#num_cols> for #name {
+        impl ::qians_xql::Schema<#num_cols> for #name {
             #[inline]
-            fn table() -> ::xql::item::TableRef<'static> {
-                ::xql::item::TableRef::Table(
-                    ::xql::item::Ident(stringify!(#table_name)),
+            fn table() -> ::qians_xql::item::TableRef<'static> {
+                ::qians_xql::item::TableRef::Table(
+                    ::qians_xql::item::Ident::new(stringify!(#table_name)),
                 )
             }
 
-            fn columns() -> [::xql::item::ColumnRef<'static>; #num_cols] {
+            fn columns() -> [::qians_xql::item::ColumnRef<'static>; #num_cols] {
                 [#(#column_array,)*]
             }
         }
@@ -67,3 +67,90 @@ pub fn derive_schema(input: proc_macro::TokenStream) -> proc_macro::TokenStream
 
     proc_macro::TokenStream::from(expanded)
 }
+
+/// Column name of a field, or `None` if it is skipped.
+fn column_name(field: &syn::Field) -> syn::Result<Option<String>> {
+    let mut name = field.ident.as_ref().map(ToString::to_string);
+    let mut skip = false;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("column"))
+    {
+        let list = match attr.parse_meta()? {
+            syn::Meta::List(list) => list,
+            meta => return Err(syn::Error::new_spanned(meta, "expected `column(...)`")),
+        };
+        for nested in list.nested {
+            match nested {
+                syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("skip") => {
+                    skip = true;
+                }
+                syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                    path,
+                    lit: syn::Lit::Str(rename),
+                    ..
+                })) if path.is_ident("rename") => {
+                    name = Some(rename.value());
+                }
+                nested => {
+                    return Err(syn::Error::new_spanned(
+                        nested,
+                        "expected `skip` or `rename = \"...\"`",
+                    ))
+                }
+            }
+        }
+    }
+    Ok(if skip { None } else { name })
+}
+
+#[proc_macro_derive(Columns, attributes(column))]
+pub fn derive_columns(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match &input.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(syn::FieldsNamed { named, .. }),
+            ..
+        }) => named,
+        _ => {
+            return syn::Error::new_spanned(&input, "`Columns` requires a struct with named fields")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    let mut column_names = Vec::new();
+    let mut field_names = Vec::new();
+    for field in fields {
+        match column_name(field) {
+            Ok(Some(column)) => {
+                column_names.push(column);
+                field_names.push(&field.ident);
+            }
+            Ok(None) => {}
+            Err(err) => return err.to_compile_error().into(),
+        }
+    }
+    let num_cols = column_names.len();
+
+    let expanded = quote::quote! {
+        impl #impl_generics ::qians_xql::schema::Columns<#num_cols> for #name #ty_generics #where_clause {
+            fn column_names() -> [::qians_xql::item::Ident<'static>; #num_cols] {
+                [#(::qians_xql::item::Ident::new(#column_names),)*]
+            }
+
+            fn as_row(&self) -> ::qians_xql::item::Row<'_> {
+                let row: [::qians_xql::expr::Expr<'_>; #num_cols] = [
+                    #(::qians_xql::expr::Expr::from(&self.#field_names),)*
+                ];
+                ::qians_xql::item::Row::from(row)
+            }
+        }
+    };
+
+    proc_macro::TokenStream::from(expanded)
+}
@@ -2,6 +2,7 @@ use crate::expr::Expr;
 use crate::item::Field;
 use crate::item::Ident;
 use crate::item::Order;
+use crate::item::TypeName;
 
 macro_rules! gen_method {
     ($method:ident) => {
@@ -14,6 +15,7 @@ macro_rules! gen_method {
         #[doc = concat!("assert_eq!(1.", stringify!($method), "(2), ", stringify!($method), "(1, 2));")]
         #[doc = "```"]
         #[inline]
+        #[allow(clippy::wrong_self_convention)]
         fn $method<R: Into<$crate::expr::Expr<'a>>>(self, right: R) -> $crate::expr::Expr<'a> {
             $crate::ops::$method(self, right)
         }
@@ -56,10 +58,15 @@ pub trait ExprExt<'a>: Sized + Into<Expr<'a>> {
     gen_method!(le);
     gen_method!(le as less_equal);
 
+    gen_method!(is_distinct_from);
+    gen_method!(is_not_distinct_from);
+
     gen_method!(and);
     gen_method!(or);
     gen_method!(like);
     gen_method!(ilike);
+    gen_method!(not_like);
+    gen_method!(not_ilike);
 
     /// A short hand for [`qians_xql::ops::as_field`](crate::ops::as_field).
     ///
@@ -87,6 +94,96 @@ pub trait ExprExt<'a>: Sized + Into<Expr<'a>> {
         crate::ops::isnull(self)
     }
 
+    /// A short hand for [`qians_xql::ops::between`](crate::ops::between).
+    ///
+    /// ```
+    /// use qians_xql::ops::between;
+    /// use qians_xql::blanket::ExprExt;
+    ///
+    /// assert_eq!("age".between(18, 65), between("age", 18, 65));
+    /// ```
+    #[inline]
+    fn between<L, H>(self, low: L, high: H) -> Expr<'a>
+    where
+        L: Into<Expr<'a>>,
+        H: Into<Expr<'a>>,
+    {
+        crate::ops::between(self, low, high)
+    }
+
+    /// A short hand for [`qians_xql::ops::not_between`](crate::ops::not_between).
+    ///
+    /// ```
+    /// use qians_xql::ops::not_between;
+    /// use qians_xql::blanket::ExprExt;
+    ///
+    /// assert_eq!("age".not_between(18, 65), not_between("age", 18, 65));
+    /// ```
+    #[inline]
+    fn not_between<L, H>(self, low: L, high: H) -> Expr<'a>
+    where
+        L: Into<Expr<'a>>,
+        H: Into<Expr<'a>>,
+    {
+        crate::ops::not_between(self, low, high)
+    }
+
+    /// A short hand for [`qians_xql::ops::cast`](crate::ops::cast).
+    ///
+    /// ```
+    /// use qians_xql::ops::cast;
+    /// use qians_xql::blanket::ExprExt;
+    /// use qians_xql::item::TypeName;
+    ///
+    /// let text = TypeName::try_from("text").unwrap();
+    /// assert_eq!("id".cast(text.clone()), cast("id", text));
+    /// ```
+    #[inline]
+    fn cast<T: Into<TypeName<'a>>>(self, ty: T) -> Expr<'a> {
+        crate::ops::cast(self, ty)
+    }
+
+    /// A short hand for [`qians_xql::ops::is_null`](crate::ops::is_null).
+    ///
+    /// ```
+    /// use qians_xql::ops::is_null;
+    /// use qians_xql::blanket::ExprExt;
+    ///
+    /// assert_eq!("id".is_null(), is_null("id"));
+    /// ```
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_null(self) -> Expr<'a> {
+        crate::ops::is_null(self)
+    }
+
+    /// A short hand for [`qians_xql::ops::is_not_null`](crate::ops::is_not_null).
+    ///
+    /// ```
+    /// use qians_xql::ops::is_not_null;
+    /// use qians_xql::blanket::ExprExt;
+    ///
+    /// assert_eq!("id".is_not_null(), is_not_null("id"));
+    /// ```
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_not_null(self) -> Expr<'a> {
+        crate::ops::is_not_null(self)
+    }
+
+    /// A short hand for [`qians_xql::ops::not`](crate::ops::not).
+    ///
+    /// ```
+    /// use qians_xql::ops::not;
+    /// use qians_xql::blanket::ExprExt;
+    ///
+    /// assert_eq!("active".not(), not("active"));
+    /// ```
+    #[inline]
+    fn not(self) -> Expr<'a> {
+        crate::ops::not(self)
+    }
+
     /// A short hand for [`qians_xql::ops::paren`](crate::ops::paren).
     ///
     /// ```
@@ -145,8 +242,16 @@ fn binops() {
     assert_eq!(1.le(1).to_string(), "1 <= 1");
     assert_eq!(1.less_equal(1).to_string(), "1 <= 1");
 
+    assert_eq!(1.is_distinct_from(1).to_string(), "1 IS DISTINCT FROM 1");
+    assert_eq!(
+        1.is_not_distinct_from(1).to_string(),
+        "1 IS NOT DISTINCT FROM 1"
+    );
+
     assert_eq!(1.and(1).to_string(), "1 AND 1");
     assert_eq!(1.or(1).to_string(), "1 OR 1");
     assert_eq!(1.like(1).to_string(), "1 LIKE 1");
     assert_eq!(1.ilike(1).to_string(), "1 ILIKE 1");
+    assert_eq!(1.not_like(1).to_string(), "1 NOT LIKE 1");
+    assert_eq!(1.not_ilike(1).to_string(), "1 NOT ILIKE 1");
 }
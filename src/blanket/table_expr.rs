@@ -59,6 +59,44 @@ pub trait TableExprExt<'a>: Into<crate::table_expr::TableExpr<'a>> {
     gen_method!(natural_left_join);
     gen_method!(natural_right_join);
     gen_method!(natural_full_join);
+
+    /// A short hand for [`qians_xql::ops::join_using`](crate::ops::join_using).
+    ///
+    /// ```
+    /// use qians_xql::ops::join_using;
+    /// use qians_xql::blanket::TableExprExt;
+    ///
+    /// assert_eq!(
+    ///     "book".join_using("author", ["author_id"]),
+    ///     join_using("book", "author", ["author_id"]),
+    /// );
+    /// ```
+    #[inline]
+    fn join_using<R, C, I>(self, right: R, columns: I) -> TableExpr<'a>
+    where
+        R: Into<crate::item::Table<'a>>,
+        C: Into<crate::item::Ident<'a>>,
+        I: IntoIterator<Item = C>,
+    {
+        crate::ops::join_using(self, right, columns)
+    }
+
+    /// A short hand for [`qians_xql::ops::as_table`](crate::ops::as_table).
+    ///
+    /// ```
+    /// use qians_xql::ops::as_table;
+    /// use qians_xql::blanket::TableExprExt;
+    ///
+    /// assert_eq!("user".as_table("u"), as_table("user", "u"));
+    /// ```
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn as_table<A>(self, alias: A) -> crate::item::Table<'a>
+    where
+        A: Into<crate::item::Ident<'a>>,
+    {
+        crate::ops::as_table(self, alias)
+    }
 }
 
 impl<'a, T> TableExprExt<'a> for T where T: Into<TableExpr<'a>> {}
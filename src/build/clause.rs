@@ -2,49 +2,89 @@ use super::Dialect;
 use super::ToSql;
 
 impl<'a> ToSql<'a> for crate::clause::Select<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         sql.push_str("SELECT ");
         join!(D, sql, args, ", ", self.0);
     }
 }
 
+impl<'a> ToSql<'a> for crate::clause::Distinct<'a> {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+        sql.push_str("DISTINCT");
+        if !self.0.is_empty() {
+            sql.push_str(" ON (");
+            join!(D, sql, args, ", ", self.0);
+            sql.push(')');
+        }
+    }
+}
+
 impl<'a> ToSql<'a> for crate::clause::From<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         sql.push_str("FROM ");
         join!(D, sql, args, ", ", self.0);
     }
 }
 
+impl<'a> ToSql<'a> for crate::clause::Using<'a> {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+        sql.push_str("USING ");
+        join!(D, sql, args, ", ", self.0);
+    }
+}
+
 impl<'a> ToSql<'a> for crate::clause::Where<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         sql.push_str("WHERE ");
         self.0.build::<D>(sql, args);
     }
 }
 
 impl<'a> ToSql<'a> for crate::clause::GroupBy<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         sql.push_str("GROUP BY ");
         join!(D, sql, args, ", ", self.0);
     }
 }
 
 impl<'a> ToSql<'a> for crate::clause::Having<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         sql.push_str("HAVING ");
         self.0.build::<D>(sql, args);
     }
 }
 
+impl<'a> ToSql<'a> for crate::clause::Window<'a> {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+        sql.push_str("WINDOW ");
+        let mut first = true;
+        for (name, window) in &self.0 {
+            if !first {
+                sql.push_str(", ");
+            }
+            first = false;
+            name.build::<D>(sql, args);
+            sql.push_str(" AS ");
+            if window.is_ref() {
+                sql.push('(');
+                window.build::<D>(sql, args);
+                sql.push(')');
+            } else {
+                window.build::<D>(sql, args);
+            }
+        }
+    }
+}
+
 impl<'a> ToSql<'a> for crate::clause::OrderBy<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         sql.push_str("ORDER BY ");
         join!(D, sql, args, ", ", self.0);
     }
 }
 
 impl<'a> ToSql<'a> for crate::clause::Insert<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         sql.push_str("INSERT INTO ");
         self.0.build::<D>(sql, args);
         if !self.1.is_empty() {
@@ -56,53 +96,85 @@ impl<'a> ToSql<'a> for crate::clause::Insert<'a> {
 }
 
 impl<'a> ToSql<'a> for crate::clause::Values<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         sql.push_str("VALUES ");
         join!(D, sql, args, ", ", self.0);
     }
 }
 
 impl<'a> ToSql<'a> for crate::clause::Returning<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         sql.push_str("RETURNING ");
         join!(D, sql, args, ", ", self.0);
     }
 }
 
 impl<'a> ToSql<'a> for crate::clause::Update<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         sql.push_str("UPDATE ");
         self.0.build::<D>(sql, args);
     }
 }
 
 impl<'a> ToSql<'a> for crate::clause::Delete<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         sql.push_str("DELETE FROM ");
         self.0.build::<D>(sql, args);
     }
 }
 
 impl<'a> ToSql<'a> for crate::clause::Set<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         sql.push_str("SET ");
-        let mut elems = self.0.into_iter();
-        if let Some(elem) = elems.next() {
+        build_assignments::<D>(self, sql, args);
+    }
+}
+
+impl<'a> ToSql<'a> for crate::clause::OnConflict<'a> {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+        sql.push_str("ON CONFLICT ");
+        if !self.0.is_empty() {
+            sql.push('(');
+            join!(D, sql, args, ", ", self.0);
+            sql.push_str(") ");
+        }
+        if let Some(predicate) = &self.1 {
+            sql.push_str("WHERE ");
+            predicate.build::<D>(sql, args);
+            sql.push(' ');
+        }
+        match &self.2 {
+            Some(set) => {
+                sql.push_str("DO UPDATE SET ");
+                build_assignments::<D>(set, sql, args);
+            }
+            None => sql.push_str("DO NOTHING"),
+        }
+    }
+}
+
+/// Build the `column = value` pairs of a [`Set`](crate::clause::Set) clause.
+pub(super) fn build_assignments<'a, D: Dialect>(
+    set: &crate::clause::Set<'a>,
+    sql: &mut String,
+    args: &mut Vec<crate::value::Value<'a>>,
+) {
+    let mut elems = set.0.iter();
+    if let Some(elem) = elems.next() {
+        elem.0.build::<D>(sql, args);
+        sql.push_str(" = ");
+        elem.1.build::<D>(sql, args);
+        for elem in elems {
+            sql.push_str(", ");
             elem.0.build::<D>(sql, args);
             sql.push_str(" = ");
             elem.1.build::<D>(sql, args);
-            for elem in elems {
-                sql.push_str(", ");
-                elem.0.build::<D>(sql, args);
-                sql.push_str(" = ");
-                elem.1.build::<D>(sql, args);
-            }
         }
     }
 }
 
 impl<'a> ToSql<'a> for crate::clause::With<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         if self.0 {
             sql.push_str("WITH RECURSIVE ");
         } else {
@@ -112,16 +184,34 @@ impl<'a> ToSql<'a> for crate::clause::With<'a> {
     }
 }
 
-impl<'a> ToSql<'a> for crate::clause::Limit {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+impl<'a> ToSql<'a> for crate::clause::Limit<'a> {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         sql.push_str("LIMIT ");
-        crate::value::Value::from(self.0 as i64).build::<D>(sql, args);
+        match &self.0 {
+            Some(count) => count.build::<D>(sql, args),
+            None => sql.push_str("ALL"),
+        }
     }
 }
 
-impl<'a> ToSql<'a> for crate::clause::Offset {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+impl<'a> ToSql<'a> for crate::clause::Offset<'a> {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         sql.push_str("OFFSET ");
-        crate::value::Value::from(self.0 as i64).build::<D>(sql, args);
+        self.0.build::<D>(sql, args);
+    }
+}
+
+impl<'a> ToSql<'a> for crate::clause::Lock<'a> {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+        sql.push_str("FOR ");
+        sql.push_str(&self.0);
+        if !self.1.is_empty() {
+            sql.push_str(" OF ");
+            join!(D, sql, args, ", ", self.1);
+        }
+        if let Some(wait) = &self.2 {
+            sql.push(' ');
+            sql.push_str(wait);
+        }
     }
 }
@@ -1,27 +1,66 @@
 use super::Dialect;
 use super::ToSql;
 
+use crate::expr::COMPARISON;
+
 impl<'a> ToSql<'a> for crate::expr::Expr<'a> {
     #[inline]
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         match self {
             crate::expr::Expr::Literal(val) => val.build::<D>(sql, args),
+            crate::expr::Expr::Raw(val) => sql.push_str(val),
+            crate::expr::Expr::Ordinal(pos) => {
+                use std::fmt::Write;
+                let _ = write!(sql, "{pos}");
+            }
+            crate::expr::Expr::Interval(val) => {
+                sql.push_str("INTERVAL ");
+                D::quote_literal(val, sql);
+            }
+            crate::expr::Expr::Quoted(val) => D::quote_literal(val, sql),
             crate::expr::Expr::Column(val) => val.build::<D>(sql, args),
             crate::expr::Expr::FuncCall(val) => val.build::<D>(sql, args),
             crate::expr::Expr::Prefix(op, val) => {
+                // `NOT` always groups the operators it negates for readability.
+                let paren = match &**op {
+                    "NOT" => matches!(
+                        **val,
+                        crate::expr::Expr::Infix(..)
+                            | crate::expr::Expr::Postfix(..)
+                            | crate::expr::Expr::Between(..)
+                            | crate::expr::Expr::LikeEscape(..)
+                            | crate::expr::Expr::In(..)
+                    ),
+                    _ => val.precedence() < 8,
+                };
                 sql.push_str(op);
                 sql.push(' ');
-                val.build::<D>(sql, args);
+                build_operand::<D>(val, paren, sql, args);
             }
             crate::expr::Expr::Infix(left, op, right) => {
-                left.build::<D>(sql, args);
+                let prec = crate::expr::Expr::infix_precedence(op);
+                // binary operators are left associative, except comparisons
+                // which are not associative at all, and associative ones can
+                // chain the same operator on the right too.
+                let paren_left = left.precedence() < prec
+                    || (prec == COMPARISON && left.precedence() == COMPARISON);
+                let paren_right = match &**right {
+                    crate::expr::Expr::Infix(_, rop, _)
+                        if rop == op && matches!(&**op, "AND" | "OR" | "+" | "*" | "||") =>
+                    {
+                        false
+                    }
+                    _ => right.precedence() <= prec,
+                };
+                build_operand::<D>(left, paren_left, sql, args);
                 sql.push(' ');
                 sql.push_str(op);
                 sql.push(' ');
-                right.build::<D>(sql, args);
+                build_operand::<D>(right, paren_right, sql, args);
             }
             crate::expr::Expr::Postfix(val, op) => {
-                val.build::<D>(sql, args);
+                let paren = val.precedence() <= COMPARISON;
+                build_operand::<D>(val, paren, sql, args);
                 sql.push(' ');
                 sql.push_str(op);
             }
@@ -30,11 +69,109 @@ impl<'a> ToSql<'a> for crate::expr::Expr<'a> {
                 val.build::<D>(sql, args);
                 sql.push(')');
             }
-            crate::expr::Expr::SubQuery(val) => {
-                sql.push('(');
+            crate::expr::Expr::SubQuery(val) => super::build_nested::<D, _>(val, sql, args),
+            crate::expr::Expr::Window(val, window) => {
+                val.build::<D>(sql, args);
+                sql.push_str(" OVER ");
+                window.build::<D>(sql, args);
+            }
+            crate::expr::Expr::Cast(val, ty) => {
+                sql.push_str("CAST(");
                 val.build::<D>(sql, args);
+                sql.push_str(" AS ");
+                ty.build::<D>(sql, args);
+                sql.push(')');
+            }
+            crate::expr::Expr::Extract(field, val) => {
+                sql.push_str("EXTRACT(");
+                field.build::<D>(sql, args);
+                sql.push_str(" FROM ");
+                val.build::<D>(sql, args);
+                sql.push(')');
+            }
+            crate::expr::Expr::Between(val, op, low, high) => {
+                let paren = val.precedence() <= COMPARISON;
+                build_operand::<D>(val, paren, sql, args);
+                sql.push(' ');
+                sql.push_str(op);
+                sql.push(' ');
+                let paren = low.precedence() <= COMPARISON;
+                build_operand::<D>(low, paren, sql, args);
+                sql.push_str(" AND ");
+                let paren = high.precedence() <= COMPARISON;
+                build_operand::<D>(high, paren, sql, args);
+            }
+            crate::expr::Expr::LikeEscape(val, op, pattern, escape) => {
+                let paren = val.precedence() <= COMPARISON;
+                build_operand::<D>(val, paren, sql, args);
+                sql.push(' ');
+                sql.push_str(op);
+                sql.push(' ');
+                let paren = pattern.precedence() <= COMPARISON;
+                build_operand::<D>(pattern, paren, sql, args);
+                sql.push_str(" ESCAPE ");
+                let paren = escape.precedence() <= COMPARISON;
+                build_operand::<D>(escape, paren, sql, args);
+            }
+            crate::expr::Expr::Quantified(op, val) => {
+                sql.push_str(op);
+                match **val {
+                    crate::expr::Expr::SubQuery(_) => val.build::<D>(sql, args),
+                    _ => {
+                        sql.push('(');
+                        val.build::<D>(sql, args);
+                        sql.push(')');
+                    }
+                }
+            }
+            crate::expr::Expr::Array(items) => {
+                sql.push_str("ARRAY[");
+                join!(D, sql, args, ", ", items);
+                sql.push(']');
+            }
+            crate::expr::Expr::Subscript(val, from, to) => {
+                // Postgres only accepts columns and subscripts to be subscripted
+                // without parentheses.
+                let paren = !matches!(
+                    **val,
+                    crate::expr::Expr::Column(_)
+                        | crate::expr::Expr::Paren(_)
+                        | crate::expr::Expr::Subscript(..)
+                );
+                build_operand::<D>(val, paren, sql, args);
+                sql.push('[');
+                from.build::<D>(sql, args);
+                if let Some(to) = to {
+                    sql.push(':');
+                    to.build::<D>(sql, args);
+                }
+                sql.push(']');
+            }
+            crate::expr::Expr::In(val, op, list) => {
+                let paren = val.precedence() <= COMPARISON;
+                build_operand::<D>(val, paren, sql, args);
+                sql.push(' ');
+                sql.push_str(op);
+                sql.push_str(" (");
+                join!(D, sql, args, ", ", list);
                 sql.push(')');
             }
         }
     }
 }
+
+/// Build an operand of an operator, wrapping it in parentheses if needed.
+fn build_operand<'a, D: Dialect>(
+    expr: &crate::expr::Expr<'a>,
+    paren: bool,
+    sql: &mut String,
+    args: &mut Vec<crate::value::Value<'a>>,
+) {
+    if paren {
+        sql.push('(');
+        expr.build::<D>(sql, args);
+        sql.push(')');
+    } else {
+        expr.build::<D>(sql, args);
+    }
+}
@@ -2,21 +2,28 @@ use super::Dialect;
 use super::ToSql;
 
 impl<'a> ToSql<'a> for crate::value::Value<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
-        args.push(self);
-        D::bind_param(args.len(), self, sql);
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+        let val = D::bind_param(args.len() + 1, self.clone(), sql);
+        args.push(val);
     }
 }
 
 impl<'a> ToSql<'a> for crate::item::Ident<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, _: &mut Vec<crate::value::Value<'a>>) {
-        D::quote_ident(self.0, sql);
+    fn build<D: Dialect>(&self, sql: &mut String, _: &mut Vec<crate::value::Value<'a>>) {
+        D::quote_ident(&self.0, sql);
+    }
+}
+
+impl<'a> ToSql<'a> for crate::item::TypeName<'a> {
+    #[inline]
+    fn build<D: Dialect>(&self, sql: &mut String, _: &mut Vec<crate::value::Value<'a>>) {
+        sql.push_str(&self.0);
     }
 }
 
 impl<'a> ToSql<'a> for crate::item::Sort {
     #[inline]
-    fn build<D: Dialect>(self, sql: &mut String, _: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(&self, sql: &mut String, _: &mut Vec<crate::value::Value<'a>>) {
         match self {
             crate::item::Sort::Asc => sql.push_str("ASC"),
             crate::item::Sort::Desc => sql.push_str("DESC"),
@@ -24,20 +31,93 @@ impl<'a> ToSql<'a> for crate::item::Sort {
     }
 }
 
+impl<'a> ToSql<'a> for crate::item::Nulls {
+    #[inline]
+    fn build<D: Dialect>(&self, sql: &mut String, _: &mut Vec<crate::value::Value<'a>>) {
+        match self {
+            crate::item::Nulls::First => sql.push_str("NULLS FIRST"),
+            crate::item::Nulls::Last => sql.push_str("NULLS LAST"),
+        }
+    }
+}
+
+impl<'a> ToSql<'a> for crate::item::Grouping<'a> {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+        match self {
+            crate::item::Grouping::Expr(expr) => expr.build::<D>(sql, args),
+            crate::item::Grouping::Sets(sets) => {
+                sql.push_str("GROUPING SETS (");
+                for (i, set) in sets.iter().enumerate() {
+                    if i > 0 {
+                        sql.push_str(", ");
+                    }
+                    sql.push('(');
+                    join!(D, sql, args, ", ", set);
+                    sql.push(')');
+                }
+                sql.push(')');
+            }
+            crate::item::Grouping::Rollup(exprs) => {
+                sql.push_str("ROLLUP(");
+                join!(D, sql, args, ", ", exprs);
+                sql.push(')');
+            }
+            crate::item::Grouping::Cube(exprs) => {
+                sql.push_str("CUBE(");
+                join!(D, sql, args, ", ", exprs);
+                sql.push(')');
+            }
+        }
+    }
+}
+
+impl<'a> ToSql<'a> for crate::item::JoinKind {
+    #[inline]
+    fn build<D: Dialect>(&self, sql: &mut String, _: &mut Vec<crate::value::Value<'a>>) {
+        match self {
+            crate::item::JoinKind::Inner => sql.push_str("JOIN"),
+            crate::item::JoinKind::Left => sql.push_str("LEFT JOIN"),
+            crate::item::JoinKind::Right => sql.push_str("RIGHT JOIN"),
+            crate::item::JoinKind::Full => sql.push_str("FULL JOIN"),
+        }
+    }
+}
+
+impl<'a> ToSql<'a> for crate::item::JoinCond<'a> {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+        match self {
+            crate::item::JoinCond::On(expr) => {
+                sql.push_str("ON ");
+                expr.build::<D>(sql, args);
+            }
+            crate::item::JoinCond::Using(columns) => {
+                sql.push_str("USING (");
+                join!(D, sql, args, ", ", columns);
+                sql.push(')');
+            }
+            crate::item::JoinCond::Natural => {}
+        }
+    }
+}
+
 impl<'a> ToSql<'a> for crate::item::Order<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         self.0.build::<D>(sql, args);
-        if let Some(sort) = self.1 {
+        if let Some(sort) = &self.1 {
             sql.push(' ');
             sort.build::<D>(sql, args);
         }
+        if let Some(nulls) = &self.2 {
+            sql.push(' ');
+            nulls.build::<D>(sql, args);
+        }
     }
 }
 
 impl<'a> ToSql<'a> for crate::item::Field<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         self.expr.build::<D>(sql, args);
-        if let Some(alias) = self.alias {
+        if let Some(alias) = &self.alias {
             sql.push_str(" AS ");
             alias.build::<D>(sql, args);
         }
@@ -45,41 +125,130 @@ impl<'a> ToSql<'a> for crate::item::Field<'a> {
 }
 
 impl<'a> ToSql<'a> for crate::item::Table<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         self.table.build::<D>(sql, args);
-        if let Some(alias) = self.alias {
+        if let Some(alias) = &self.alias {
             sql.push_str(" AS ");
             alias.build::<D>(sql, args);
+            match &self.table {
+                crate::table_expr::TableExpr::Values(_, columns) if !columns.is_empty() => {
+                    sql.push('(');
+                    join!(D, sql, args, ", ", columns);
+                    sql.push(')');
+                }
+                _ => (),
+            }
+        }
+        if let Some(sample) = &self.sample {
+            sql.push(' ');
+            sample.build::<D>(sql, args);
+        }
+    }
+}
+
+impl<'a> ToSql<'a> for crate::item::TableSample<'a> {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+        sql.push_str("TABLESAMPLE ");
+        self.0.build::<D>(sql, args);
+        sql.push_str(" (");
+        self.1.build::<D>(sql, args);
+        sql.push(')');
+        if let Some(seed) = &self.2 {
+            sql.push_str(" REPEATABLE (");
+            seed.build::<D>(sql, args);
+            sql.push(')');
         }
     }
 }
 
+impl<'a> ToSql<'a> for crate::item::SampleMethod {
+    #[inline]
+    fn build<D: Dialect>(&self, sql: &mut String, _: &mut Vec<crate::value::Value<'a>>) {
+        match self {
+            crate::item::SampleMethod::Bernoulli => sql.push_str("BERNOULLI"),
+            crate::item::SampleMethod::System => sql.push_str("SYSTEM"),
+        }
+    }
+}
+
+impl<'a> ToSql<'a> for crate::item::DateField {
+    #[inline]
+    fn build<D: Dialect>(&self, sql: &mut String, _: &mut Vec<crate::value::Value<'a>>) {
+        sql.push_str(match self {
+            crate::item::DateField::Microsecond => "MICROSECOND",
+            crate::item::DateField::Microseconds => "MICROSECONDS",
+            crate::item::DateField::Milliseconds => "MILLISECONDS",
+            crate::item::DateField::Second => "SECOND",
+            crate::item::DateField::Minute => "MINUTE",
+            crate::item::DateField::Hour => "HOUR",
+            crate::item::DateField::Day => "DAY",
+            crate::item::DateField::Week => "WEEK",
+            crate::item::DateField::Month => "MONTH",
+            crate::item::DateField::Quarter => "QUARTER",
+            crate::item::DateField::Year => "YEAR",
+            crate::item::DateField::Decade => "DECADE",
+            crate::item::DateField::Century => "CENTURY",
+            crate::item::DateField::Millennium => "MILLENNIUM",
+            crate::item::DateField::Dow => "DOW",
+            crate::item::DateField::Doy => "DOY",
+            crate::item::DateField::Epoch => "EPOCH",
+            crate::item::DateField::IsoDow => "ISODOW",
+            crate::item::DateField::IsoYear => "ISOYEAR",
+            crate::item::DateField::Julian => "JULIAN",
+            crate::item::DateField::Timezone => "TIMEZONE",
+            crate::item::DateField::TimezoneHour => "TIMEZONE_HOUR",
+            crate::item::DateField::TimezoneMinute => "TIMEZONE_MINUTE",
+            crate::item::DateField::SecondMicrosecond => "SECOND_MICROSECOND",
+            crate::item::DateField::MinuteMicrosecond => "MINUTE_MICROSECOND",
+            crate::item::DateField::MinuteSecond => "MINUTE_SECOND",
+            crate::item::DateField::HourMicrosecond => "HOUR_MICROSECOND",
+            crate::item::DateField::HourSecond => "HOUR_SECOND",
+            crate::item::DateField::HourMinute => "HOUR_MINUTE",
+            crate::item::DateField::DayMicrosecond => "DAY_MICROSECOND",
+            crate::item::DateField::DaySecond => "DAY_SECOND",
+            crate::item::DateField::DayMinute => "DAY_MINUTE",
+            crate::item::DateField::DayHour => "DAY_HOUR",
+            crate::item::DateField::YearMonth => "YEAR_MONTH",
+        });
+    }
+}
+
 impl<'a> ToSql<'a> for crate::item::ColumnRef<'a> {
     #[inline]
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         match self {
+            crate::item::ColumnRef::Star => sql.push('*'),
             crate::item::ColumnRef::Column(col) => col.build::<D>(sql, args),
             crate::item::ColumnRef::TableColumn(tbl, col) => join!(D, sql, args, ".", [tbl, col]),
             crate::item::ColumnRef::SchemaTableColumn(sch, tbl, col) => {
                 join!(D, sql, args, ".", [sch, tbl, col])
             }
+            crate::item::ColumnRef::TableStar(tbl) => {
+                tbl.build::<D>(sql, args);
+                sql.push_str(".*");
+            }
+            crate::item::ColumnRef::Excluded(col) => {
+                sql.push_str("EXCLUDED.");
+                col.build::<D>(sql, args);
+            }
         }
     }
 }
 
 impl<'a> ToSql<'a> for crate::item::FuncRef<'a> {
     #[inline]
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         match self {
             crate::item::FuncRef::Func(fun) => fun.build::<D>(sql, args),
             crate::item::FuncRef::SchemaFunc(sch, fun) => join!(D, sql, args, ".", [sch, fun]),
+            crate::item::FuncRef::Builtin(name) => sql.push_str(name),
         }
     }
 }
 
 impl<'a> ToSql<'a> for crate::item::TableRef<'a> {
     #[inline]
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         match self {
             crate::item::TableRef::Table(tbl) => tbl.build::<D>(sql, args),
             crate::item::TableRef::SchemaTable(sch, tbl) => join!(D, sql, args, ".", [sch, tbl]),
@@ -88,16 +257,28 @@ impl<'a> ToSql<'a> for crate::item::TableRef<'a> {
 }
 
 impl<'a> ToSql<'a> for crate::item::FuncCall<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
-        self.0.build::<D>(sql, args);
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+        self.func.build::<D>(sql, args);
         sql.push('(');
-        join!(D, sql, args, ", ", self.1);
+        if self.distinct {
+            sql.push_str("DISTINCT ");
+        }
+        join!(D, sql, args, ", ", self.args);
+        if let Some(orders) = &self.orders {
+            sql.push(' ');
+            orders.build::<D>(sql, args);
+        }
         sql.push(')');
+        if let Some(filter) = &self.filter {
+            sql.push_str(" FILTER (");
+            filter.build::<D>(sql, args);
+            sql.push(')');
+        }
     }
 }
 
 impl<'a> ToSql<'a> for crate::item::Row<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         sql.push('(');
         join!(D, sql, args, ", ", self.0);
         sql.push(')');
@@ -105,7 +286,7 @@ impl<'a> ToSql<'a> for crate::item::Row<'a> {
 }
 
 impl<'a> ToSql<'a> for crate::item::Cte<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         self.name.build::<D>(sql, args);
         if !self.columns.is_empty() {
             sql.push('(');
@@ -113,8 +294,79 @@ impl<'a> ToSql<'a> for crate::item::Cte<'a> {
             sql.push(')');
         }
         sql.push_str(" AS ");
+        super::build_nested::<D, _>(&self.stmt, sql, args);
+    }
+}
+
+impl<'a> ToSql<'a> for crate::item::Window<'a> {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+        if self.is_ref() {
+            if let Some(name) = &self.name {
+                name.build::<D>(sql, args);
+            }
+            return;
+        }
         sql.push('(');
-        self.stmt.build::<D>(sql, args);
+        let mut space = false;
+        if let Some(name) = &self.name {
+            name.build::<D>(sql, args);
+            space = true;
+        }
+        if !self.partition.is_empty() {
+            if space {
+                sql.push(' ');
+            }
+            sql.push_str("PARTITION BY ");
+            join!(D, sql, args, ", ", self.partition);
+            space = true;
+        }
+        if let Some(orders) = &self.orders {
+            if space {
+                sql.push(' ');
+            }
+            orders.build::<D>(sql, args);
+            space = true;
+        }
+        if let Some(frame) = &self.frame {
+            if space {
+                sql.push(' ');
+            }
+            frame.build::<D>(sql, args);
+        }
         sql.push(')');
     }
 }
+
+impl<'a> ToSql<'a> for crate::item::Frame<'a> {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+        sql.push_str(&self.0);
+        sql.push(' ');
+        match &self.2 {
+            Some(end) => {
+                sql.push_str("BETWEEN ");
+                self.1.build::<D>(sql, args);
+                sql.push_str(" AND ");
+                end.build::<D>(sql, args);
+            }
+            None => self.1.build::<D>(sql, args),
+        }
+    }
+}
+
+impl<'a> ToSql<'a> for crate::item::FrameBound<'a> {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+        match self {
+            crate::item::FrameBound::UnboundedPreceding => sql.push_str("UNBOUNDED PRECEDING"),
+            crate::item::FrameBound::Preceding(expr) => {
+                expr.build::<D>(sql, args);
+                sql.push_str(" PRECEDING");
+            }
+            crate::item::FrameBound::CurrentRow => sql.push_str("CURRENT ROW"),
+            crate::item::FrameBound::Following(expr) => {
+                expr.build::<D>(sql, args);
+                sql.push_str(" FOLLOWING");
+            }
+            crate::item::FrameBound::UnboundedFollowing => sql.push_str("UNBOUNDED FOLLOWING"),
+        }
+    }
+}
@@ -1,9 +1,4 @@
 macro_rules! join {
-    ($Dialect: ty, $sql:expr, $args:expr, $left:expr, $sep:expr, $right:expr, $cond:expr) => {{
-        join!($Dialect, $sql, $args, $left, $sep, $right);
-        $sql.push_str(" ON ");
-        $cond.build::<$Dialect>($sql, $args);
-    }};
     ($Dialect: ty, $sql:expr, $args:expr, $left:expr, $sep:expr, $right:expr) => {{
         $left.build::<$Dialect>($sql, $args);
         $sql.push(' ');
@@ -13,7 +8,8 @@ macro_rules! join {
     }};
 
     ($Dialect: ty, $sql:expr, $args:expr, $sep:expr, $elems:expr) => {{
-        let mut elems = $elems.into_iter();
+        let elems = &$elems;
+        let mut elems = elems.iter();
         if let Some(elem) = elems.next() {
             elem.build::<$Dialect>($sql, $args);
             for elem in elems {
@@ -30,43 +26,170 @@ mod item;
 mod stmt;
 mod table_expr;
 
+/// How a statement is rendered for a specific database.
+///
+/// Dialects are chosen statically, e.g. `stmt.to_sql(Postgres)`, so the
+/// rendering is monomorphized for each of them. See [`dialect`](crate::dialect)
+/// for the provided implementors.
 pub trait Dialect {
+    /// Write `val` as a quoted string literal.
     fn quote_literal(val: &str, buff: &mut String);
 
+    /// Write `name` as a quoted identifier.
     fn quote_ident(name: &str, buff: &mut String);
 
+    /// Write `val` as a binary string literal.
+    ///
+    /// By default this writes PostgreSQL's hex format, e.g. `'\x0aff'`.
+    fn quote_bytes(val: &[u8], buff: &mut String) {
+        buff.push_str("'\\x");
+        crate::dialect::hex(buff, val);
+        buff.push('\'');
+    }
+
+    /// Write the placeholder of the `n`th (1-based) parameter.
+    fn placeholder(n: usize, buff: &mut String);
+
+    /// Bind `val` as the `n`th (1-based) parameter.
+    ///
+    /// By default this writes a [`placeholder`](Dialect::placeholder) and
+    /// keeps `val` as a parameter.
     fn bind_param<'a>(
         n: usize,
         val: crate::value::Value<'a>,
         buff: &mut String,
-    ) -> crate::value::Value<'a>;
+    ) -> crate::value::Value<'a> {
+        Self::placeholder(n, buff);
+        val
+    }
+
+    /// Whether MySQL's `ON DUPLICATE KEY UPDATE` is rendered.
+    const ON_DUPLICATE_KEY_UPDATE: bool = false;
+
+    /// Whether `CROSS APPLY` and `OUTER APPLY` are rendered as is, instead of
+    /// the equivalent `LATERAL` joins.
+    const APPLY: bool = false;
+
+    /// Whether `LIMIT` is rendered as `TOP` right after `SELECT`, or as
+    /// `OFFSET ... ROWS FETCH NEXT ... ROWS ONLY` when there is an offset or
+    /// the statement is not a plain `SELECT`.
+    const TOP: bool = false;
+
+    /// Whether row locks such as `FOR UPDATE` are rendered. A dialect without
+    /// them locks rows through table hints, so the clause is left out.
+    const LOCK: bool = true;
+
+    /// Written as the count of `LIMIT` for no limit at all, instead of
+    /// `LIMIT ALL`. A dialect setting it also writes it before an `OFFSET`
+    /// without `LIMIT`, as it only accepts an offset after a limit.
+    const NO_LIMIT: Option<&'static str> = None;
+
+    /// Whether an offset is written as `LIMIT offset, count` instead of
+    /// `LIMIT count OFFSET offset`.
+    const LIMIT_OFFSET_COMMA: bool = false;
+
+    /// Whether each clause of a statement is put on its own line, with nested
+    /// statements indented.
+    const PRETTY: bool = false;
+}
+
+/// Indentation of each level of nested statements in pretty mode.
+const INDENT: usize = 4;
+
+/// Leading spaces of the last line of `sql`.
+fn indent(sql: &str) -> usize {
+    let line = &sql[sql.rfind('\n').map_or(0, |i| i + 1)..];
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+fn newline(sql: &mut String, indent: usize) {
+    sql.push('\n');
+    for _ in 0..indent {
+        sql.push(' ');
+    }
+}
+
+/// Separate two clauses of a statement.
+pub(crate) fn clause_sep<D: Dialect>(sql: &mut String) {
+    if D::PRETTY {
+        newline(sql, indent(sql));
+    } else {
+        sql.push(' ');
+    }
+}
+
+/// Build a statement nested in parentheses.
+pub(crate) fn build_nested<'a, D: Dialect, T: ToSql<'a>>(
+    stmt: &T,
+    sql: &mut String,
+    args: &mut Vec<crate::value::Value<'a>>,
+) {
+    if D::PRETTY {
+        let indent = indent(sql);
+        sql.push('(');
+        newline(sql, indent + INDENT);
+        stmt.build::<D>(sql, args);
+        newline(sql, indent);
+        sql.push(')');
+    } else {
+        sql.push('(');
+        stmt.build::<D>(sql, args);
+        sql.push(')');
+    }
 }
 
 pub(crate) trait ToSql<'a>: Sized {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>);
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>);
 
-    fn to_sql<D: Dialect>(self) -> (String, Vec<crate::value::Value<'a>>) {
+    fn to_sql<D: Dialect>(&self) -> (String, Vec<crate::value::Value<'a>>) {
         let mut sql = String::new();
         let mut args = Vec::new();
         self.build::<D>(&mut sql, &mut args);
         (sql, args)
     }
+
+    /// Collect the values bound by [`to_sql`](ToSql::to_sql), in the same
+    /// order. Built in [`Params<D>`], which skips writing identifiers,
+    /// literals and placeholders, so only the keywords are written and then
+    /// discarded.
+    fn params<D: Dialect>(&self) -> Vec<crate::value::Value<'a>> {
+        let mut args = Vec::new();
+        self.build::<Params<D>>(&mut String::new(), &mut args);
+        args
+    }
+
+    /// Render in `D` either with the values bound to placeholders, or with
+    /// them written inline, in which case no values are returned.
+    fn render<D: Dialect>(
+        &self,
+        mode: crate::dialect::RenderMode,
+    ) -> (String, Vec<crate::value::Value<'a>>) {
+        match mode {
+            crate::dialect::RenderMode::Params => self.to_sql::<D>(),
+            crate::dialect::RenderMode::Inline => {
+                let mut sql = String::new();
+                self.build::<Inline<D>>(&mut sql, &mut Vec::new());
+                (sql, Vec::new())
+            }
+        }
+    }
 }
 
+/// Writes values inline, like [`RenderMode::Inline`](crate::dialect::RenderMode::Inline),
+/// with identifiers only quoted when needed. Used by the `Display` impl of
+/// statements.
 pub(crate) struct Display;
 
 impl Dialect for Display {
+    const ON_DUPLICATE_KEY_UPDATE: bool = true;
+
     fn quote_literal(val: &str, buff: &mut String) {
-        buff.push('\'');
-        for ch in val.chars() {
-            if ch == '\'' {
-                buff.push('\'');
-            }
-            buff.push(ch);
-        }
-        buff.push('\'');
+        crate::dialect::Postgres::quote_literal(val, buff)
     }
 
+    /// Values are inlined, so there are never any placeholders.
+    fn placeholder(_: usize, _: &mut String) {}
+
     fn quote_ident(name: &str, buff: &mut String) {
         let mut chars = name.chars();
         if chars.next().map(|c| !c.is_ascii_digit()).unwrap_or(true)
@@ -91,39 +214,182 @@ impl Dialect for Display {
         val: crate::value::Value<'a>,
         buff: &mut String,
     ) -> crate::value::Value<'a> {
-        use std::fmt::Write;
-
-        match val {
-            crate::value::Value::Null(..) => buff.push_str("null"),
-            crate::value::Value::Bool(val) => buff.push_str(val.to_string().as_str()),
-            crate::value::Value::TinyInt(val) => buff.push_str(val.to_string().as_str()),
-            crate::value::Value::SmallInt(val) => buff.push_str(val.to_string().as_str()),
-            crate::value::Value::Int(val) => buff.push_str(val.to_string().as_str()),
-            crate::value::Value::BigInt(val) => buff.push_str(val.to_string().as_str()),
-            crate::value::Value::TinyUInt(val) => buff.push_str(val.to_string().as_str()),
-            crate::value::Value::SmallUInt(val) => buff.push_str(val.to_string().as_str()),
-            crate::value::Value::UInt(val) => buff.push_str(val.to_string().as_str()),
-            crate::value::Value::BigUInt(val) => buff.push_str(val.to_string().as_str()),
-            crate::value::Value::Text(val) => Display::quote_literal(val, buff),
-            crate::value::Value::Bytes(val) => {
-                buff.push_str("b\"");
-                for &byte in val.iter() {
-                    let _ = write!(buff, "{:02x}", byte);
-                }
-                buff.push('"');
-            }
-            #[cfg(feature = "use-chrono")]
-            crate::value::Value::DateTime(val) => {
-                buff.push('\'');
-                let _ = write!(buff, "{val}");
-                buff.push('\'');
-            }
+        inline_value::<Display>(&val, buff);
+        val
+    }
+}
 
-            #[cfg(feature = "use-decimal")]
-            crate::value::Value::Numeric(val) => {
-                let _ = write!(buff, "{val}");
-            }
-        };
+/// Write `val` as a literal, quoting strings in the dialect `D`.
+pub(crate) fn inline_value<D: Dialect>(val: &crate::value::Value, buff: &mut String) {
+    use std::fmt::Write;
+
+    match val {
+        crate::value::Value::Null(..) => buff.push_str("NULL"),
+        crate::value::Value::Bool(val) => {
+            let _ = write!(buff, "{val}");
+        }
+        crate::value::Value::TinyInt(val) => {
+            let _ = write!(buff, "{val}");
+        }
+        crate::value::Value::SmallInt(val) => {
+            let _ = write!(buff, "{val}");
+        }
+        crate::value::Value::Int(val) => {
+            let _ = write!(buff, "{val}");
+        }
+        crate::value::Value::BigInt(val) => {
+            let _ = write!(buff, "{val}");
+        }
+        crate::value::Value::TinyUInt(val) => {
+            let _ = write!(buff, "{val}");
+        }
+        crate::value::Value::SmallUInt(val) => {
+            let _ = write!(buff, "{val}");
+        }
+        crate::value::Value::UInt(val) => {
+            let _ = write!(buff, "{val}");
+        }
+        crate::value::Value::BigUInt(val) => {
+            let _ = write!(buff, "{val}");
+        }
+        crate::value::Value::Real(val) => float(*val, buff),
+        crate::value::Value::Double(val) => float(*val, buff),
+        crate::value::Value::Decimal(val) => buff.push_str(&val.0),
+        crate::value::Value::Text(val) => D::quote_literal(val, buff),
+        crate::value::Value::Bytes(val) => D::quote_bytes(val, buff),
+        crate::value::Value::Json(val) => D::quote_literal(&val.0, buff),
+        #[cfg(feature = "use-chrono")]
+        crate::value::Value::Date(val) => {
+            let _ = write!(buff, "'{}'", val.format("%Y-%m-%d"));
+        }
+        #[cfg(feature = "use-chrono")]
+        crate::value::Value::Timestamp(val) => {
+            let _ = write!(buff, "'{}'", val.format("%Y-%m-%dT%H:%M:%S%.f"));
+        }
+        #[cfg(feature = "use-chrono")]
+        crate::value::Value::DateTime(val) => {
+            let _ = write!(buff, "'{}'", val.format("%Y-%m-%dT%H:%M:%S%.fZ"));
+        }
+
+        #[cfg(feature = "use-decimal")]
+        crate::value::Value::Numeric(val) => {
+            let _ = write!(buff, "{val}");
+        }
+    };
+}
+
+/// Write a float literal, keeping the fraction of integral values so that e.g.
+/// `3.0 / 2` is not an integer division.
+fn float<F: Copy + Into<f64> + std::fmt::Debug>(val: F, buff: &mut String) {
+    use std::fmt::Write;
+
+    let wide: f64 = val.into();
+    if wide.is_nan() {
+        buff.push_str("'NaN'");
+    } else if wide.is_infinite() {
+        buff.push_str(if wide > 0.0 { "'Infinity'" } else { "'-Infinity'" });
+    } else {
+        let _ = write!(buff, "{val:?}");
+    }
+}
+
+/// [`Display`] with each clause on its own line, used by the alternate `{:#}`
+/// format.
+pub(crate) struct Pretty;
+
+impl Dialect for Pretty {
+    const ON_DUPLICATE_KEY_UPDATE: bool = true;
+    const PRETTY: bool = true;
+
+    fn quote_literal(val: &str, buff: &mut String) {
+        Display::quote_literal(val, buff)
+    }
+
+    fn quote_ident(name: &str, buff: &mut String) {
+        Display::quote_ident(name, buff)
+    }
+
+    fn quote_bytes(val: &[u8], buff: &mut String) {
+        Display::quote_bytes(val, buff)
+    }
+
+    fn placeholder(n: usize, buff: &mut String) {
+        Display::placeholder(n, buff)
+    }
+
+    fn bind_param<'a>(
+        n: usize,
+        val: crate::value::Value<'a>,
+        buff: &mut String,
+    ) -> crate::value::Value<'a> {
+        Display::bind_param(n, val, buff)
+    }
+}
+
+/// `D` with values written inline as literals, used by
+/// [`RenderMode::Inline`](crate::dialect::RenderMode::Inline).
+pub(crate) struct Inline<D>(std::marker::PhantomData<D>);
+
+impl<D: Dialect> Dialect for Inline<D> {
+    const ON_DUPLICATE_KEY_UPDATE: bool = D::ON_DUPLICATE_KEY_UPDATE;
+    const APPLY: bool = D::APPLY;
+    const TOP: bool = D::TOP;
+    const LOCK: bool = D::LOCK;
+    const NO_LIMIT: Option<&'static str> = D::NO_LIMIT;
+    const LIMIT_OFFSET_COMMA: bool = D::LIMIT_OFFSET_COMMA;
+    const PRETTY: bool = D::PRETTY;
+
+    fn quote_literal(val: &str, buff: &mut String) {
+        D::quote_literal(val, buff)
+    }
+
+    fn quote_ident(name: &str, buff: &mut String) {
+        D::quote_ident(name, buff)
+    }
+
+    fn quote_bytes(val: &[u8], buff: &mut String) {
+        D::quote_bytes(val, buff)
+    }
+
+    /// Values are inlined, so there are never any placeholders.
+    fn placeholder(_: usize, _: &mut String) {}
+
+    fn bind_param<'a>(
+        _: usize,
+        val: crate::value::Value<'a>,
+        buff: &mut String,
+    ) -> crate::value::Value<'a> {
+        inline_value::<D>(&val, buff);
+        val
+    }
+}
+
+/// `D` writing neither identifiers, literals nor placeholders, used by
+/// [`ToSql::params`] where the SQL is thrown away. The layout of `D` is kept,
+/// as it decides the order in which values are bound.
+pub(crate) struct Params<D>(std::marker::PhantomData<D>);
+
+impl<D: Dialect> Dialect for Params<D> {
+    const ON_DUPLICATE_KEY_UPDATE: bool = D::ON_DUPLICATE_KEY_UPDATE;
+    const APPLY: bool = D::APPLY;
+    const TOP: bool = D::TOP;
+    const LOCK: bool = D::LOCK;
+    const NO_LIMIT: Option<&'static str> = D::NO_LIMIT;
+    const LIMIT_OFFSET_COMMA: bool = D::LIMIT_OFFSET_COMMA;
+
+    fn quote_literal(_: &str, _: &mut String) {}
+
+    fn quote_ident(_: &str, _: &mut String) {}
+
+    fn quote_bytes(_: &[u8], _: &mut String) {}
+
+    fn placeholder(_: usize, _: &mut String) {}
+
+    fn bind_param<'a>(
+        _: usize,
+        val: crate::value::Value<'a>,
+        _: &mut String,
+    ) -> crate::value::Value<'a> {
         val
     }
 }
@@ -2,7 +2,7 @@ use super::Dialect;
 use super::ToSql;
 
 impl<'a> ToSql<'a> for crate::stmt::Stmt<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         match self {
             crate::stmt::Stmt::Insert(stmt) => stmt.build::<D>(sql, args),
             crate::stmt::Stmt::Select(stmt) => stmt.build::<D>(sql, args),
@@ -11,100 +11,198 @@ impl<'a> ToSql<'a> for crate::stmt::Stmt<'a> {
             crate::stmt::Stmt::Values(stmt) => stmt.build::<D>(sql, args),
             crate::stmt::Stmt::Binary(stmt) => stmt.build::<D>(sql, args),
             crate::stmt::Stmt::Result(stmt) => stmt.build::<D>(sql, args),
+            crate::stmt::Stmt::Explain(stmt) => stmt.build::<D>(sql, args),
         }
     }
 }
 
 impl<'a> ToSql<'a> for crate::stmt::binary::Binary<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
-        if let Some(with) = self.with {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+        if let Some(with) = &self.with {
             with.build::<D>(sql, args);
-            sql.push(' ')
+            super::clause_sep::<D>(sql);
         }
-        self.left.build::<D>(sql, args);
-        sql.push(' ');
-        sql.push_str(self.op);
-        sql.push(' ');
-        self.right.build::<D>(sql, args);
+        let left = needs_paren(&self.left, &self.op, false);
+        let right = needs_paren(&self.right, &self.op, true);
+        build_operand::<D>(&self.left, left, sql, args);
+        super::clause_sep::<D>(sql);
+        sql.push_str(&self.op);
+        super::clause_sep::<D>(sql);
+        build_operand::<D>(&self.right, right, sql, args);
     }
 }
 
-impl<'a> ToSql<'a> for crate::stmt::select::Select<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
-        if let Some(with) = self.with {
-            with.build::<D>(sql, args);
-            sql.push(' ')
+/// `INTERSECT` binds tighter than `UNION` and `EXCEPT`.
+fn precedence(op: &str) -> u8 {
+    if op.starts_with("INTERSECT") {
+        2
+    } else {
+        1
+    }
+}
+
+/// Check whether an operand of a binary statement must be parenthesized to
+/// keep its meaning.
+fn needs_paren(operand: &crate::stmt::result::Result, op: &str, right: bool) -> bool {
+    if operand.with.is_some()
+        || operand.orders.is_some()
+        || operand.limit.is_some()
+        || operand.offset.is_some()
+        || operand.lock.is_some()
+    {
+        return true;
+    }
+    match &operand.data {
+        crate::stmt::data::Data::Select(stmt) => stmt.with.is_some() || stmt.orders.is_some(),
+        crate::stmt::data::Data::Values(stmt) => stmt.with.is_some(),
+        crate::stmt::data::Data::Binary(stmt) if right => {
+            stmt.with.is_some() || precedence(&stmt.op) <= precedence(op)
         }
-        macro_rules! build_option {
-            ($name:ident) => {
-                if let Some($name) = self.$name {
-                    sql.push(' ');
-                    $name.build::<D>(sql, args);
-                };
-            };
+        crate::stmt::data::Data::Binary(stmt) => {
+            stmt.with.is_some() || precedence(&stmt.op) < precedence(op)
         }
-        self.fields.build::<D>(sql, args);
-        build_option!(tables);
-        build_option!(filter);
-        build_option!(groups);
-        build_option!(having);
-        build_option!(orders);
     }
 }
 
+fn build_operand<'a, D: Dialect>(
+    operand: &crate::stmt::result::Result<'a>,
+    paren: bool,
+    sql: &mut String,
+    args: &mut Vec<crate::value::Value<'a>>,
+) {
+    if paren {
+        super::build_nested::<D, _>(operand, sql, args);
+    } else {
+        operand.build::<D>(sql, args);
+    }
+}
+
+impl<'a> ToSql<'a> for crate::stmt::select::Select<'a> {
+    #[inline]
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+        build_select::<D>(self, None, sql, args);
+    }
+}
+
+/// Build a `SELECT` statement, with `TOP (top)` after `SELECT` if given.
+fn build_select<'a, D: Dialect>(
+    stmt: &crate::stmt::select::Select<'a>,
+    top: Option<&crate::expr::Expr<'a>>,
+    sql: &mut String,
+    args: &mut Vec<crate::value::Value<'a>>,
+) {
+    if let Some(with) = &stmt.with {
+        with.build::<D>(sql, args);
+        super::clause_sep::<D>(sql);
+    }
+    macro_rules! build_option {
+        ($name:ident) => {
+            if let Some($name) = &stmt.$name {
+                super::clause_sep::<D>(sql);
+                $name.build::<D>(sql, args);
+            };
+        };
+    }
+    sql.push_str("SELECT");
+    if let Some(distinct) = &stmt.distinct {
+        sql.push(' ');
+        distinct.build::<D>(sql, args);
+    }
+    if let Some(count) = top {
+        sql.push_str(" TOP (");
+        count.build::<D>(sql, args);
+        sql.push(')');
+    }
+    if !stmt.fields.0.is_empty() {
+        sql.push(' ');
+        join!(D, sql, args, ", ", stmt.fields.0);
+    }
+    // an empty `FROM` is the same as no `FROM` at all
+    if let Some(tables) = stmt.tables.as_ref().filter(|tables| !tables.0.is_empty()) {
+        super::clause_sep::<D>(sql);
+        tables.build::<D>(sql, args);
+    }
+    build_option!(filter);
+    build_option!(groups);
+    build_option!(having);
+    build_option!(windows);
+    build_option!(orders);
+}
+
 impl<'a> ToSql<'a> for crate::stmt::insert::Insert<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
-        if let Some(with) = self.with {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+        if let Some(with) = &self.with {
             with.build::<D>(sql, args);
-            sql.push(' ')
+            super::clause_sep::<D>(sql);
         }
         self.table.build::<D>(sql, args);
-        sql.push(' ');
-        self.values.build::<D>(sql, args);
-        if let Some(returns) = self.returns {
-            sql.push(' ');
+        super::clause_sep::<D>(sql);
+        if self.defaults {
+            sql.push_str("DEFAULT VALUES");
+        } else {
+            self.values.build::<D>(sql, args);
+        }
+        if let Some(set) = self
+            .on_duplicate
+            .as_ref()
+            .filter(|_| D::ON_DUPLICATE_KEY_UPDATE)
+        {
+            super::clause_sep::<D>(sql);
+            sql.push_str("ON DUPLICATE KEY UPDATE ");
+            super::clause::build_assignments::<D>(set, sql, args);
+        }
+        if let Some(on_conflict) = &self.on_conflict {
+            super::clause_sep::<D>(sql);
+            on_conflict.build::<D>(sql, args);
+        }
+        if let Some(returns) = &self.returns {
+            super::clause_sep::<D>(sql);
             returns.build::<D>(sql, args);
         }
     }
 }
 
 impl<'a> ToSql<'a> for crate::stmt::update::Update<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
-        if let Some(with) = self.with {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+        if let Some(with) = &self.with {
             with.build::<D>(sql, args);
-            sql.push(' ')
+            super::clause_sep::<D>(sql);
         }
         self.table.build::<D>(sql, args);
-        sql.push(' ');
+        super::clause_sep::<D>(sql);
         self.set.build::<D>(sql, args);
-        if let Some(from) = self.from {
-            sql.push(' ');
+        if let Some(from) = &self.from {
+            super::clause_sep::<D>(sql);
             from.build::<D>(sql, args);
         }
-        if let Some(filter) = self.filter {
-            sql.push(' ');
+        if let Some(filter) = &self.filter {
+            super::clause_sep::<D>(sql);
             filter.build::<D>(sql, args);
         }
-        if let Some(returns) = self.returns {
-            sql.push(' ');
+        if let Some(returns) = &self.returns {
+            super::clause_sep::<D>(sql);
             returns.build::<D>(sql, args);
         }
     }
 }
 
 impl<'a> ToSql<'a> for crate::stmt::delete::Delete<'a> {
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
-        if let Some(with) = self.with {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+        if let Some(with) = &self.with {
             with.build::<D>(sql, args);
-            sql.push(' ')
+            super::clause_sep::<D>(sql);
         }
         self.table.build::<D>(sql, args);
-        if let Some(filter) = self.filter {
-            sql.push(' ');
+        if let Some(using) = &self.using {
+            super::clause_sep::<D>(sql);
+            using.build::<D>(sql, args);
+        }
+        if let Some(filter) = &self.filter {
+            super::clause_sep::<D>(sql);
             filter.build::<D>(sql, args);
         }
-        if let Some(returns) = self.returns {
-            sql.push(' ');
+        if let Some(returns) = &self.returns {
+            super::clause_sep::<D>(sql);
             returns.build::<D>(sql, args);
         }
     }
@@ -112,10 +210,10 @@ impl<'a> ToSql<'a> for crate::stmt::delete::Delete<'a> {
 
 impl<'a> ToSql<'a> for crate::stmt::values::Values<'a> {
     #[inline]
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
-        if let Some(with) = self.with {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+        if let Some(with) = &self.with {
             with.build::<D>(sql, args);
-            sql.push(' ')
+            super::clause_sep::<D>(sql);
         }
         self.rows.build::<D>(sql, args);
     }
@@ -123,26 +221,86 @@ impl<'a> ToSql<'a> for crate::stmt::values::Values<'a> {
 
 impl<'a> ToSql<'a> for crate::stmt::result::Result<'a> {
     #[inline]
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
-        if let Some(with) = self.with {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+        if let Some(with) = &self.with {
             with.build::<D>(sql, args);
-            sql.push(' ')
+            super::clause_sep::<D>(sql);
+        }
+        // `LIMIT ALL` is the same as no limit at all
+        let count = self.limit.as_ref().and_then(|limit| limit.0.as_ref());
+        // `TOP` can't be combined with an offset, nor applied to a compound
+        // statement without wrapping it.
+        let top = D::TOP && self.offset.is_none();
+        match &self.data {
+            crate::stmt::data::Data::Select(stmt) if top => {
+                build_select::<D>(stmt, count, sql, args)
+            }
+            data => data.build::<D>(sql, args),
         }
-        self.data.build::<D>(sql, args);
-        if let Some(limit) = self.limit {
-            sql.push(' ');
-            limit.build::<D>(sql, args);
+        if let Some(orders) = &self.orders {
+            super::clause_sep::<D>(sql);
+            orders.build::<D>(sql, args);
+        }
+        if !D::TOP {
+            let limited = self.limit.is_some() || (self.offset.is_some() && D::NO_LIMIT.is_some());
+            if limited {
+                super::clause_sep::<D>(sql);
+                sql.push_str("LIMIT ");
+                if let Some(offset) = self.offset.as_ref().filter(|_| D::LIMIT_OFFSET_COMMA) {
+                    offset.0.build::<D>(sql, args);
+                    sql.push_str(", ");
+                }
+                match (count, D::NO_LIMIT) {
+                    (Some(count), _) => count.build::<D>(sql, args),
+                    (None, Some(max)) => sql.push_str(max),
+                    (None, None) => sql.push_str("ALL"),
+                }
+            }
+            if let Some(offset) = self
+                .offset
+                .as_ref()
+                .filter(|_| !(limited && D::LIMIT_OFFSET_COMMA))
+            {
+                super::clause_sep::<D>(sql);
+                offset.build::<D>(sql, args);
+            }
+        } else if !top
+            || (count.is_some() && !matches!(self.data, crate::stmt::data::Data::Select(_)))
+        {
+            // `OFFSET` is only accepted after `ORDER BY`, so sort by a
+            // constant when there is no order.
+            let ordered = match &self.data {
+                crate::stmt::data::Data::Select(stmt) => stmt.orders.is_some(),
+                _ => false,
+            };
+            if !ordered && self.orders.is_none() {
+                super::clause_sep::<D>(sql);
+                sql.push_str("ORDER BY (SELECT NULL)");
+            }
+            super::clause_sep::<D>(sql);
+            sql.push_str("OFFSET ");
+            match &self.offset {
+                Some(offset) => offset.0.build::<D>(sql, args),
+                None => sql.push('0'),
+            }
+            sql.push_str(" ROWS");
+            if let Some(count) = count {
+                super::clause_sep::<D>(sql);
+                sql.push_str("FETCH NEXT ");
+                count.build::<D>(sql, args);
+                sql.push_str(" ROWS ONLY");
+            }
         }
-        if let Some(offset) = self.offset {
-            sql.push(' ');
-            offset.build::<D>(sql, args);
+        if let Some(lock) = self.lock.as_ref().filter(|_| D::LOCK) {
+            super::clause_sep::<D>(sql);
+            lock.build::<D>(sql, args);
         }
     }
 }
 
 impl<'a> ToSql<'a> for crate::stmt::data::Data<'a> {
     #[inline]
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         match self {
             crate::stmt::data::Data::Select(stmt) => stmt.build::<D>(sql, args),
             crate::stmt::data::Data::Values(stmt) => stmt.build::<D>(sql, args),
@@ -150,3 +308,32 @@ impl<'a> ToSql<'a> for crate::stmt::data::Data<'a> {
         }
     }
 }
+
+impl<'a> ToSql<'a> for crate::stmt::explain::Explain<'a> {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+        sql.push_str("EXPLAIN");
+        if self.options.is_empty() {
+            if self.analyze {
+                sql.push_str(" ANALYZE");
+            }
+        } else {
+            sql.push_str(" (");
+            if self.analyze {
+                sql.push_str("ANALYZE, ");
+            }
+            for (i, (name, value)) in self.options.iter().enumerate() {
+                if i > 0 {
+                    sql.push_str(", ");
+                }
+                sql.push_str(name);
+                if let Some(value) = value {
+                    sql.push(' ');
+                    sql.push_str(value);
+                }
+            }
+            sql.push(')');
+        }
+        super::clause_sep::<D>(sql);
+        self.stmt.build::<D>(sql, args);
+    }
+}
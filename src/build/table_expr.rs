@@ -3,42 +3,73 @@ use super::ToSql;
 
 impl<'a> ToSql<'a> for crate::table_expr::TableExpr<'a> {
     #[inline]
-    fn build<D: Dialect>(self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
+    fn build<D: Dialect>(&self, sql: &mut String, args: &mut Vec<crate::value::Value<'a>>) {
         match self {
             crate::table_expr::TableExpr::TableRef(val) => val.build::<D>(sql, args),
             crate::table_expr::TableExpr::FuncCall(val) => val.build::<D>(sql, args),
-            crate::table_expr::TableExpr::Join(left, right, cond) => {
-                join!(D, sql, args, left, "JOIN", right, cond)
+            crate::table_expr::TableExpr::Join(left, kind, right, cond) => {
+                let natural = matches!(cond, crate::item::JoinCond::Natural);
+                left.build::<D>(sql, args);
+                sql.push(' ');
+                if natural {
+                    sql.push_str("NATURAL ");
+                }
+                kind.build::<D>(sql, args);
+                sql.push(' ');
+                right.build::<D>(sql, args);
+                if !natural {
+                    sql.push(' ');
+                    cond.build::<D>(sql, args);
+                }
             }
-            crate::table_expr::TableExpr::LeftJoin(left, right, cond) => {
-                join!(D, sql, args, left, "LEFT JOIN", right, cond)
-            }
-            crate::table_expr::TableExpr::RightJoin(left, right, cond) => {
-                join!(D, sql, args, left, "RIGHT JOIN", right, cond)
-            }
-            crate::table_expr::TableExpr::FullJoin(left, right, cond) => {
-                join!(D, sql, args, left, "FULL JOIN", right, cond)
+            crate::table_expr::TableExpr::CrossJoin(left, right) => {
+                join!(D, sql, args, left, "CROSS JOIN", right)
             }
-            crate::table_expr::TableExpr::NaturalJoin(left, right) => {
-                join!(D, sql, args, left, "NATURAL JOIN", right)
+            crate::table_expr::TableExpr::CrossApply(left, right) => {
+                build_apply::<D>(left, "CROSS APPLY", "CROSS JOIN", right, sql, args)
             }
-            crate::table_expr::TableExpr::NaturalLeftJoin(left, right) => {
-                join!(D, sql, args, left, "NATURAL LEFT JOIN", right)
+            crate::table_expr::TableExpr::OuterApply(left, right) => {
+                build_apply::<D>(left, "OUTER APPLY", "LEFT JOIN", right, sql, args);
+                if !D::APPLY {
+                    sql.push_str(" ON TRUE");
+                }
             }
-            crate::table_expr::TableExpr::NaturalRightJoin(left, right) => {
-                join!(D, sql, args, left, "NATURAL RIGHT JOIN", right)
+            crate::table_expr::TableExpr::Lateral(val) => {
+                sql.push_str("LATERAL ");
+                val.build::<D>(sql, args);
             }
-            crate::table_expr::TableExpr::NaturalFullJoin(left, right) => {
-                join!(D, sql, args, left, "NATURAL FULL JOIN", right)
-            }
-            crate::table_expr::TableExpr::CrossJoin(left, right) => {
-                join!(D, sql, args, left, "CROSS JOIN", right)
+            crate::table_expr::TableExpr::Values(rows, _) => {
+                super::build_nested::<D, _>(rows, sql, args)
             }
+            crate::table_expr::TableExpr::Raw(val) => sql.push_str(val),
             crate::table_expr::TableExpr::SubQuery(val) => {
-                sql.push('(');
-                val.build::<D>(sql, args);
-                sql.push(')');
+                super::build_nested::<D, _>(val, sql, args)
             }
         }
     }
 }
+
+/// Build an `APPLY` operation, or the `LATERAL` join it's equivalent to if the
+/// dialect doesn't support it.
+fn build_apply<'a, D: Dialect>(
+    left: &crate::item::Table<'a>,
+    apply: &str,
+    join: &str,
+    right: &crate::item::Table<'a>,
+    sql: &mut String,
+    args: &mut Vec<crate::value::Value<'a>>,
+) {
+    left.build::<D>(sql, args);
+    sql.push(' ');
+    if D::APPLY {
+        sql.push_str(apply);
+        sql.push(' ');
+    } else {
+        sql.push_str(join);
+        sql.push(' ');
+        if !matches!(right.table, crate::table_expr::TableExpr::Lateral(_)) {
+            sql.push_str("LATERAL ");
+        }
+    }
+    right.build::<D>(sql, args);
+}
@@ -1,7 +1,9 @@
 use crate::expr::Expr;
 use crate::item::Cte;
 use crate::item::Field;
+use crate::item::Grouping;
 use crate::item::Ident;
+use crate::item::Keyword;
 use crate::item::Order;
 use crate::item::Row;
 use crate::item::Table;
@@ -9,6 +11,8 @@ use crate::item::TableRef;
 
 /// Represent a `WITH` clause.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 pub struct With<'a>(pub(crate) bool, pub(crate) Vec<Cte<'a>>);
 
 crate::macros::gen_display!(With<'_>);
@@ -35,6 +39,8 @@ where
 
 /// Represent a `SELECT` clause.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 #[repr(transparent)]
 pub struct Select<'a>(pub(crate) Vec<Field<'a>>);
 
@@ -43,8 +49,51 @@ crate::macros::gen_impl_from_arr!(Select[Field]<'a>);
 crate::macros::gen_impl_from_vec!(Select[Field]<'a>);
 crate::macros::gen_impl_from_tup!(Select[Field]<'a>);
 
+impl<'a> Select<'a> {
+    /// Drop the fields named any of `columns`, by their alias or otherwise the
+    /// name of the column they select. Other expressions are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::clause::Select;
+    ///
+    /// let clause: Select = ["id", "name", "password"].into();
+    /// assert_eq!(clause.exclude(["password"]).to_string(), "SELECT id, name");
+    /// ```
+    pub fn exclude<C, I>(mut self, columns: I) -> Select<'a>
+    where
+        C: Into<Ident<'a>>,
+        I: IntoIterator<Item = C>,
+    {
+        let columns: Vec<Ident> = columns.into_iter().map(Into::into).collect();
+        self.0.retain(|field| {
+            let name = match (&field.alias, &field.expr) {
+                (Some(alias), _) => Some(alias.clone()),
+                (None, Expr::Column(col)) => Ident::try_from(col.clone()).ok(),
+                (None, _) => None,
+            };
+            name.is_none_or(|name| !columns.contains(&name))
+        });
+        self
+    }
+}
+
+/// Represent a `DISTINCT` or `DISTINCT ON` clause inside `SELECT` statement.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
+#[repr(transparent)]
+pub struct Distinct<'a>(pub(crate) Vec<Expr<'a>>);
+
+crate::macros::gen_display!(Distinct<'_>);
+crate::macros::gen_impl_from_arr!(Distinct[Expr]<'a>);
+crate::macros::gen_impl_from_vec!(Distinct[Expr]<'a>);
+
 /// Represent a `FROM` clause.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 #[repr(transparent)]
 pub struct From<'a>(pub(crate) Vec<Table<'a>>);
 
@@ -62,8 +111,31 @@ crate::macros::gen_display!(From<'_>);
 crate::macros::gen_impl_from_arr!(From[Table]<'a>);
 crate::macros::gen_impl_from_vec!(From[Table]<'a>);
 
+/// Represent a `USING` clause of `DELETE` statement.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
+#[repr(transparent)]
+pub struct Using<'a>(pub(crate) Vec<Table<'a>>);
+
+impl<'a, T> std::convert::From<T> for Using<'a>
+where
+    T: Into<Table<'a>>,
+{
+    #[inline]
+    fn from(val: T) -> Self {
+        Using(vec![val.into()])
+    }
+}
+
+crate::macros::gen_display!(Using<'_>);
+crate::macros::gen_impl_from_arr!(Using[Table]<'a>);
+crate::macros::gen_impl_from_vec!(Using[Table]<'a>);
+
 /// Represent a `WHERE` clause.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 #[repr(transparent)]
 pub struct Where<'a>(pub(crate) Expr<'a>);
 
@@ -81,12 +153,14 @@ where
 
 /// Represent a `GROUP BY` clause.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 #[repr(transparent)]
-pub struct GroupBy<'a>(pub(crate) Vec<Expr<'a>>);
+pub struct GroupBy<'a>(pub(crate) Vec<Grouping<'a>>);
 
 impl<'a, T> std::convert::From<T> for GroupBy<'a>
 where
-    T: Into<Expr<'a>>,
+    T: Into<Grouping<'a>>,
 {
     #[inline]
     fn from(val: T) -> Self {
@@ -95,11 +169,13 @@ where
 }
 
 crate::macros::gen_display!(GroupBy<'_>);
-crate::macros::gen_impl_from_arr!(GroupBy[Expr]<'a>);
-crate::macros::gen_impl_from_vec!(GroupBy[Expr]<'a>);
+crate::macros::gen_impl_from_arr!(GroupBy[Grouping]<'a>);
+crate::macros::gen_impl_from_vec!(GroupBy[Grouping]<'a>);
 
 /// Represent a `HAVING` clause.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 #[repr(transparent)]
 pub struct Having<'a>(pub(crate) Expr<'a>);
 
@@ -115,8 +191,19 @@ where
     }
 }
 
+/// Represent a `WINDOW` clause.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
+#[repr(transparent)]
+pub struct Window<'a>(pub(crate) Vec<(Ident<'a>, crate::item::Window<'a>)>);
+
+crate::macros::gen_display!(Window<'_>);
+
 /// Represent a `ORDER BY` clause.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 #[repr(transparent)]
 pub struct OrderBy<'a>(pub(crate) Vec<Order<'a>>);
 
@@ -136,12 +223,16 @@ crate::macros::gen_impl_from_vec!(OrderBy[Order]<'a>);
 
 /// Represent a `INSERT` clause.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 pub struct Insert<'a>(pub(crate) TableRef<'a>, pub(crate) Vec<Ident<'a>>);
 
 crate::macros::gen_display!(Insert<'_>);
 
 /// Represent a `VALUES` clause.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 #[repr(transparent)]
 pub struct Values<'a>(pub(crate) Vec<Row<'a>>);
 
@@ -152,6 +243,8 @@ crate::macros::gen_impl_from_tup!(Values[Row]<'a>);
 
 /// Represent a `VALUES` clause.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 #[repr(transparent)]
 pub struct Returning<'a>(pub(crate) Vec<Field<'a>>);
 
@@ -162,6 +255,8 @@ crate::macros::gen_impl_from_tup!(Returning[Field]<'a>);
 
 /// Represent a `DELETE` clause.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 #[repr(transparent)]
 pub struct Delete<'a>(pub(crate) TableRef<'a>);
 
@@ -179,6 +274,8 @@ where
 
 /// Represent a `UPDATE` clause.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 #[repr(transparent)]
 pub struct Update<'a>(pub(crate) TableRef<'a>);
 
@@ -194,8 +291,25 @@ where
     }
 }
 
+/// Represent an `ON CONFLICT` clause inside `INSERT` statement.
+///
+/// It holds the conflict target columns, the predicate of a partial unique
+/// index and the columns to update, doing nothing if there are none.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
+pub struct OnConflict<'a>(
+    pub(crate) Vec<Ident<'a>>,
+    pub(crate) Option<Expr<'a>>,
+    pub(crate) Option<Set<'a>>,
+);
+
+crate::macros::gen_display!(OnConflict<'_>);
+
 /// Represent a `SET` clause inside `UPDATE` statement.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 #[repr(transparent)]
 pub struct Set<'a>(pub(crate) Vec<(Ident<'a>, Expr<'a>)>);
 
@@ -229,33 +343,69 @@ where
     }
 }
 
-/// Represent a `LIMIT` clause.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Represent a `LIMIT` clause, `LIMIT ALL` if there is no count.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 #[repr(transparent)]
-pub struct Limit(pub(crate) u32);
+pub struct Limit<'a>(pub(crate) Option<Expr<'a>>);
 
-crate::macros::gen_display!(Limit);
+crate::macros::gen_display!(Limit<'_>);
 
-impl std::convert::From<u32> for Limit {
+impl<'a, E> std::convert::From<E> for Limit<'a>
+where
+    E: Into<Expr<'a>>,
+{
     #[inline]
-    fn from(val: u32) -> Self {
-        Limit(val)
+    fn from(val: E) -> Self {
+        Limit(Some(count(val.into())))
     }
 }
 
 /// Represent a `OFFSET` clause.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 #[repr(transparent)]
-pub struct Offset(pub(crate) u32);
+pub struct Offset<'a>(pub(crate) Expr<'a>);
 
-crate::macros::gen_display!(Offset);
+crate::macros::gen_display!(Offset<'_>);
 
-impl std::convert::From<u32> for Offset {
+impl<'a, E> std::convert::From<E> for Offset<'a>
+where
+    E: Into<Expr<'a>>,
+{
     #[inline]
-    fn from(val: u32) -> Self {
-        Offset(val)
+    fn from(val: E) -> Self {
+        Offset(count(val.into()))
     }
 }
+
+/// Widen unsigned literals of `LIMIT` and `OFFSET` to `BIGINT`, since Postgres
+/// can't bind unsigned integers.
+fn count(expr: Expr<'_>) -> Expr<'_> {
+    use crate::value::Value;
+
+    match expr {
+        Expr::Literal(Value::TinyUInt(val)) => Expr::Literal(Value::BigInt(val.into())),
+        Expr::Literal(Value::SmallUInt(val)) => Expr::Literal(Value::BigInt(val.into())),
+        Expr::Literal(Value::UInt(val)) => Expr::Literal(Value::BigInt(val.into())),
+        expr => expr,
+    }
+}
+
+/// Represent a locking clause (`FOR UPDATE`, `FOR SHARE`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
+pub struct Lock<'a>(
+    pub(crate) Keyword,
+    pub(crate) Vec<Ident<'a>>,
+    pub(crate) Option<Keyword>,
+);
+
+crate::macros::gen_display!(Lock<'_>);
+
 #[cfg(test)]
 mod tests {
     use crate::clause::From;
@@ -267,6 +417,7 @@ mod tests {
     use crate::expr::Expr;
     use crate::item::ColumnRef;
     use crate::item::Field;
+    use crate::item::Grouping;
     use crate::item::Ident;
     use crate::item::Order;
     use crate::item::Sort;
@@ -282,7 +433,7 @@ mod tests {
             clause,
             Select(vec![Field {
                 alias: None,
-                expr: Expr::Column(ColumnRef::Column(Ident("id")))
+                expr: Expr::Column(ColumnRef::Column(Ident::from("id")))
             }])
         );
         assert_eq!(clause.to_string(), "SELECT id");
@@ -293,17 +444,39 @@ mod tests {
             Select(vec![
                 Field {
                     alias: None,
-                    expr: Expr::Column(ColumnRef::Column(Ident("id"))),
+                    expr: Expr::Column(ColumnRef::Column(Ident::from("id"))),
                 },
                 Field {
                     alias: None,
-                    expr: Expr::Column(ColumnRef::TableColumn(Ident("user"), Ident("name"),)),
+                    expr: Expr::Column(ColumnRef::TableColumn(
+                        Ident::from("user"),
+                        Ident::from("name"),
+                    )),
                 },
             ])
         );
         assert_eq!(clause.to_string(), "SELECT id, user.name");
     }
 
+    #[test]
+    fn exclude() {
+        let clause: Select = (
+            "id",
+            ("user", "name"),
+            ("user", "password"),
+            crate::ops::as_field("salt", "password"),
+            crate::func::count("password"),
+        )
+            .into();
+        assert_eq!(
+            clause.exclude(["password"]).to_string(),
+            "SELECT id, user.name, COUNT(password)"
+        );
+
+        let clause: Select = [crate::ops::star(), crate::ops::star_of("user")].into();
+        assert_eq!(clause.exclude(["id"]).to_string(), "SELECT *, user.*");
+    }
+
     #[test]
     fn from() {
         let mut clause: From = ["user"].into();
@@ -311,7 +484,8 @@ mod tests {
             clause,
             From(vec![Table {
                 alias: None,
-                table: TableExpr::TableRef(TableRef::Table(Ident("user"))),
+                sample: None,
+                table: TableExpr::TableRef(TableRef::Table(Ident::from("user"))),
             }])
         );
         assert_eq!(clause.to_string(), "FROM user");
@@ -322,13 +496,15 @@ mod tests {
             From(vec![
                 Table {
                     alias: None,
-                    table: TableExpr::TableRef(TableRef::Table(Ident("user")))
+                    sample: None,
+                    table: TableExpr::TableRef(TableRef::Table(Ident::from("user")))
                 },
                 Table {
                     alias: None,
+                    sample: None,
                     table: TableExpr::TableRef(TableRef::SchemaTable(
-                        Ident("public"),
-                        Ident("contact")
+                        Ident::from("public"),
+                        Ident::from("contact")
                     ))
                 },
             ])
@@ -348,7 +524,9 @@ mod tests {
         let mut clause: GroupBy = ["id"].into();
         assert_eq!(
             clause,
-            GroupBy(vec![Expr::Column(ColumnRef::Column(Ident("id")))])
+            GroupBy(vec![Grouping::Expr(Expr::Column(ColumnRef::Column(
+                Ident::from("id")
+            )))])
         );
         assert_eq!(clause.to_string(), "GROUP BY id");
 
@@ -356,8 +534,11 @@ mod tests {
         assert_eq!(
             clause,
             GroupBy(vec![
-                Expr::Column(ColumnRef::Column(Ident("id"))),
-                Expr::Column(ColumnRef::TableColumn(Ident("user"), Ident("name")))
+                Grouping::Expr(Expr::Column(ColumnRef::Column(Ident::from("id")))),
+                Grouping::Expr(Expr::Column(ColumnRef::TableColumn(
+                    Ident::from("user"),
+                    Ident::from("name")
+                )))
             ])
         );
         assert_eq!(clause.to_string(), "GROUP BY id, user.name")
@@ -376,7 +557,8 @@ mod tests {
         assert_eq!(
             clause,
             OrderBy(vec![Order(
-                Expr::Column(ColumnRef::Column(Ident("id"))),
+                Expr::Column(ColumnRef::Column(Ident::from("id"))),
+                None,
                 None
             )]),
         );
@@ -389,13 +571,98 @@ mod tests {
         assert_eq!(
             clause,
             OrderBy(vec![
-                Order(Expr::Column(ColumnRef::Column(Ident("id"))), None),
                 Order(
-                    Expr::Column(ColumnRef::TableColumn(Ident("user"), Ident("name"),)),
-                    Some(Sort::Desc)
+                    Expr::Column(ColumnRef::Column(Ident::from("id"))),
+                    None,
+                    None
+                ),
+                Order(
+                    Expr::Column(ColumnRef::TableColumn(
+                        Ident::from("user"),
+                        Ident::from("name"),
+                    )),
+                    Some(Sort::Desc),
+                    None
                 )
             ]),
         );
         assert_eq!(clause.to_string(), "ORDER BY id, user.name DESC")
     }
+
+    #[test]
+    fn nulls() {
+        use crate::ops::{asc, desc};
+
+        let clause: OrderBy = asc("a").nulls_first().into();
+        assert_eq!(clause.to_string(), "ORDER BY a ASC NULLS FIRST");
+        let clause: OrderBy = asc("a").nulls_last().into();
+        assert_eq!(clause.to_string(), "ORDER BY a ASC NULLS LAST");
+        let clause: OrderBy = desc("a").nulls_first().into();
+        assert_eq!(clause.to_string(), "ORDER BY a DESC NULLS FIRST");
+        let clause: OrderBy = desc("a").nulls_last().into();
+        assert_eq!(clause.to_string(), "ORDER BY a DESC NULLS LAST");
+
+        let clause: OrderBy = [Order::from("a").nulls_last(), desc("b")].into();
+        assert_eq!(clause.to_string(), "ORDER BY a NULLS LAST, b DESC");
+    }
+
+    #[test]
+    fn ordinal() {
+        use crate::dialect::Postgres;
+        use crate::ops::{desc, ordinal};
+        use crate::stmt::select;
+        use crate::value::Value;
+
+        let clause: OrderBy = [ordinal(1).into(), desc(ordinal(2))].into();
+        assert_eq!(clause.to_string(), "ORDER BY 1, 2 DESC");
+
+        // an integer literal renders the same, but is bound as a parameter
+        let query = select(["a", "b"]).from("t").order_by([1]);
+        assert_eq!(query.to_string(), "SELECT a, b FROM t ORDER BY 1");
+        let (sql, args) = query.to_sql(Postgres);
+        assert_eq!(sql, r#"SELECT "a", "b" FROM "t" ORDER BY $1"#);
+        assert_eq!(args, [Value::Int(1)]);
+
+        let query = select(["a", "b"])
+            .from("t")
+            .group_by(ordinal(1))
+            .order_by([ordinal(1)]);
+        let (sql, args) = query.to_sql(Postgres);
+        assert_eq!(sql, r#"SELECT "a", "b" FROM "t" GROUP BY 1 ORDER BY 1"#);
+        assert!(args.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "ordinal position starts from 1")]
+    fn ordinal_zero() {
+        crate::ops::ordinal(0);
+    }
+
+    #[test]
+    fn large_tuples() {
+        let clause: Select = (
+            "c1", "c2", "c3", "c4", "c5", "c6", "c7", "c8", "c9", "c10", "c11", "c12", "c13",
+            "c14", "c15", 16,
+        )
+            .into();
+        assert_eq!(clause.0.len(), 16);
+        assert_eq!(
+            clause.to_string(),
+            "SELECT c1, c2, c3, c4, c5, c6, c7, c8, c9, c10, c11, c12, c13, c14, c15, 16"
+        );
+
+        let clause: crate::clause::Returning = (
+            "c1", "c2", "c3", "c4", "c5", "c6", "c7", "c8", "c9", "c10", "c11", "c12", "c13",
+            "c14", "c15", "c16",
+        )
+            .into();
+        assert_eq!(clause.0.len(), 16);
+
+        let clause: crate::clause::Values =
+            ((1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, true),).into();
+        assert_eq!(
+            clause.to_string(),
+            "VALUES (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, true)"
+        );
+    }
 }
@@ -0,0 +1,720 @@
+//! SQL dialects a statement can be rendered into.
+//!
+//! A dialect decides how identifiers are quoted and how values are bound to
+//! placeholders when calling `to_sql` on a statement.
+
+pub use crate::build::Dialect;
+
+use crate::value::Null;
+use crate::value::Value;
+
+/// Whether values are bound to placeholders or written inline.
+///
+/// The same statement can be rendered with placeholders to run it, and with
+/// literals to log it. Displaying a statement always writes values inline.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::dialect::Postgres;
+/// use qians_xql::dialect::RenderMode;
+/// use qians_xql::eq;
+/// use qians_xql::select;
+/// use qians_xql::value::Value;
+///
+/// let name = "a'b".to_string();
+/// let query = select(["id"]).from("user").filter(eq("name", &name));
+///
+/// let (sql, args) = query.to_sql_with(Postgres, RenderMode::Params);
+/// assert_eq!(sql, r#"SELECT "id" FROM "user" WHERE "name" = $1"#);
+/// assert_eq!(args, [Value::Text("a'b".into())]);
+///
+/// let (sql, args) = query.to_sql_with(Postgres, RenderMode::Inline);
+/// assert_eq!(sql, r#"SELECT "id" FROM "user" WHERE "name" = 'a''b'"#);
+/// assert!(args.is_empty());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RenderMode {
+    /// Write values as literals quoted for the dialect.
+    Inline,
+    /// Bind values to placeholders and return them along with the SQL.
+    #[default]
+    Params,
+}
+
+/// Quote `val` with `q`, doubling any `q` inside it.
+fn quote(buff: &mut String, val: &str, q: char) {
+    buff.push(q);
+    for ch in val.chars() {
+        if ch == q {
+            buff.push(q);
+        }
+        buff.push(ch);
+    }
+    buff.push(q);
+}
+
+/// Write `val` as lowercase hex digits, two per byte.
+pub(crate) fn hex(buff: &mut String, val: &[u8]) {
+    use std::fmt::Write;
+
+    for byte in val {
+        let _ = write!(buff, "{byte:02x}");
+    }
+}
+
+/// Single quote `val`, doubling any quote or backslash inside it.
+fn quote_escaped(buff: &mut String, val: &str) {
+    buff.push('\'');
+    for ch in val.chars() {
+        if ch == '\'' || ch == '\\' {
+            buff.push(ch);
+        }
+        buff.push(ch);
+    }
+    buff.push('\'');
+}
+
+/// Postgres dialect.
+///
+/// Identifiers are double quoted and values are bound to `$1`, `$2`, ...
+/// placeholders.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::dialect::Postgres;
+/// use qians_xql::select;
+///
+/// let (sql, _) = select(["id"]).from("user").to_sql(Postgres);
+/// assert_eq!(sql, r#"SELECT "id" FROM "user""#);
+///
+/// let (sql, _) = select([r#"a"b"#]).to_sql(Postgres);
+/// assert_eq!(sql, r#"SELECT "a""b""#);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Postgres;
+
+impl Dialect for Postgres {
+    /// Strings containing backslashes are written as escape strings, e.g.
+    /// `E'a\\b'`, so they mean the same whatever `standard_conforming_strings`
+    /// is set to.
+    fn quote_literal(val: &str, buff: &mut String) {
+        if val.contains('\\') {
+            buff.push('E');
+            quote_escaped(buff, val);
+        } else {
+            quote(buff, val, '\'');
+        }
+    }
+
+    fn quote_ident(val: &str, buff: &mut String) {
+        quote(buff, val, '"')
+    }
+
+    fn placeholder(n: usize, buff: &mut String) {
+        buff.push('$');
+        buff.push_str(n.to_string().as_str());
+    }
+
+    /// Decimals and JSON documents are sent as text, so their placeholders are
+    /// cast to `numeric` and `jsonb` respectively.
+    fn bind_param<'a>(n: usize, val: Value<'a>, buff: &mut String) -> Value<'a> {
+        Self::placeholder(n, buff);
+        match val {
+            Value::Decimal(..) | Value::Null(Null::Decimal(..)) => buff.push_str("::numeric"),
+            Value::Json(..) | Value::Null(Null::Json(..)) => buff.push_str("::jsonb"),
+            _ => {}
+        }
+        val
+    }
+}
+
+/// MySQL dialect.
+///
+/// Identifiers are quoted with backticks and values are bound to `?`
+/// placeholders.
+///
+/// MySQL has neither `LIMIT ALL` nor `OFFSET` without `LIMIT`, so the largest
+/// unsigned 64-bit integer is written as the count for no limit. See
+/// [`MySqlLimitComma`] for offsets written as `LIMIT offset, count`.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::dialect::MySql;
+/// use qians_xql::select;
+///
+/// let (sql, _) = select(["id"]).from("user").to_sql(MySql);
+/// assert_eq!(sql, "SELECT `id` FROM `user`");
+///
+/// let (sql, _) = select(["a`b"]).to_sql(MySql);
+/// assert_eq!(sql, "SELECT `a``b`");
+///
+/// let (sql, _) = select(["id"]).from("user").pagination(10, 20).to_sql(MySql);
+/// assert_eq!(sql, "SELECT `id` FROM `user` LIMIT ? OFFSET ?");
+///
+/// let (sql, _) = select(["id"]).from("user").offset(20).to_sql(MySql);
+/// assert_eq!(sql, "SELECT `id` FROM `user` LIMIT 18446744073709551615 OFFSET ?");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MySql;
+
+impl Dialect for MySql {
+    const ON_DUPLICATE_KEY_UPDATE: bool = true;
+    const NO_LIMIT: Option<&'static str> = Some("18446744073709551615");
+
+    /// Backslashes are escaped as well, since MySQL treats them as escape
+    /// characters unless `NO_BACKSLASH_ESCAPES` is enabled.
+    fn quote_literal(val: &str, buff: &mut String) {
+        quote_escaped(buff, val)
+    }
+
+    fn quote_ident(val: &str, buff: &mut String) {
+        quote(buff, val, '`')
+    }
+
+    fn quote_bytes(val: &[u8], buff: &mut String) {
+        buff.push_str("X'");
+        hex(buff, val);
+        buff.push('\'');
+    }
+
+    fn placeholder(_: usize, buff: &mut String) {
+        buff.push('?');
+    }
+}
+
+/// MySQL dialect writing an offset as `LIMIT offset, count`, the other form
+/// MySQL accepts, otherwise the same as [`MySql`].
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::dialect::MySqlLimitComma;
+/// use qians_xql::select;
+/// use qians_xql::value::Value;
+///
+/// let (sql, args) = select(["id"]).from("user").pagination(10, 20).to_sql(MySqlLimitComma);
+/// assert_eq!(sql, "SELECT `id` FROM `user` LIMIT ?, ?");
+/// assert_eq!(args, [Value::Int(20), Value::Int(10)]);
+///
+/// let (sql, _) = select(["id"]).from("user").offset(20).to_sql(MySqlLimitComma);
+/// assert_eq!(sql, "SELECT `id` FROM `user` LIMIT ?, 18446744073709551615");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MySqlLimitComma;
+
+impl Dialect for MySqlLimitComma {
+    const ON_DUPLICATE_KEY_UPDATE: bool = MySql::ON_DUPLICATE_KEY_UPDATE;
+    const NO_LIMIT: Option<&'static str> = MySql::NO_LIMIT;
+    const LIMIT_OFFSET_COMMA: bool = true;
+
+    fn quote_literal(val: &str, buff: &mut String) {
+        MySql::quote_literal(val, buff)
+    }
+
+    fn quote_ident(val: &str, buff: &mut String) {
+        MySql::quote_ident(val, buff)
+    }
+
+    fn quote_bytes(val: &[u8], buff: &mut String) {
+        MySql::quote_bytes(val, buff)
+    }
+
+    fn placeholder(n: usize, buff: &mut String) {
+        MySql::placeholder(n, buff)
+    }
+}
+
+/// SQLite dialect.
+///
+/// Identifiers are double quoted and values are bound to `?` placeholders.
+///
+/// SQLite has neither `LIMIT ALL` nor `OFFSET` without `LIMIT`, so `-1` is
+/// written as the count for no limit.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::dialect::Sqlite;
+/// use qians_xql::eq;
+/// use qians_xql::select;
+/// use qians_xql::value::Value;
+///
+/// let (sql, args) = select(["id"]).from("user").filter(eq("age", 18)).to_sql(Sqlite);
+/// assert_eq!(sql, r#"SELECT "id" FROM "user" WHERE "age" = ?"#);
+/// assert_eq!(args, [Value::Int(18)]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Sqlite;
+
+impl Dialect for Sqlite {
+    const NO_LIMIT: Option<&'static str> = Some("-1");
+
+    fn quote_literal(val: &str, buff: &mut String) {
+        quote(buff, val, '\'')
+    }
+
+    fn quote_ident(val: &str, buff: &mut String) {
+        quote(buff, val, '"')
+    }
+
+    fn quote_bytes(val: &[u8], buff: &mut String) {
+        buff.push_str("X'");
+        hex(buff, val);
+        buff.push('\'');
+    }
+
+    fn placeholder(_: usize, buff: &mut String) {
+        buff.push('?');
+    }
+}
+
+/// SQL Server dialect.
+///
+/// Identifiers are quoted with square brackets and values are bound to `@p1`,
+/// `@p2`, ... placeholders. `CROSS APPLY` and `OUTER APPLY` are rendered as
+/// is, and `LIMIT` is rendered as `TOP`, or as `OFFSET ... ROWS FETCH NEXT
+/// ... ROWS ONLY` along with an offset, which SQL Server only accepts after
+/// `ORDER BY`.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::dialect::SqlServer;
+/// use qians_xql::eq;
+/// use qians_xql::select;
+///
+/// let (sql, _) = select(["id"]).from("user").filter(eq("age", 18)).to_sql(SqlServer);
+/// assert_eq!(sql, "SELECT [id] FROM [user] WHERE [age] = @p1");
+///
+/// let (sql, _) = select(["a]b"]).to_sql(SqlServer);
+/// assert_eq!(sql, "SELECT [a]]b]");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SqlServer;
+
+impl Dialect for SqlServer {
+    const APPLY: bool = true;
+    const TOP: bool = true;
+    const LOCK: bool = false;
+
+    fn quote_literal(val: &str, buff: &mut String) {
+        quote(buff, val, '\'')
+    }
+
+    fn quote_ident(val: &str, buff: &mut String) {
+        buff.push('[');
+        for ch in val.chars() {
+            if ch == ']' {
+                buff.push(ch);
+            }
+            buff.push(ch);
+        }
+        buff.push(']');
+    }
+
+    fn quote_bytes(val: &[u8], buff: &mut String) {
+        buff.push_str("0x");
+        hex(buff, val);
+    }
+
+    fn placeholder(n: usize, buff: &mut String) {
+        buff.push_str("@p");
+        buff.push_str(n.to_string().as_str());
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn quoting() {
+    use crate::ops::eq;
+    use crate::stmt::select;
+
+    let query = select([("order", "id")])
+        .from("order")
+        .filter(eq(("order", "user"), 1));
+    assert_eq!(
+        query.to_sql(Postgres).0,
+        r#"SELECT "order"."id" FROM "order" WHERE "order"."user" = $1"#
+    );
+    assert_eq!(
+        query.to_sql(MySql).0,
+        "SELECT `order`.`id` FROM `order` WHERE `order`.`user` = ?"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn star() {
+    use crate::ops::star;
+    use crate::ops::star_of;
+    use crate::stmt::select;
+
+    let query = select([star()]).select([star_of("user")]).from("user");
+    assert_eq!(
+        query.to_sql(Postgres).0,
+        r#"SELECT *, "user".* FROM "user""#
+    );
+    assert_eq!(query.to_sql(MySql).0, "SELECT *, `user`.* FROM `user`");
+
+    let query = select([crate::func::count_all()]).from("user");
+    assert_eq!(query.to_sql(MySql).0, "SELECT COUNT(*) FROM `user`");
+    assert_eq!(query.to_sql(Postgres).0, r#"SELECT COUNT(*) FROM "user""#);
+
+    let query = select([crate::func::count("id"), crate::func::lower("name")]).from("user");
+    assert_eq!(
+        query.to_sql(Postgres).0,
+        r#"SELECT COUNT("id"), LOWER("name") FROM "user""#
+    );
+    // a function named by the caller is quoted like any identifier
+    let query = select([crate::func::func("count", ["id"])]).from("user");
+    assert_eq!(query.to_sql(MySql).0, "SELECT `count`(`id`) FROM `user`");
+
+    // a `*` given as a name is an identifier like any other
+    let query = select(["*"]).from(crate::ops::as_table("user", "*"));
+    assert_eq!(query.to_sql(Postgres).0, r#"SELECT "*" FROM "user" AS "*""#);
+}
+
+#[test]
+#[cfg(test)]
+fn apply() {
+    use crate::ops::as_table;
+    use crate::ops::eq;
+    use crate::ops::gt;
+    use crate::stmt::select;
+
+    let orders = select(["total"])
+        .from("order")
+        .filter(eq(("order", "user_id"), ("user", "id")))
+        .filter(gt("total", 0));
+    let query = select([("user", "name"), ("o", "total")])
+        .from("user")
+        .outer_apply(as_table(orders, "o"));
+    assert_eq!(
+        query.to_sql(SqlServer).0,
+        "SELECT [user].[name], [o].[total] FROM [user] OUTER APPLY \
+        (SELECT [total] FROM [order] WHERE [order].[user_id] = [user].[id] AND [total] > @p1) AS [o]"
+    );
+    assert_eq!(
+        query.to_sql(Postgres).0,
+        r#"SELECT "user"."name", "o"."total" FROM "user" LEFT JOIN LATERAL (SELECT "total" FROM "order" WHERE "order"."user_id" = "user"."id" AND "total" > $1) AS "o" ON TRUE"#
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn top() {
+    use crate::ops::ordinal;
+    use crate::stmt::select;
+    use crate::stmt::union;
+    use crate::value::Value;
+
+    let query = select(["id"]).from("user").distinct().limit(10);
+    assert_eq!(
+        query.to_sql(Postgres),
+        (
+            r#"SELECT DISTINCT "id" FROM "user" LIMIT $1"#.to_string(),
+            vec![Value::Int(10)]
+        )
+    );
+    assert_eq!(
+        query.to_sql(SqlServer),
+        (
+            "SELECT DISTINCT TOP (@p1) [id] FROM [user]".to_string(),
+            vec![Value::Int(10)]
+        )
+    );
+
+    let query = select(["id"])
+        .from("user")
+        .order_by(["id"])
+        .pagination(10, 20);
+    assert_eq!(
+        query.to_sql(SqlServer).0,
+        "SELECT [id] FROM [user] ORDER BY [id] OFFSET @p1 ROWS FETCH NEXT @p2 ROWS ONLY"
+    );
+
+    let query = union(select([1]), select([2]))
+        .order_by([ordinal(1)])
+        .limit(1);
+    assert_eq!(
+        query.to_sql(SqlServer).0,
+        "SELECT @p1 UNION SELECT @p2 ORDER BY 1 OFFSET 0 ROWS FETCH NEXT @p3 ROWS ONLY"
+    );
+
+    let query = select(["id"]).from("user").limit_all();
+    assert_eq!(query.to_sql(SqlServer).0, "SELECT [id] FROM [user]");
+
+    // `OFFSET` needs an `ORDER BY`
+    let query = select(["id"]).from("t").offset(5);
+    assert_eq!(
+        query.to_sql(SqlServer).0,
+        "SELECT [id] FROM [t] ORDER BY (SELECT NULL) OFFSET @p1 ROWS"
+    );
+
+    let query = select(["id"]).from("t").order_by(["id"]).offset(5);
+    assert_eq!(
+        query.to_sql(SqlServer).0,
+        "SELECT [id] FROM [t] ORDER BY [id] OFFSET @p1 ROWS"
+    );
+
+    let query = union(select(["id"]).from("t"), select(["id"]).from("u")).limit(3);
+    assert_eq!(
+        query.to_sql(SqlServer).0,
+        "SELECT [id] FROM [t] UNION SELECT [id] FROM [u] \
+        ORDER BY (SELECT NULL) OFFSET 0 ROWS FETCH NEXT @p1 ROWS ONLY"
+    );
+
+    // no `FOR UPDATE`
+    let query = select(["id"]).from("t").limit(3).for_update();
+    assert_eq!(query.to_sql(SqlServer).0, "SELECT TOP (@p1) [id] FROM [t]");
+    assert_eq!(
+        query.to_sql(Postgres).0,
+        r#"SELECT "id" FROM "t" LIMIT $1 FOR UPDATE"#
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn limit_offset() {
+    use crate::stmt::select;
+    use crate::value::Value;
+
+    let query = select(["id"]).from("user").pagination(10, 20);
+    assert_eq!(
+        query.to_sql(Postgres).0,
+        r#"SELECT "id" FROM "user" LIMIT $1 OFFSET $2"#
+    );
+    assert_eq!(
+        query.to_sql(MySql),
+        (
+            "SELECT `id` FROM `user` LIMIT ? OFFSET ?".to_string(),
+            vec![Value::Int(10), Value::Int(20)]
+        )
+    );
+    assert_eq!(
+        query.to_sql(MySqlLimitComma),
+        (
+            "SELECT `id` FROM `user` LIMIT ?, ?".to_string(),
+            vec![Value::Int(20), Value::Int(10)]
+        )
+    );
+    assert_eq!(
+        query.to_sql(Sqlite).0,
+        r#"SELECT "id" FROM "user" LIMIT ? OFFSET ?"#
+    );
+
+    let query = select(["id"]).from("user").offset(20);
+    assert_eq!(
+        query.to_sql(Postgres).0,
+        r#"SELECT "id" FROM "user" OFFSET $1"#
+    );
+    assert_eq!(
+        query.to_sql(MySql),
+        (
+            "SELECT `id` FROM `user` LIMIT 18446744073709551615 OFFSET ?".to_string(),
+            vec![Value::Int(20)]
+        )
+    );
+    assert_eq!(
+        query.to_sql(MySqlLimitComma),
+        (
+            "SELECT `id` FROM `user` LIMIT ?, 18446744073709551615".to_string(),
+            vec![Value::Int(20)]
+        )
+    );
+    assert_eq!(
+        query.to_sql(Sqlite).0,
+        r#"SELECT "id" FROM "user" LIMIT -1 OFFSET ?"#
+    );
+
+    let query = select(["id"]).from("user").limit_all();
+    assert_eq!(
+        query.to_sql(Postgres).0,
+        r#"SELECT "id" FROM "user" LIMIT ALL"#
+    );
+    assert_eq!(
+        query.to_sql(MySql).0,
+        "SELECT `id` FROM `user` LIMIT 18446744073709551615"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn numbered_params() {
+    use crate::ops::{and, eq, gt};
+    use crate::stmt::select;
+
+    let name = "alice".to_string();
+    let (sql, args) = select(["id"])
+        .from("user")
+        .filter(and(eq("name", &name), gt("age", 18)))
+        .filter(eq("level", 18))
+        .to_sql(Postgres);
+    assert_eq!(
+        sql,
+        r#"SELECT "id" FROM "user" WHERE "name" = $1 AND "age" > $2 AND "level" = $3"#
+    );
+    assert_eq!(
+        args,
+        [Value::Text("alice".into()), Value::Int(18), Value::Int(18)]
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn positional_params() {
+    use crate::ops::{and, eq, gt};
+    use crate::stmt::update;
+
+    let name = "alice".to_string();
+    let query = update("user")
+        .set("name", &name)
+        .set("level", 2)
+        .filter(and(gt("age", 18), eq("level", 1)));
+    assert_eq!(
+        query.to_sql(MySql),
+        (
+            "UPDATE `user` SET `name` = ?, `level` = ? WHERE `age` > ? AND `level` = ?".to_string(),
+            vec![
+                Value::Text("alice".into()),
+                Value::Int(2),
+                Value::Int(18),
+                Value::Int(1)
+            ]
+        )
+    );
+    assert_eq!(
+        query.to_sql(Sqlite),
+        (
+            r#"UPDATE "user" SET "name" = ?, "level" = ? WHERE "age" > ? AND "level" = ?"#
+                .to_string(),
+            vec![
+                Value::Text("alice".into()),
+                Value::Int(2),
+                Value::Int(18),
+                Value::Int(1)
+            ]
+        )
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn string_literals() {
+    fn literal<D: Dialect>(val: &str) -> String {
+        let mut buff = String::new();
+        D::quote_literal(val, &mut buff);
+        buff
+    }
+
+    assert_eq!(literal::<Postgres>("O'Brien"), "'O''Brien'");
+    assert_eq!(literal::<Postgres>(r"a\b'c"), r"E'a\\b''c'");
+    assert_eq!(literal::<Postgres>("a\nb"), "'a\nb'");
+    assert_eq!(literal::<MySql>("O'Brien"), "'O''Brien'");
+    assert_eq!(literal::<MySql>(r"a\b"), r"'a\\b'");
+    assert_eq!(literal::<MySql>("a\nb"), "'a\nb'");
+    assert_eq!(literal::<Sqlite>("O'Brien"), "'O''Brien'");
+    assert_eq!(literal::<Sqlite>(r"a\b"), r"'a\b'");
+    assert_eq!(literal::<Sqlite>("a\nb"), "'a\nb'");
+
+    let val = Value::Text("'; DROP TABLE user; --".into());
+    assert_eq!(val.to_string(), "'''; DROP TABLE user; --'");
+    let val = Value::Text(r"C:\dir".into());
+    assert_eq!(val.to_string(), r"E'C:\\dir'");
+}
+
+#[test]
+#[cfg(test)]
+fn casts() {
+    use crate::stmt::update;
+    use crate::value::{Decimal, Json};
+
+    let query = update("book")
+        .set("price", Decimal::new("12.50"))
+        .set("meta", Json::new("{}"))
+        .set("note", None::<Json>);
+    assert_eq!(
+        query.to_sql(Postgres).0,
+        r#"UPDATE "book" SET "price" = $1::numeric, "meta" = $2::jsonb, "note" = $3::jsonb"#
+    );
+    assert_eq!(
+        query.to_sql(MySql).0,
+        "UPDATE `book` SET `price` = ?, `meta` = ?, `note` = ?"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn render_mode() {
+    use crate::ops::eq;
+    use crate::stmt::select;
+    use crate::stmt::Stmt;
+
+    let name = r"a'b\c".to_string();
+    let query = select(["id"])
+        .from("user")
+        .filter(eq("name", &name))
+        .limit(10)
+        .offset(20);
+    assert_eq!(
+        query.to_sql_with(MySqlLimitComma, RenderMode::Params),
+        (
+            "SELECT `id` FROM `user` WHERE `name` = ? LIMIT ?, ?".to_string(),
+            vec![Value::Text(r"a'b\c".into()), Value::Int(20), Value::Int(10)]
+        )
+    );
+    assert_eq!(
+        query.to_sql_with(MySqlLimitComma, RenderMode::Inline),
+        (
+            r"SELECT `id` FROM `user` WHERE `name` = 'a''b\\c' LIMIT 20, 10".to_string(),
+            vec![]
+        )
+    );
+
+    let query: Stmt = select(["id"]).from("user").filter(eq("age", 18)).into();
+    assert_eq!(
+        query.to_sql_with(Postgres, RenderMode::Params),
+        query.to_sql(Postgres)
+    );
+    assert_eq!(
+        query.to_sql_with(SqlServer, RenderMode::Inline).0,
+        "SELECT [id] FROM [user] WHERE [age] = 18"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn inline_bytes() {
+    use crate::ops::eq;
+    use crate::stmt::select;
+
+    let hash = Value::Bytes(b"\x0a\xff"[..].into());
+    let query = select(["id"]).from("file").filter(eq("hash", hash));
+    assert_eq!(
+        query.to_sql_with(Postgres, RenderMode::Inline).0,
+        r#"SELECT "id" FROM "file" WHERE "hash" = '\x0aff'"#
+    );
+    assert_eq!(
+        query.to_sql_with(MySql, RenderMode::Inline).0,
+        "SELECT `id` FROM `file` WHERE `hash` = X'0aff'"
+    );
+    assert_eq!(
+        query.to_sql_with(MySqlLimitComma, RenderMode::Inline).0,
+        "SELECT `id` FROM `file` WHERE `hash` = X'0aff'"
+    );
+    assert_eq!(
+        query.to_sql_with(Sqlite, RenderMode::Inline).0,
+        r#"SELECT "id" FROM "file" WHERE "hash" = X'0aff'"#
+    );
+    assert_eq!(
+        query.to_sql_with(SqlServer, RenderMode::Inline).0,
+        "SELECT [id] FROM [file] WHERE [hash] = 0x0aff"
+    );
+    assert_eq!(
+        query.to_string(),
+        r"SELECT id FROM file WHERE hash = '\x0aff'"
+    );
+}
@@ -1,6 +1,12 @@
 #[cfg(feature = "sqlx")]
 use sqlx::database::Database;
 
+#[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+use std::borrow::Cow;
+
+#[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+use crate::value::{Decimal, Json};
+
 #[cfg(feature = "sqlx")]
 use crate::value::Value;
 
@@ -25,6 +31,15 @@ where
     {
         Self::bind_fn()(self, value)
     }
+
+    /// Bind each of `values` in order.
+    fn bind_values<I>(self, values: I) -> Result<Self, sqlx::Error>
+    where
+        DB: Bind,
+        I: IntoIterator<Item = Value<'q>>,
+    {
+        values.into_iter().try_fold(self, DB::bind)
+    }
 }
 
 macro_rules! gen_query_common {
@@ -138,8 +153,16 @@ impl Bind for sqlx::Postgres {
             Value::SmallUInt(..) => Err(unsupported::<sqlx::Postgres, u16>()),
             Value::UInt(..) => Err(unsupported::<sqlx::Postgres, u32>()),
             Value::BigUInt(..) => Err(unsupported::<sqlx::Postgres, u64>()),
-            Value::Text(val) => Ok(query.bind(val)),
-            Value::Bytes(val) => Ok(query.bind(val)),
+            Value::Text(Cow::Borrowed(val)) => Ok(query.bind(val)),
+            Value::Text(Cow::Owned(val)) => Ok(query.bind(val)),
+            Value::Bytes(Cow::Borrowed(val)) => Ok(query.bind(val)),
+            Value::Bytes(Cow::Owned(val)) => Ok(query.bind(val)),
+            Value::Real(val) => Ok(query.bind(val)),
+            Value::Double(val) => Ok(query.bind(val)),
+            Value::Decimal(Decimal(Cow::Borrowed(val))) => Ok(query.bind(val)),
+            Value::Decimal(Decimal(Cow::Owned(val))) => Ok(query.bind(val)),
+            Value::Json(Json(Cow::Borrowed(val))) => Ok(query.bind(val)),
+            Value::Json(Json(Cow::Owned(val))) => Ok(query.bind(val)),
             Value::Null(crate::value::Null::Bool(..)) => Ok(query.bind(None::<bool>)),
             Value::Null(crate::value::Null::TinyInt(..)) => Ok(query.bind(None::<i8>)),
             Value::Null(crate::value::Null::SmallInt(..)) => Ok(query.bind(None::<i16>)),
@@ -157,9 +180,23 @@ impl Bind for sqlx::Postgres {
             }
             Value::Null(crate::value::Null::Text(..)) => Ok(query.bind(None::<&'q str>)),
             Value::Null(crate::value::Null::Bytes(..)) => Ok(query.bind(None::<&'q str>)),
+            Value::Null(crate::value::Null::Real(..)) => Ok(query.bind(None::<f32>)),
+            Value::Null(crate::value::Null::Double(..)) => Ok(query.bind(None::<f64>)),
+            Value::Null(crate::value::Null::Decimal(..)) => Ok(query.bind(None::<&'q str>)),
+            Value::Null(crate::value::Null::Json(..)) => Ok(query.bind(None::<&'q str>)),
+            #[cfg(feature = "use-chrono")]
+            Value::Date(val) => Ok(query.bind(val)),
+            #[cfg(feature = "use-chrono")]
+            Value::Timestamp(val) => Ok(query.bind(val)),
             #[cfg(feature = "use-chrono")]
             Value::DateTime(val) => Ok(query.bind(val)),
             #[cfg(feature = "use-chrono")]
+            Value::Null(crate::value::Null::Date(..)) => Ok(query.bind(None::<chrono::NaiveDate>)),
+            #[cfg(feature = "use-chrono")]
+            Value::Null(crate::value::Null::Timestamp(..)) => {
+                Ok(query.bind(None::<chrono::NaiveDateTime>))
+            }
+            #[cfg(feature = "use-chrono")]
             Value::Null(crate::value::Null::DateTime(..)) => {
                 Ok(query.bind(None::<chrono::DateTime<chrono::Utc>>))
             }
@@ -190,8 +227,16 @@ impl Bind for sqlx::MySql {
             Value::SmallUInt(val) => Ok(query.bind(val)),
             Value::UInt(val) => Ok(query.bind(val)),
             Value::BigUInt(val) => Ok(query.bind(val)),
-            Value::Text(val) => Ok(query.bind(val)),
-            Value::Bytes(val) => Ok(query.bind(val)),
+            Value::Text(Cow::Borrowed(val)) => Ok(query.bind(val)),
+            Value::Text(Cow::Owned(val)) => Ok(query.bind(val)),
+            Value::Bytes(Cow::Borrowed(val)) => Ok(query.bind(val)),
+            Value::Bytes(Cow::Owned(val)) => Ok(query.bind(val)),
+            Value::Real(val) => Ok(query.bind(val)),
+            Value::Double(val) => Ok(query.bind(val)),
+            Value::Decimal(Decimal(Cow::Borrowed(val))) => Ok(query.bind(val)),
+            Value::Decimal(Decimal(Cow::Owned(val))) => Ok(query.bind(val)),
+            Value::Json(Json(Cow::Borrowed(val))) => Ok(query.bind(val)),
+            Value::Json(Json(Cow::Owned(val))) => Ok(query.bind(val)),
             Value::Null(crate::value::Null::Bool(..)) => Ok(query.bind(None::<bool>)),
             Value::Null(crate::value::Null::TinyInt(..)) => Ok(query.bind(None::<i8>)),
             Value::Null(crate::value::Null::SmallInt(..)) => Ok(query.bind(None::<i16>)),
@@ -203,9 +248,23 @@ impl Bind for sqlx::MySql {
             Value::Null(crate::value::Null::BigUInt(..)) => Ok(query.bind(None::<u64>)),
             Value::Null(crate::value::Null::Text(..)) => Ok(query.bind(None::<&'q str>)),
             Value::Null(crate::value::Null::Bytes(..)) => Ok(query.bind(None::<&'q str>)),
+            Value::Null(crate::value::Null::Real(..)) => Ok(query.bind(None::<f32>)),
+            Value::Null(crate::value::Null::Double(..)) => Ok(query.bind(None::<f64>)),
+            Value::Null(crate::value::Null::Decimal(..)) => Ok(query.bind(None::<&'q str>)),
+            Value::Null(crate::value::Null::Json(..)) => Ok(query.bind(None::<&'q str>)),
+            #[cfg(feature = "use-chrono")]
+            Value::Date(val) => Ok(query.bind(val)),
+            #[cfg(feature = "use-chrono")]
+            Value::Timestamp(val) => Ok(query.bind(val)),
             #[cfg(feature = "use-chrono")]
             Value::DateTime(val) => Ok(query.bind(val)),
             #[cfg(feature = "use-chrono")]
+            Value::Null(crate::value::Null::Date(..)) => Ok(query.bind(None::<chrono::NaiveDate>)),
+            #[cfg(feature = "use-chrono")]
+            Value::Null(crate::value::Null::Timestamp(..)) => {
+                Ok(query.bind(None::<chrono::NaiveDateTime>))
+            }
+            #[cfg(feature = "use-chrono")]
             Value::Null(crate::value::Null::DateTime(..)) => {
                 Ok(query.bind(None::<chrono::DateTime<chrono::Utc>>))
             }
@@ -236,8 +295,16 @@ impl Bind for sqlx::Sqlite {
             Value::SmallUInt(val) => Ok(query.bind(val)),
             Value::UInt(val) => Ok(query.bind(val)),
             Value::BigUInt(..) => Err(unsupported::<sqlx::Sqlite, u64>()),
-            Value::Text(val) => Ok(query.bind(val)),
-            Value::Bytes(val) => Ok(query.bind(val)),
+            Value::Text(Cow::Borrowed(val)) => Ok(query.bind(val)),
+            Value::Text(Cow::Owned(val)) => Ok(query.bind(val)),
+            Value::Bytes(Cow::Borrowed(val)) => Ok(query.bind(val)),
+            Value::Bytes(Cow::Owned(val)) => Ok(query.bind(val)),
+            Value::Real(val) => Ok(query.bind(val)),
+            Value::Double(val) => Ok(query.bind(val)),
+            Value::Decimal(Decimal(Cow::Borrowed(val))) => Ok(query.bind(val)),
+            Value::Decimal(Decimal(Cow::Owned(val))) => Ok(query.bind(val)),
+            Value::Json(Json(Cow::Borrowed(val))) => Ok(query.bind(val)),
+            Value::Json(Json(Cow::Owned(val))) => Ok(query.bind(val)),
             Value::Null(crate::value::Null::Bool(..)) => Ok(query.bind(None::<bool>)),
             Value::Null(crate::value::Null::TinyInt(..)) => Ok(query.bind(None::<i8>)),
             Value::Null(crate::value::Null::SmallInt(..)) => Ok(query.bind(None::<i16>)),
@@ -249,9 +316,23 @@ impl Bind for sqlx::Sqlite {
             Value::Null(crate::value::Null::BigUInt(..)) => Err(unsupported::<sqlx::Sqlite, u64>()),
             Value::Null(crate::value::Null::Text(..)) => Ok(query.bind(None::<&'q str>)),
             Value::Null(crate::value::Null::Bytes(..)) => Ok(query.bind(None::<&'q str>)),
+            Value::Null(crate::value::Null::Real(..)) => Ok(query.bind(None::<f32>)),
+            Value::Null(crate::value::Null::Double(..)) => Ok(query.bind(None::<f64>)),
+            Value::Null(crate::value::Null::Decimal(..)) => Ok(query.bind(None::<&'q str>)),
+            Value::Null(crate::value::Null::Json(..)) => Ok(query.bind(None::<&'q str>)),
+            #[cfg(feature = "use-chrono")]
+            Value::Date(val) => Ok(query.bind(val)),
+            #[cfg(feature = "use-chrono")]
+            Value::Timestamp(val) => Ok(query.bind(val)),
             #[cfg(feature = "use-chrono")]
             Value::DateTime(val) => Ok(query.bind(val)),
             #[cfg(feature = "use-chrono")]
+            Value::Null(crate::value::Null::Date(..)) => Ok(query.bind(None::<chrono::NaiveDate>)),
+            #[cfg(feature = "use-chrono")]
+            Value::Null(crate::value::Null::Timestamp(..)) => {
+                Ok(query.bind(None::<chrono::NaiveDateTime>))
+            }
+            #[cfg(feature = "use-chrono")]
             Value::Null(crate::value::Null::DateTime(..)) => {
                 Ok(query.bind(None::<chrono::DateTime<chrono::Utc>>))
             }
@@ -16,64 +16,58 @@ use crate::{build::Dialect, build::ToSql, exec::bind::Bind, stmt::Stmt, value::V
 
 pub mod bind;
 
-#[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
-fn quote(buff: &mut String, val: &str, q: char) {
-    buff.push(q);
-    for ch in val.chars() {
-        if ch == q {
-            buff.push(q);
-        }
-        buff.push(ch);
-    }
-    buff.push(q);
-}
+#[cfg(feature = "rusqlite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rusqlite")))]
+pub mod rusqlite;
 
 #[cfg(feature = "postgres")]
 impl Dialect for Postgres {
     fn quote_literal(val: &str, buff: &mut String) {
-        quote(buff, val, '\'')
+        crate::dialect::Postgres::quote_literal(val, buff)
     }
 
     fn quote_ident(val: &str, buff: &mut String) {
-        quote(buff, val, '"')
+        crate::dialect::Postgres::quote_ident(val, buff)
+    }
+
+    fn placeholder(n: usize, buff: &mut String) {
+        crate::dialect::Postgres::placeholder(n, buff)
     }
 
     fn bind_param<'a>(n: usize, val: Value<'a>, buff: &mut String) -> Value<'a> {
-        buff.push('$');
-        buff.push_str(n.to_string().as_str());
-        val
+        crate::dialect::Postgres::bind_param(n, val, buff)
     }
 }
 
 #[cfg(feature = "mysql")]
 impl Dialect for MySql {
+    const ON_DUPLICATE_KEY_UPDATE: bool = true;
+
     fn quote_literal(val: &str, buff: &mut String) {
-        quote(buff, val, '\'')
+        crate::dialect::MySql::quote_literal(val, buff)
     }
 
     fn quote_ident(val: &str, buff: &mut String) {
-        quote(buff, val, '`')
+        crate::dialect::MySql::quote_ident(val, buff)
     }
 
-    fn bind_param<'a>(_: usize, val: Value<'a>, buff: &mut String) -> Value<'a> {
-        buff.push('?');
-        val
+    fn placeholder(n: usize, buff: &mut String) {
+        crate::dialect::MySql::placeholder(n, buff)
     }
 }
 
 #[cfg(feature = "sqlite")]
 impl Dialect for Sqlite {
     fn quote_literal(val: &str, buff: &mut String) {
-        quote(buff, val, '\'')
+        crate::dialect::Sqlite::quote_literal(val, buff)
     }
 
     fn quote_ident(val: &str, buff: &mut String) {
-        quote(buff, val, '"')
+        crate::dialect::Sqlite::quote_ident(val, buff)
     }
 
-    fn bind_param<'a>(_: usize, val: Value<'a>, buff: &mut String) -> Value<'a> {
-        buff.push('?');
-        val
+    fn placeholder(n: usize, buff: &mut String) {
+        crate::dialect::Sqlite::placeholder(n, buff)
     }
 }
 
@@ -340,6 +334,42 @@ impl Backend for Sqlite {
     gen_backend_methods!();
 }
 
+/// Render `stmt` into SQL and parameters for the database `DB`.
+///
+/// The parameters can be bound to a sqlx query with
+/// [`Query::bind_values`](bind::Query::bind_values).
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "postgres")]
+/// # fn main() -> Result<(), sqlx::Error> {
+/// use qians_xql::eq;
+/// use qians_xql::exec::bind::Query;
+/// use qians_xql::exec::to_sqlx;
+/// use qians_xql::update;
+///
+/// let name = "alice".to_string();
+/// let query = update("user").set("name", &name).filter(eq("id", 1));
+/// let (sql, args) = to_sqlx::<sqlx::Postgres, _>(query);
+/// assert_eq!(sql, r#"UPDATE "user" SET "name" = $1 WHERE "id" = $2"#);
+///
+/// let query = sqlx::query::<sqlx::Postgres>(&sql).bind_values(args)?;
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "postgres"))]
+/// # fn main() {}
+/// ```
+#[cfg(feature = "sqlx")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sqlx")))]
+pub fn to_sqlx<'v, DB, S>(stmt: S) -> (String, Vec<Value<'v>>)
+where
+    S: Into<Stmt<'v>>,
+    DB: Backend + Dialect,
+{
+    ToSql::to_sql::<DB>(&stmt.into())
+}
+
 #[cfg(feature = "sqlx")]
 #[cfg_attr(docsrs, doc(cfg(feature = "sqlx")))]
 pub async fn fetch_one<'c, 'v, DB, E, S>(stmt: S, executor: E) -> Result<DB::Row, sqlx::Error>
@@ -348,7 +378,7 @@ where
     DB: Backend + Dialect,
     E: Executor<'c, Database = DB>,
 {
-    let (sql, args) = stmt.into().to_sql::<E::Database>();
+    let (sql, args) = ToSql::to_sql::<E::Database>(&stmt.into());
     E::Database::fetch_one(executor, sql, args).await
 }
 
@@ -363,7 +393,7 @@ where
     DB: Backend + Dialect,
     E: Executor<'c, Database = DB>,
 {
-    let (sql, args) = stmt.into().to_sql::<E::Database>();
+    let (sql, args) = ToSql::to_sql::<E::Database>(&stmt.into());
     E::Database::fetch_optional(executor, sql, args).await
 }
 
@@ -375,7 +405,7 @@ where
     DB: Backend + Dialect,
     E: Executor<'c, Database = DB>,
 {
-    let (sql, args) = stmt.into().to_sql::<E::Database>();
+    let (sql, args) = ToSql::to_sql::<E::Database>(&stmt.into());
     E::Database::fetch_all(executor, sql, args).await
 }
 
@@ -388,7 +418,7 @@ where
     E::Database: Backend + Dialect,
     O: Send + Unpin + for<'r> FromRow<'r, <E::Database as Database>::Row>,
 {
-    let (sql, args) = stmt.into().to_sql::<E::Database>();
+    let (sql, args) = ToSql::to_sql::<E::Database>(&stmt.into());
     E::Database::fetch_one_as(executor, sql, args).await
 }
 
@@ -404,7 +434,7 @@ where
     E::Database: Backend + Dialect,
     O: Send + Unpin + for<'r> FromRow<'r, <E::Database as Database>::Row>,
 {
-    let (sql, args) = stmt.into().to_sql::<E::Database>();
+    let (sql, args) = ToSql::to_sql::<E::Database>(&stmt.into());
     E::Database::fetch_optional_as(executor, sql, args).await
 }
 
@@ -417,7 +447,7 @@ where
     E::Database: Backend + Dialect,
     O: Send + Unpin + for<'r> FromRow<'r, <E::Database as Database>::Row>,
 {
-    let (sql, args) = stmt.into().to_sql::<E::Database>();
+    let (sql, args) = ToSql::to_sql::<E::Database>(&stmt.into());
     E::Database::fetch_all_as(executor, sql, args).await
 }
 
@@ -431,7 +461,7 @@ where
     O: Send + Unpin,
     (O,): for<'r> FromRow<'r, <E::Database as Database>::Row>,
 {
-    let (sql, args) = stmt.into().to_sql::<E::Database>();
+    let (sql, args) = ToSql::to_sql::<E::Database>(&stmt.into());
     E::Database::fetch_one_scalar(executor, sql, args).await
 }
 
@@ -448,7 +478,7 @@ where
     O: Send + Unpin,
     (O,): for<'r> FromRow<'r, <E::Database as Database>::Row>,
 {
-    let (sql, args) = stmt.into().to_sql::<E::Database>();
+    let (sql, args) = ToSql::to_sql::<E::Database>(&stmt.into());
     E::Database::fetch_optional_scalar(executor, sql, args).await
 }
 
@@ -462,6 +492,6 @@ where
     O: Send + Unpin,
     (O,): for<'r> FromRow<'r, <E::Database as Database>::Row>,
 {
-    let (sql, args) = stmt.into().to_sql::<E::Database>();
+    let (sql, args) = ToSql::to_sql::<E::Database>(&stmt.into());
     E::Database::fetch_all_scalar(executor, sql, args).await
 }
@@ -0,0 +1,121 @@
+//! Execute statements with [`rusqlite`](::rusqlite).
+
+use crate::build::ToSql;
+use crate::stmt::Stmt;
+use crate::value::Value;
+
+impl TryFrom<Value<'_>> for ::rusqlite::types::Value {
+    type Error = ::rusqlite::Error;
+
+    fn try_from(val: Value<'_>) -> Result<Self, Self::Error> {
+        use ::rusqlite::types::Value as Sqlite;
+
+        Ok(match val {
+            Value::Null(_) => Sqlite::Null,
+            Value::Bool(val) => Sqlite::Integer(val.into()),
+            Value::TinyInt(val) => Sqlite::Integer(val.into()),
+            Value::SmallInt(val) => Sqlite::Integer(val.into()),
+            Value::Int(val) => Sqlite::Integer(val.into()),
+            Value::BigInt(val) => Sqlite::Integer(val),
+            Value::TinyUInt(val) => Sqlite::Integer(val.into()),
+            Value::SmallUInt(val) => Sqlite::Integer(val.into()),
+            Value::UInt(val) => Sqlite::Integer(val.into()),
+            Value::BigUInt(val) => Sqlite::Integer(
+                i64::try_from(val)
+                    .map_err(|err| ::rusqlite::Error::ToSqlConversionFailure(Box::new(err)))?,
+            ),
+            Value::Real(val) => Sqlite::Real(val.into()),
+            Value::Double(val) => Sqlite::Real(val),
+            Value::Decimal(val) => Sqlite::Text(val.0.to_string()),
+            Value::Text(val) => Sqlite::Text(val.to_string()),
+            Value::Bytes(val) => Sqlite::Blob(val.to_vec()),
+            Value::Json(val) => Sqlite::Text(val.0.to_string()),
+            #[cfg(feature = "use-chrono")]
+            Value::Date(val) => Sqlite::Text(val.to_string()),
+            #[cfg(feature = "use-chrono")]
+            Value::Timestamp(val) => Sqlite::Text(val.format("%Y-%m-%dT%H:%M:%S%.f").to_string()),
+            #[cfg(feature = "use-chrono")]
+            Value::DateTime(val) => Sqlite::Text(val.to_rfc3339()),
+            #[cfg(feature = "use-decimal")]
+            Value::Numeric(val) => Sqlite::Text(val.to_string()),
+        })
+    }
+}
+
+/// Render `stmt` into SQL with `?` placeholders and its parameters.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::eq;
+/// use qians_xql::exec::rusqlite::to_rusqlite;
+/// use qians_xql::select;
+///
+/// let query = select(["id"]).from("user").filter(eq("age", 18));
+/// let (sql, args) = to_rusqlite(query).unwrap();
+/// assert_eq!(sql, r#"SELECT "id" FROM "user" WHERE "age" = ?"#);
+/// assert_eq!(args, [rusqlite::types::Value::Integer(18)]);
+/// ```
+pub fn to_rusqlite<'v, S>(stmt: S) -> ::rusqlite::Result<(String, Vec<::rusqlite::types::Value>)>
+where
+    S: Into<Stmt<'v>>,
+{
+    let (sql, args) = ToSql::to_sql::<crate::dialect::Sqlite>(&stmt.into());
+    let args = args
+        .into_iter()
+        .map(TryFrom::try_from)
+        .collect::<::rusqlite::Result<_>>()?;
+    Ok((sql, args))
+}
+
+/// Execute statements on a [`Connection`](::rusqlite::Connection).
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::exec::rusqlite::ConnectionExt;
+/// use qians_xql::{insert, select};
+///
+/// let conn = rusqlite::Connection::open_in_memory()?;
+/// conn.execute_batch("CREATE TABLE book (id INTEGER, title TEXT)")?;
+///
+/// let title = "Dune".to_string();
+/// conn.execute_xql(insert("book", ["id", "title"]).values([(1, &title)]))?;
+///
+/// let titles: Vec<String> = conn.query_xql(select(["title"]).from("book"), |row| row.get(0))?;
+/// assert_eq!(titles, ["Dune"]);
+/// # Ok::<(), rusqlite::Error>(())
+/// ```
+pub trait ConnectionExt {
+    /// Execute `stmt`, returning the number of rows changed.
+    fn execute_xql<'v, S>(&self, stmt: S) -> ::rusqlite::Result<usize>
+    where
+        S: Into<Stmt<'v>>;
+
+    /// Execute `stmt`, mapping each of the returned rows with `f`.
+    fn query_xql<'v, S, T, F>(&self, stmt: S, f: F) -> ::rusqlite::Result<Vec<T>>
+    where
+        S: Into<Stmt<'v>>,
+        F: FnMut(&::rusqlite::Row<'_>) -> ::rusqlite::Result<T>;
+}
+
+impl ConnectionExt for ::rusqlite::Connection {
+    fn execute_xql<'v, S>(&self, stmt: S) -> ::rusqlite::Result<usize>
+    where
+        S: Into<Stmt<'v>>,
+    {
+        let (sql, args) = to_rusqlite(stmt)?;
+        self.execute(&sql, ::rusqlite::params_from_iter(args))
+    }
+
+    fn query_xql<'v, S, T, F>(&self, stmt: S, f: F) -> ::rusqlite::Result<Vec<T>>
+    where
+        S: Into<Stmt<'v>>,
+        F: FnMut(&::rusqlite::Row<'_>) -> ::rusqlite::Result<T>,
+    {
+        let (sql, args) = to_rusqlite(stmt)?;
+        let mut query = self.prepare(&sql)?;
+        let rows = query.query_map(::rusqlite::params_from_iter(args), f)?;
+        rows.collect()
+    }
+}
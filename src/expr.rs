@@ -1,25 +1,108 @@
 use crate::item::ColumnRef;
+use crate::item::DateField;
 use crate::item::FuncCall;
+use crate::item::Keyword;
+use crate::item::TypeName;
+use crate::item::Window;
 use crate::stmt::data::Data;
 use crate::stmt::select::Select;
 use crate::stmt::values::Values;
 use crate::value::Value;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 pub enum Expr<'a> {
     Column(ColumnRef<'a>),
     Literal(Value<'a>),
     FuncCall(FuncCall<'a>),
 
-    Prefix(&'static str, Box<Expr<'a>>),
-    Infix(Box<Expr<'a>>, &'static str, Box<Expr<'a>>),
-    Postfix(Box<Expr<'a>>, &'static str),
+    Prefix(Keyword, Box<Expr<'a>>),
+    Infix(Box<Expr<'a>>, Keyword, Box<Expr<'a>>),
+    Postfix(Box<Expr<'a>>, Keyword),
     Paren(Box<Expr<'a>>),
     SubQuery(Data<'a>),
+    Window(Box<Expr<'a>>, Box<Window<'a>>),
+    Cast(Box<Expr<'a>>, TypeName<'a>),
+    /// `EXTRACT(field FROM source)`, see [`extract`](crate::func::extract).
+    Extract(DateField, Box<Expr<'a>>),
+    Between(Box<Expr<'a>>, Keyword, Box<Expr<'a>>, Box<Expr<'a>>),
+    /// `LIKE` or one of its variants with an `ESCAPE` character, see
+    /// [`like_escape`](crate::ops::like_escape).
+    LikeEscape(Box<Expr<'a>>, Keyword, Box<Expr<'a>>, Box<Expr<'a>>),
+    In(Box<Expr<'a>>, Keyword, Vec<Expr<'a>>),
+    Quantified(Keyword, Box<Expr<'a>>),
+    Array(Vec<Expr<'a>>),
+    Subscript(Box<Expr<'a>>, Box<Expr<'a>>, Option<Box<Expr<'a>>>),
+    /// 1-based position of a selected field, in `ORDER BY` or `GROUP BY`.
+    Ordinal(u32),
+    /// `INTERVAL` literal, see [`interval`](crate::ops::interval). Always
+    /// written in the PostgreSQL and standard SQL syntax.
+    Interval(#[cfg_attr(feature = "serde", serde(borrow))] std::borrow::Cow<'a, str>),
+    /// String literal written inline and quoted, where a parameter is not
+    /// accepted, see [`date_trunc`](crate::func::date_trunc).
+    Quoted(#[cfg_attr(feature = "serde", serde(borrow))] std::borrow::Cow<'a, str>),
+    /// SQL written verbatim, see [`raw`](crate::ops::raw).
+    Raw(#[cfg_attr(feature = "serde", serde(borrow))] std::borrow::Cow<'a, str>),
 }
 
 crate::macros::gen_display!(Expr<'_>);
 
+/// Precedence of comparison operators, which are not associative.
+pub(crate) const COMPARISON: u8 = 4;
+
+impl Expr<'_> {
+    /// Precedence of a binary operator, the higher the tighter it binds.
+    pub(crate) fn infix_precedence(op: &str) -> u8 {
+        match op {
+            "OR" => 1,
+            "AND" => 2,
+            "=" | "<>" | "!=" | "<" | ">" | "<=" | ">=" => COMPARISON,
+            "LIKE" | "ILIKE" | "NOT LIKE" | "NOT ILIKE" => COMPARISON,
+            "IN" | "NOT IN" | "IS DISTINCT FROM" | "IS NOT DISTINCT FROM" => COMPARISON,
+            "+" | "-" => 6,
+            "*" | "/" | "%" => 7,
+            _ => 5,
+        }
+    }
+
+    /// Precedence of the expression used as an operand, atomic expressions
+    /// bind the tightest.
+    pub(crate) fn precedence(&self) -> u8 {
+        match self {
+            Expr::Infix(_, op, _) => Expr::infix_precedence(op),
+            Expr::Prefix(op, _) if op == "NOT" => 3,
+            Expr::Prefix(..) => 8,
+            Expr::Postfix(..) | Expr::Between(..) | Expr::LikeEscape(..) | Expr::In(..) => {
+                COMPARISON
+            }
+            _ => u8::MAX,
+        }
+    }
+}
+
+impl<'a> Expr<'a> {
+    /// Make an alias out of the expression, see also
+    /// [`as_field`](crate::ops::as_field).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::mul;
+    /// use qians_xql::select;
+    ///
+    /// let query = select([mul("price", "quantity").alias("total")]).from("item");
+    /// assert_eq!(query.to_string(), "SELECT price * quantity AS total FROM item");
+    /// ```
+    #[inline]
+    pub fn alias<A>(self, alias: A) -> crate::item::Field<'a>
+    where
+        A: Into<crate::item::Ident<'a>>,
+    {
+        crate::ops::as_field(self, alias)
+    }
+}
+
 impl<'a> std::convert::From<ColumnRef<'a>> for Expr<'a> {
     #[inline]
     fn from(val: ColumnRef<'a>) -> Self {
@@ -91,13 +174,15 @@ mod tests {
     use crate::expr::Expr;
     use crate::func::avg;
     use crate::func::count;
-    use crate::func::sum;
-    use crate::func::min;
+    use crate::func::lower;
     use crate::func::max;
+    use crate::func::min;
+    use crate::func::sum;
     use crate::item::ColumnRef;
     use crate::item::Ident;
     use crate::item::Order;
     use crate::item::Sort;
+    use crate::item::TypeName;
     use crate::ops::*;
     use crate::value::Value;
 
@@ -114,7 +199,7 @@ mod tests {
         assert_eq!(Into::<Expr>::into(64_i64), Expr::Literal(Value::BigInt(64)));
         assert_eq!(
             Into::<Expr>::into(&"text".to_string()),
-            Expr::Literal(Value::Text("text"))
+            Expr::Literal(Value::Text("text".into()))
         );
     }
 
@@ -134,26 +219,36 @@ mod tests {
 
     #[test]
     fn option() {
-        assert_eq!(Into::<Expr>::into(None::<i32>).to_string(), "null");
+        assert_eq!(Into::<Expr>::into(None::<i32>).to_string(), "NULL");
         assert_eq!(Into::<Expr>::into(Some(1)).to_string(), "1");
+        assert_eq!(Into::<Expr>::into(None::<f64>).to_string(), "NULL");
+        assert_eq!(Into::<Expr>::into(Some(1.5)).to_string(), "1.5");
+        assert_eq!(
+            Into::<Expr>::into(None::<&String>),
+            Expr::Literal(Value::Null(crate::value::Null::Text(Default::default())))
+        );
+        assert_eq!(
+            Into::<Expr>::into(Some(&"text".to_string())).to_string(),
+            "'text'"
+        );
     }
 
     #[test]
     fn column_ref() {
         assert_eq!(
             Into::<Expr>::into("id"),
-            Expr::Column(ColumnRef::Column(Ident("id")))
+            Expr::Column(ColumnRef::Column(Ident::from("id")))
         );
         assert_eq!(
             Into::<Expr>::into(("user", "id")),
-            Expr::Column(ColumnRef::TableColumn(Ident("user"), Ident("id")))
+            Expr::Column(ColumnRef::TableColumn(Ident::from("user"), Ident::from("id")))
         );
         assert_eq!(
             Into::<Expr>::into(("public", "user", "id")),
             Expr::Column(ColumnRef::SchemaTableColumn(
-                Ident("public"),
-                Ident("user"),
-                Ident("id")
+                Ident::from("public"),
+                Ident::from("user"),
+                Ident::from("id")
             ))
         );
     }
@@ -180,27 +275,27 @@ mod tests {
     #[test]
     #[rustfmt::skip]
     fn ops() {
-        assert_eq!(add(1, 2),    Expr::Infix(Box::new(1.into()), "+",      Box::new(2.into())));
-        assert_eq!(sub(1, 2),    Expr::Infix(Box::new(1.into()), "-",      Box::new(2.into())));
-        assert_eq!(mul(1, 2),    Expr::Infix(Box::new(1.into()), "*",      Box::new(2.into())));
-        assert_eq!(div(1, 2),    Expr::Infix(Box::new(1.into()), "/",      Box::new(2.into())));
-        assert_eq!(rem(1, 2),    Expr::Infix(Box::new(1.into()), "%",      Box::new(2.into())));
-        assert_eq!(eq(1, 2),     Expr::Infix(Box::new(1.into()), "=",      Box::new(2.into())));
-        assert_eq!(ne(1, 2),     Expr::Infix(Box::new(1.into()), "<>",     Box::new(2.into())));
-        assert_eq!(gt(1, 2),     Expr::Infix(Box::new(1.into()), ">",      Box::new(2.into())));
-        assert_eq!(ge(1, 2),     Expr::Infix(Box::new(1.into()), ">=",     Box::new(2.into())));
-        assert_eq!(lt(1, 2),     Expr::Infix(Box::new(1.into()), "<",      Box::new(2.into())));
-        assert_eq!(le(1, 2),     Expr::Infix(Box::new(1.into()), "<=",     Box::new(2.into())));
-        assert_eq!(and(1, 2),    Expr::Infix(Box::new(1.into()), "AND",    Box::new(2.into())));
-        assert_eq!(or(1, 2),     Expr::Infix(Box::new(1.into()), "OR",     Box::new(2.into())));
-        assert_eq!(like(1, 2),   Expr::Infix(Box::new(1.into()), "LIKE",   Box::new(2.into())));
-        assert_eq!(ilike(1, 2),  Expr::Infix(Box::new(1.into()), "ILIKE",  Box::new(2.into())));
-
-        assert_eq!(asc("id"),  Order(Expr::Column(ColumnRef::Column("id".into())), Some(Sort::Asc)));
-        assert_eq!(desc("id"), Order(Expr::Column(ColumnRef::Column("id".into())), Some(Sort::Desc)));
-
-        assert_eq!(not(true), Expr::Prefix("NOT", Box::new(true.into())));
-        assert_eq!(isnull("expr"), Expr::Postfix(Box::new("expr".into()), "ISNULL"));
+        assert_eq!(add(1, 2),    Expr::Infix(Box::new(1.into()), "+".into(),      Box::new(2.into())));
+        assert_eq!(sub(1, 2),    Expr::Infix(Box::new(1.into()), "-".into(),      Box::new(2.into())));
+        assert_eq!(mul(1, 2),    Expr::Infix(Box::new(1.into()), "*".into(),      Box::new(2.into())));
+        assert_eq!(div(1, 2),    Expr::Infix(Box::new(1.into()), "/".into(),      Box::new(2.into())));
+        assert_eq!(rem(1, 2),    Expr::Infix(Box::new(1.into()), "%".into(),      Box::new(2.into())));
+        assert_eq!(eq(1, 2),     Expr::Infix(Box::new(1.into()), "=".into(),      Box::new(2.into())));
+        assert_eq!(ne(1, 2),     Expr::Infix(Box::new(1.into()), "<>".into(),     Box::new(2.into())));
+        assert_eq!(gt(1, 2),     Expr::Infix(Box::new(1.into()), ">".into(),      Box::new(2.into())));
+        assert_eq!(ge(1, 2),     Expr::Infix(Box::new(1.into()), ">=".into(),     Box::new(2.into())));
+        assert_eq!(lt(1, 2),     Expr::Infix(Box::new(1.into()), "<".into(),      Box::new(2.into())));
+        assert_eq!(le(1, 2),     Expr::Infix(Box::new(1.into()), "<=".into(),     Box::new(2.into())));
+        assert_eq!(and(1, 2),    Expr::Infix(Box::new(1.into()), "AND".into(),    Box::new(2.into())));
+        assert_eq!(or(1, 2),     Expr::Infix(Box::new(1.into()), "OR".into(),     Box::new(2.into())));
+        assert_eq!(like(1, 2),   Expr::Infix(Box::new(1.into()), "LIKE".into(),   Box::new(2.into())));
+        assert_eq!(ilike(1, 2),  Expr::Infix(Box::new(1.into()), "ILIKE".into(),  Box::new(2.into())));
+
+        assert_eq!(asc("id"),  Order(Expr::Column(ColumnRef::Column("id".into())), Some(Sort::Asc), None));
+        assert_eq!(desc("id"), Order(Expr::Column(ColumnRef::Column("id".into())), Some(Sort::Desc), None));
+
+        assert_eq!(not(true), Expr::Prefix("NOT".into(), Box::new(true.into())));
+        assert_eq!(isnull("expr"), Expr::Postfix(Box::new("expr".into()), "ISNULL".into()));
     }
 
     #[test]
@@ -211,6 +306,415 @@ mod tests {
         assert_eq!(cond.to_string(), "(a OR b) AND (c OR d)");
     }
 
+    #[test]
+    fn window() {
+        use crate::func::func;
+        use crate::item::Window;
+
+        let window = Window::default()
+            .partition_by(["dept", "team"])
+            .order_by([desc("salary")]);
+        let expr = func("row_number", Vec::<Expr>::new()).over(window);
+        assert_eq!(
+            expr.to_string(),
+            "row_number() OVER (PARTITION BY dept, team ORDER BY salary DESC)"
+        );
+        assert_eq!(
+            over(count("id"), Window::default()).to_string(),
+            "COUNT(id) OVER ()"
+        );
+
+        use crate::item::FrameBound;
+        let window = Window::from("w").range_between(
+            FrameBound::Preceding(3.into()),
+            FrameBound::Following(3.into()),
+        );
+        assert_eq!(
+            sum("amount").over(window).to_string(),
+            "SUM(amount) OVER (w RANGE BETWEEN 3 PRECEDING AND 3 FOLLOWING)"
+        );
+        let window = Window::default()
+            .partition_by(["dept"])
+            .order_by(["day"])
+            .groups_between(FrameBound::CurrentRow, FrameBound::UnboundedFollowing);
+        assert_eq!(
+            sum("amount").over(window).to_string(),
+            "SUM(amount) OVER (PARTITION BY dept ORDER BY day GROUPS BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING)"
+        );
+    }
+
+    #[test]
+    fn raw() {
+        use crate::dialect::Postgres;
+        use crate::ops::raw;
+
+        let days = 7;
+        let expr = and(eq("kind", 1), raw(format!("age < interval '{days} days'")));
+        assert_eq!(expr.to_string(), "kind = 1 AND age < interval '7 days'");
+
+        let query = crate::stmt::select([raw("count(*) FILTER (WHERE ok)")])
+            .from("t")
+            .filter(raw("ok IS TRUE"));
+        let (sql, args) = query.to_sql(Postgres);
+        assert_eq!(
+            sql,
+            r#"SELECT count(*) FILTER (WHERE ok) FROM "t" WHERE ok IS TRUE"#
+        );
+        assert!(args.is_empty());
+    }
+
+    #[test]
+    fn type_cast() {
+        let ty = |val| TypeName::try_from(val).unwrap();
+        let expr = eq(cast("id", ty("integer")), 1);
+        assert_eq!(expr.to_string(), "CAST(id AS integer) = 1");
+        let expr = cast(sum("amount"), ty("double precision"));
+        assert_eq!(expr.to_string(), "CAST(SUM(amount) AS double precision)");
+        let expr = cast("price", ty("numeric(10, 2)"));
+        assert_eq!(expr.to_string(), "CAST(price AS numeric(10, 2))");
+        let expr = cast("grid", ty("int[][]"));
+        assert_eq!(expr.to_string(), "CAST(grid AS int[][])");
+    }
+
+    #[test]
+    fn cast_invalid_type() {
+        for val in [
+            "",
+            " text",
+            "text ",
+            "2text",
+            "text)--",
+            "text); DROP TABLE user; --",
+            "text), (SELECT password FROM users",
+            "varchar(16",
+            "varchar(a)",
+            "numeric(10, 2, 3)",
+            "int[1]",
+            "text -- comment",
+            "text\nAS int",
+        ] {
+            assert!(TypeName::try_from(val).is_err(), "{val}");
+        }
+        assert_eq!(
+            TypeName::try_from("text), (SELECT password FROM users"),
+            Err("is not a type name")
+        );
+    }
+
+    #[test]
+    fn between() {
+        let expr = and(crate::ops::between("age", 18, 65), eq("active", true));
+        assert_eq!(expr.to_string(), "age BETWEEN 18 AND 65 AND active = true");
+        let expr = or(eq("vip", true), not_between("age", 18, 65));
+        assert_eq!(expr.to_string(), "vip = true OR age NOT BETWEEN 18 AND 65");
+        let expr = crate::ops::between("flag", and(true, false), true);
+        assert_eq!(expr.to_string(), "flag BETWEEN (true AND false) AND true");
+    }
+
+    #[test]
+    fn like_with_escape() {
+        let pattern = "100!%".to_string();
+        let expr = not_like_escape("rate", &pattern, "!");
+        assert_eq!(expr.to_string(), "rate NOT LIKE '100!%' ESCAPE '!'");
+        let expr = ilike_escape("rate", &pattern, "!");
+        assert_eq!(expr.to_string(), "rate ILIKE '100!%' ESCAPE '!'");
+        let expr = not(like_escape("rate", &pattern, "!"));
+        assert_eq!(expr.to_string(), "NOT (rate LIKE '100!%' ESCAPE '!')");
+        let expr = and(not_ilike_escape("rate", &pattern, "!"), eq("active", true));
+        assert_eq!(
+            expr.to_string(),
+            "rate NOT ILIKE '100!%' ESCAPE '!' AND active = true",
+        );
+        let query = crate::stmt::select(["id"]).filter(not_like_escape("rate", &pattern, "!"));
+        assert_eq!(
+            query.to_sql(crate::dialect::Postgres).0,
+            r#"SELECT "id" WHERE "rate" NOT LIKE $1 ESCAPE $2"#,
+        );
+    }
+
+    #[test]
+    fn in_list() {
+        let expr = and(in_("id", [1, 2, 3]), not_in("status", [0]));
+        assert_eq!(expr.to_string(), "id IN (1, 2, 3) AND status NOT IN (0)");
+        let expr = and(
+            in_("id", Vec::<i32>::new()),
+            not_in("status", Vec::<i32>::new()),
+        );
+        assert_eq!(expr.to_string(), "false AND true");
+    }
+
+    #[test]
+    fn in_subquery() {
+        use crate::stmt::select;
+        let inner = select([("orders", "user_id")])
+            .from("orders")
+            .filter(eq(("orders", "shop_id"), ("users", "shop_id")));
+        let query = select([("users", "id")])
+            .from("users")
+            .filter(not_in_subquery(("users", "id"), inner));
+        assert_eq!(
+            query.to_string(),
+            "SELECT users.id FROM users WHERE users.id NOT IN (SELECT orders.user_id FROM orders WHERE orders.shop_id = users.shop_id)"
+        );
+    }
+
+    #[test]
+    fn null_test() {
+        let expr = and(is_null("deleted_at"), is_not_null("name"));
+        assert_eq!(expr.to_string(), "deleted_at IS NULL AND name IS NOT NULL");
+        let expr = is_null(and("a", "b"));
+        assert_eq!(expr.to_string(), "(a AND b) IS NULL");
+
+        let expr = and(eq("deleted_at", None::<i64>), ne("name", None::<&String>));
+        assert_eq!(expr.to_string(), "deleted_at IS NULL AND name IS NOT NULL");
+        let expr = eq(None::<i32>, add("a", 1));
+        assert_eq!(expr.to_string(), "a + 1 IS NULL");
+        let expr = eq("parent_id", Some(1));
+        assert_eq!(expr.to_string(), "parent_id = 1");
+    }
+
+    #[test]
+    fn distinct_from() {
+        let name = "bob".to_string();
+        let expr = or(is_distinct_from("nickname", &name), eq("id", 1));
+        assert_eq!(
+            expr.to_string(),
+            "nickname IS DISTINCT FROM 'bob' OR id = 1"
+        );
+        let expr = and(is_not_distinct_from("parent_id", None::<i32>), eq("id", 1));
+        assert_eq!(
+            expr.to_string(),
+            "parent_id IS NOT DISTINCT FROM NULL AND id = 1"
+        );
+    }
+
+    #[test]
+    fn quantified() {
+        use crate::stmt::select;
+        let expr = and(
+            eq_any("id", select(["user_id"]).from("orders")),
+            gt("age", all("ages")),
+        );
+        assert_eq!(
+            expr.to_string(),
+            "id = ANY(SELECT user_id FROM orders) AND age > ALL(ages)"
+        );
+    }
+
+    #[test]
+    fn precedence() {
+        assert_eq!(add("a", mul("b", "c")).to_string(), "a + b * c");
+        assert_eq!(mul(add("a", "b"), "c").to_string(), "(a + b) * c");
+        assert_eq!(mul("a", add("b", "c")).to_string(), "a * (b + c)");
+        assert_eq!(sub(sub("a", "b"), "c").to_string(), "a - b - c");
+        assert_eq!(sub("a", sub("b", "c")).to_string(), "a - (b - c)");
+        assert_eq!(
+            div(mul("a", "b"), rem("c", 2)).to_string(),
+            "a * b / (c % 2)"
+        );
+        assert_eq!(
+            gt(mul("price", "quantity"), add(100, "fee")).to_string(),
+            "price * quantity > 100 + fee"
+        );
+        assert_eq!(eq(eq("a", "b"), false).to_string(), "(a = b) = false");
+        assert_eq!(and(or("a", "b"), "c").to_string(), "(a OR b) AND c");
+        assert_eq!(or(and("a", "b"), "c").to_string(), "a AND b OR c");
+        assert_eq!(and(not("a"), "b").to_string(), "NOT a AND b");
+        assert_eq!(not(or("a", "b")).to_string(), "NOT (a OR b)");
+        assert_eq!(paren(add(1, 2)).to_string(), "(1 + 2)");
+    }
+
+    #[test]
+    fn negation() {
+        use crate::stmt::select;
+        assert_eq!(not("active").to_string(), "NOT active");
+        assert_eq!(not(eq("a", 1)).to_string(), "NOT (a = 1)");
+        assert_eq!(not(is_null("a")).to_string(), "NOT (a IS NULL)");
+        assert_eq!(
+            not(exists(select([1]).from("orders"))).to_string(),
+            "NOT EXISTS (SELECT 1 FROM orders)"
+        );
+        assert_eq!(and(not(eq("a", 1)), "b").to_string(), "NOT (a = 1) AND b");
+    }
+
+    #[test]
+    fn array_expr() {
+        use crate::stmt::select;
+        assert_eq!(
+            eq_any("id", array([1, 2, 3])).to_string(),
+            "id = ANY(ARRAY[1, 2, 3])"
+        );
+        assert_eq!(
+            select([array(["a", "b"])]).to_string(),
+            "SELECT ARRAY[a, b]"
+        );
+        assert_eq!(
+            array_typed(Vec::<i32>::new(), TypeName::try_from("int[]").unwrap()).to_string(),
+            "CAST(ARRAY[] AS int[])"
+        );
+    }
+
+    #[test]
+    fn subscript() {
+        use crate::func::func;
+        assert_eq!(index("tags", 1).to_string(), "tags[1]");
+        assert_eq!(index(index("matrix", 1), 2).to_string(), "matrix[1][2]");
+        assert_eq!(slice("tags", 1, 3).to_string(), "tags[1:3]");
+        assert_eq!(index("tags", add("i", 1)).to_string(), "tags[i + 1]");
+        assert_eq!(
+            index(array([1, 2, 3]), 1).to_string(),
+            "(ARRAY[1, 2, 3])[1]"
+        );
+        assert_eq!(
+            eq(index(func("string_to_array", ["path", "sep"]), 1), "name").to_string(),
+            "(string_to_array(path, sep))[1] = name"
+        );
+    }
+
+    #[test]
+    fn json() {
+        let key = "theme".to_string();
+        let expr = and(
+            eq(json_get_text("settings", &key), &key),
+            gt(add(json_get_text(json_get("data", "items"), 0), 1), 2),
+        );
+        assert_eq!(
+            expr.to_string(),
+            "settings ->> 'theme' = 'theme' AND (data -> 'items' ->> 0) + 1 > 2"
+        );
+        assert_eq!(
+            json_path_text("data", ["a", "b"]).to_string(),
+            "data #>> ARRAY['a', 'b']"
+        );
+    }
+
+    #[test]
+    fn regex() {
+        let pattern = r"^it's \w+$".to_string();
+        assert_eq!(
+            regex_match("name", &pattern).to_string(),
+            r"name ~ E'^it''s \\w+$'"
+        );
+        assert_eq!(
+            regex_imatch("name", &pattern).to_string(),
+            r"name ~* E'^it''s \\w+$'"
+        );
+
+        let pattern = "^tmp_".to_string();
+        let expr = and(
+            not_regex_match("name", &pattern),
+            not_regex_imatch(lower("email"), &pattern),
+        );
+        assert_eq!(
+            expr.to_string(),
+            "name !~ '^tmp_' AND LOWER(email) !~* '^tmp_'"
+        );
+        assert_eq!(
+            eq(regex_match("name", &pattern), false).to_string(),
+            "name ~ '^tmp_' = false"
+        );
+
+        let (sql, args) = crate::stmt::select(["id"])
+            .from("user")
+            .filter(regex_imatch("name", &pattern))
+            .to_sql(crate::dialect::Postgres);
+        assert_eq!(sql, r#"SELECT "id" FROM "user" WHERE "name" ~* $1"#);
+        assert_eq!(args, [Value::Text("^tmp_".into())]);
+    }
+
+    #[test]
+    fn containment() {
+        let (rust, sql) = ("rust".to_string(), "sql".to_string());
+        let expr = and(
+            contains("tags", array([&rust])),
+            overlaps("tags", array([&rust, &sql])),
+        );
+        assert_eq!(
+            expr.to_string(),
+            "tags @> ARRAY['rust'] AND tags && ARRAY['rust', 'sql']"
+        );
+
+        let expr = or(contained_by("ids", array([1, 2])), eq("owner", 3));
+        assert_eq!(expr.to_string(), "ids <@ ARRAY[1, 2] OR owner = 3");
+
+        let expr = contains(json_get("data", "user"), "filter");
+        assert_eq!(expr.to_string(), "data -> 'user' @> filter");
+
+        let expr = overlaps(and("a", "b"), "c");
+        assert_eq!(expr.to_string(), "(a AND b) && c");
+        let expr = not(contains("tags", "wanted"));
+        assert_eq!(expr.to_string(), "NOT (tags @> wanted)");
+    }
+
+    #[test]
+    fn interval_literal() {
+        let expr = gt("created_at", sub(crate::func::now(), interval("7 days")));
+        assert_eq!(expr.to_string(), "created_at > NOW() - INTERVAL '7 days'");
+
+        let expr = add(interval("1 hour"), interval("30 minutes"));
+        assert_eq!(
+            expr.to_string(),
+            "INTERVAL '1 hour' + INTERVAL '30 minutes'"
+        );
+
+        let (sql, args) = crate::stmt::select(["id"])
+            .from("event")
+            .filter(gt("created", sub("updated", interval("1 day'; --"))))
+            .to_sql(crate::dialect::Postgres);
+        assert_eq!(
+            sql,
+            r#"SELECT "id" FROM "event" WHERE "created" > "updated" - INTERVAL '1 day''; --'"#
+        );
+        assert!(args.is_empty());
+    }
+
+    #[test]
+    fn deep_precedence() {
+        let expr = or(
+            and(eq("a", 1), or(eq("b", 2), eq("c", 3))),
+            and(not(eq("d", 4)), gt(add("e", mul("f", sub("g", "h"))), 0)),
+        );
+        assert_eq!(
+            expr.to_string(),
+            "a = 1 AND (b = 2 OR c = 3) OR NOT (d = 4) AND e + f * (g - h) > 0"
+        );
+
+        let expr = and(and(and("a", "b"), "c"), and("d", and("e", "f")));
+        assert_eq!(expr.to_string(), "a AND b AND c AND d AND e AND f");
+        assert_eq!(or("a", or("b", "c")).to_string(), "a OR b OR c");
+        assert_eq!(add("a", add("b", "c")).to_string(), "a + b + c");
+        assert_eq!(add("a", sub("b", "c")).to_string(), "a + (b - c)");
+        assert_eq!(mul("a", mul("b", "c")).to_string(), "a * b * c");
+        assert_eq!(mul("a", div("b", "c")).to_string(), "a * (b / c)");
+
+        let expr = div(div("a", "b"), div("c", mul("d", "e")));
+        assert_eq!(expr.to_string(), "a / b / (c / (d * e))");
+
+        let expr = mul(add(sub("a", "b"), "c"), add("d", rem("e", "f")));
+        assert_eq!(expr.to_string(), "(a - b + c) * (d + e % f)");
+
+        let expr = eq(gt("a", "b"), lt("c", "d"));
+        assert_eq!(expr.to_string(), "(a > b) = (c < d)");
+
+        let expr = lt(add(mul("a", 2), 1), paren(sub("b", 1)));
+        assert_eq!(expr.to_string(), "a * 2 + 1 < (b - 1)");
+
+        let expr = and(
+            crate::ops::between(add("a", 1), "b", mul("c", 2)),
+            is_null("d"),
+        );
+        assert_eq!(expr.to_string(), "a + 1 BETWEEN b AND c * 2 AND d IS NULL");
+
+        let expr = eq(crate::ops::between("a", 1, 2), is_null(or("b", "c")));
+        assert_eq!(expr.to_string(), "(a BETWEEN 1 AND 2) = ((b OR c) IS NULL)");
+
+        let expr = or(in_(add("a", 1), [1, 2]), like(json_get_text("d", "k"), "p"));
+        assert_eq!(expr.to_string(), "a + 1 IN (1, 2) OR d ->> 'k' LIKE p");
+
+        let expr = mul(binop("a", "||", "b"), not(and("c", "d")));
+        assert_eq!(expr.to_string(), "(a || b) * (NOT (c AND d))");
+    }
+
     #[test]
     fn subquery() {
         use crate::stmt::select;
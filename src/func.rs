@@ -1,6 +1,8 @@
 use crate::expr::Expr;
+use crate::item::DateField;
 use crate::item::FuncCall;
 use crate::item::FuncRef;
+use crate::value::Value;
 
 /// Construct a function call expression.
 #[inline]
@@ -10,7 +12,24 @@ where
     A: Into<Expr<'a>>,
     I: IntoIterator<Item = A>,
 {
-    FuncCall(func.into(), args.into_iter().map(Into::into).collect())
+    FuncCall {
+        func: func.into(),
+        args: args.into_iter().map(Into::into).collect(),
+        filter: None,
+        distinct: false,
+        orders: None,
+    }
+}
+
+/// Construct a call to a built-in function, whose name is never quoted.
+#[inline]
+fn builtin<'a, N, A, I>(name: N, args: I) -> FuncCall<'a>
+where
+    N: Into<crate::item::Keyword>,
+    A: Into<Expr<'a>>,
+    I: IntoIterator<Item = A>,
+{
+    func(FuncRef::Builtin(name.into()), args)
 }
 
 macro_rules! gen_funcs {
@@ -22,10 +41,7 @@ macro_rules! gen_funcs {
             where
                 A: Into<Expr<'a>>,
             {
-                let mut s = stringify!($func).to_string();
-                s.make_ascii_uppercase();
-                let s: &'static str = Box::leak(s.into_boxed_str());
-                func(s, [arg.into()])
+                builtin(stringify!($func).to_ascii_uppercase(), [arg.into()])
             }
         )+
     };
@@ -41,5 +57,672 @@ gen_funcs!(
     /// Construct a call to `min` aggregate function.
     min,
     /// Construct a call to `max` aggregate function.
-    max
+    max,
+    /// Construct a call to `abs` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::abs;
+    /// use qians_xql::sub;
+    ///
+    /// assert_eq!(abs(sub("a", "b")).to_string(), "ABS(a - b)");
+    /// ```
+    abs,
+    /// Construct a call to `round` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::round;
+    ///
+    /// assert_eq!(round("price").to_string(), "ROUND(price)");
+    /// ```
+    round,
+    /// Construct a call to `ceil` function.
+    ceil,
+    /// Construct a call to `floor` function.
+    floor,
+    /// Construct a call to `length` function.
+    length,
+    /// Construct a call to `lower` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::eq;
+    /// use qians_xql::lower;
+    ///
+    /// assert_eq!(
+    ///     eq(lower("email"), &"alice@example.com".to_string()).to_string(),
+    ///     "LOWER(email) = 'alice@example.com'",
+    /// );
+    /// ```
+    lower,
+    /// Construct a call to `upper` function.
+    upper,
+    /// Construct a call to `trim` function.
+    trim,
+    /// Construct a call to `to_tsvector` function, see [`matches`](crate::ops::matches).
+    to_tsvector,
+    /// Construct a call to `to_tsquery` function, see [`matches`](crate::ops::matches).
+    to_tsquery,
+    /// Construct a call to `plainto_tsquery` function, which parses plain
+    /// text instead of the `tsquery` syntax.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::matches;
+    /// use qians_xql::plainto_tsquery;
+    ///
+    /// let text = "rust sql".to_string();
+    /// assert_eq!(
+    ///     matches("document", plainto_tsquery(&text)).to_string(),
+    ///     "document @@ PLAINTO_TSQUERY('rust sql')",
+    /// );
+    /// ```
+    plainto_tsquery
+);
+
+/// Construct a call to `now` function.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::now;
+///
+/// assert_eq!(now().to_string(), "NOW()");
+/// ```
+#[inline]
+pub fn now<'a>() -> FuncCall<'a> {
+    builtin("NOW", std::iter::empty::<Expr>())
+}
+
+/// Construct `CURRENT_DATE`, the date at the start of the transaction.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::current_date;
+/// use qians_xql::eq;
+///
+/// assert_eq!(eq("day", current_date()).to_string(), "day = CURRENT_DATE");
+/// ```
+#[inline]
+pub fn current_date<'a>() -> Expr<'a> {
+    crate::ops::raw("CURRENT_DATE")
+}
+
+/// Construct `CURRENT_TIMESTAMP`, the timestamp at the start of the
+/// transaction.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::current_timestamp;
+/// use qians_xql::lt;
+///
+/// assert_eq!(
+///     lt("expires", current_timestamp()).to_string(),
+///     "expires < CURRENT_TIMESTAMP",
+/// );
+/// ```
+#[inline]
+pub fn current_timestamp<'a>() -> Expr<'a> {
+    crate::ops::raw("CURRENT_TIMESTAMP")
+}
+
+/// Construct `EXTRACT(field FROM source)`, getting a field of a date, time or
+/// interval.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::extract;
+/// use qians_xql::item::DateField;
+/// use qians_xql::select;
+///
+/// let query = select([extract(DateField::Year, "created")]).from("post");
+/// assert_eq!(query.to_string(), "SELECT EXTRACT(YEAR FROM created) FROM post");
+///
+/// let query = select([extract(DateField::YearMonth, "created")]).from("post");
+/// assert_eq!(query.to_string(), "SELECT EXTRACT(YEAR_MONTH FROM created) FROM post");
+/// ```
+#[inline]
+pub fn extract<'a, E>(field: DateField, source: E) -> Expr<'a>
+where
+    E: Into<Expr<'a>>,
+{
+    Expr::Extract(field, Box::new(source.into()))
+}
+
+/// Construct a call to `date_trunc` function, truncating a timestamp to the
+/// given precision, e.g. `day` or `month`.
+///
+/// The precision is written as a quoted string literal, since PostgreSQL
+/// can't infer the type of a parameter there.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::count_all;
+/// use qians_xql::date_trunc;
+/// use qians_xql::select;
+///
+/// let query = select([date_trunc("day", "created").alias("day"), count_all().into()])
+///     .from("post")
+///     .group_by([1]);
+/// assert_eq!(
+///     query.to_string(),
+///     "SELECT DATE_TRUNC('day', created) AS day, COUNT(*) FROM post GROUP BY 1",
+/// );
+/// ```
+#[inline]
+pub fn date_trunc<'a, E>(part: &'a str, source: E) -> FuncCall<'a>
+where
+    E: Into<Expr<'a>>,
+{
+    builtin("DATE_TRUNC", [Expr::Quoted(part.into()), source.into()])
+}
+
+/// Construct `COUNT(*)`, counting all rows.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::count_all;
+/// use qians_xql::select;
+///
+/// let query = select([count_all()]).from("book");
+/// assert_eq!(query.to_string(), "SELECT COUNT(*) FROM book");
+/// ```
+#[inline]
+pub fn count_all<'a>() -> FuncCall<'a> {
+    builtin("COUNT", [crate::ops::star()])
+}
+
+macro_rules! gen_variadic_funcs {
+    ($($(#[$comment:meta])* $func:ident: $name:literal),+) => {
+        $(
+            $(#[$comment])*
+            #[inline]
+            pub fn $func<'a, A, I>(args: I) -> FuncCall<'a>
+            where
+                A: Into<Expr<'a>>,
+                I: IntoIterator<Item = A>,
+            {
+                let call = builtin($name, args);
+                assert!(
+                    !call.args.is_empty(),
+                    concat!("`", $name, "` requires at least one argument")
+                );
+                call
+            }
+        )+
+    };
+}
+
+gen_variadic_funcs!(
+    /// Construct a call to `coalesce` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::coalesce;
+    ///
+    /// assert_eq!(coalesce(["nickname", "name"]).to_string(), "COALESCE(nickname, name)");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if no argument is given.
+    coalesce: "COALESCE",
+    /// Construct a call to `greatest` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::greatest;
+    ///
+    /// assert_eq!(greatest([1, 2, 3]).to_string(), "GREATEST(1, 2, 3)");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if no argument is given.
+    greatest: "GREATEST",
+    /// Construct a call to `least` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::least;
+    ///
+    /// assert_eq!(least([1, 2, 3]).to_string(), "LEAST(1, 2, 3)");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if no argument is given.
+    least: "LEAST"
+);
+
+/// Construct a call to `nullif` function.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::nullif;
+///
+/// assert_eq!(nullif("price", 0).to_string(), "NULLIF(price, 0)");
+/// ```
+#[inline]
+pub fn nullif<'a, L, R>(left: L, right: R) -> FuncCall<'a>
+where
+    L: Into<Expr<'a>>,
+    R: Into<Expr<'a>>,
+{
+    builtin("NULLIF", [left.into(), right.into()])
+}
+
+/// Construct a call to `string_agg` aggregate function.
+///
+/// The separator is always a literal text. Use
+/// [`FuncCall::order_by`] to order the concatenated values.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::string_agg;
+///
+/// assert_eq!(
+///     string_agg("name", ", ").order_by(["name"]).to_string(),
+///     "STRING_AGG(name, ', ' ORDER BY name)",
+/// );
+/// ```
+#[inline]
+pub fn string_agg<'a, E>(expr: E, sep: &'a str) -> FuncCall<'a>
+where
+    E: Into<Expr<'a>>,
+{
+    builtin("STRING_AGG", [expr.into(), Expr::Literal(Value::Text(sep.into()))])
+}
+
+macro_rules! gen_window_funcs {
+    ($($(#[$comment:meta])* $func:ident: $name:literal),+) => {
+        $(
+            $(#[$comment])*
+            #[inline]
+            pub fn $func<'a>() -> FuncCall<'a> {
+                builtin($name, std::iter::empty::<Expr>())
+            }
+        )+
+    };
+}
+
+gen_window_funcs!(
+    /// Construct a call to `row_number` window function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::row_number;
+    /// use qians_xql::item::Window;
+    ///
+    /// assert_eq!(
+    ///     row_number().over(Window::default().order_by(["id"])).to_string(),
+    ///     "ROW_NUMBER() OVER (ORDER BY id)",
+    /// );
+    /// ```
+    row_number: "ROW_NUMBER",
+    /// Construct a call to `rank` window function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::desc;
+    /// use qians_xql::rank;
+    /// use qians_xql::item::Window;
+    ///
+    /// let window = Window::default().partition_by(["dept"]).order_by([desc("salary")]);
+    /// assert_eq!(
+    ///     rank().over(window).to_string(),
+    ///     "RANK() OVER (PARTITION BY dept ORDER BY salary DESC)",
+    /// );
+    /// ```
+    rank: "RANK",
+    /// Construct a call to `dense_rank` window function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::dense_rank;
+    /// use qians_xql::item::Window;
+    ///
+    /// assert_eq!(
+    ///     dense_rank().over(Window::default().order_by(["score"])).to_string(),
+    ///     "DENSE_RANK() OVER (ORDER BY score)",
+    /// );
+    /// ```
+    dense_rank: "DENSE_RANK"
 );
+
+/// Construct a call to `lag` window function, the value of `expr` at `offset`
+/// rows before the current row.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::lag;
+/// use qians_xql::item::Window;
+///
+/// assert_eq!(
+///     lag("price", 1).over(Window::default().order_by(["day"])).to_string(),
+///     "LAG(price, 1) OVER (ORDER BY day)",
+/// );
+/// ```
+#[inline]
+pub fn lag<'a, E, N>(expr: E, offset: N) -> FuncCall<'a>
+where
+    E: Into<Expr<'a>>,
+    N: Into<Expr<'a>>,
+{
+    builtin("LAG", [expr.into(), offset.into()])
+}
+
+/// Construct a call to `lead` window function, the value of `expr` at
+/// `offset` rows after the current row.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::lead;
+/// use qians_xql::item::Window;
+///
+/// assert_eq!(
+///     lead("price", 1).over(Window::default().order_by(["day"])).to_string(),
+///     "LEAD(price, 1) OVER (ORDER BY day)",
+/// );
+/// ```
+#[inline]
+pub fn lead<'a, E, N>(expr: E, offset: N) -> FuncCall<'a>
+where
+    E: Into<Expr<'a>>,
+    N: Into<Expr<'a>>,
+{
+    builtin("LEAD", [expr.into(), offset.into()])
+}
+
+/// Construct a call to `ntile` window function, dividing the rows into `n`
+/// buckets.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::ntile;
+/// use qians_xql::item::Window;
+///
+/// assert_eq!(
+///     ntile(4).over(Window::default().order_by(["score"])).to_string(),
+///     "NTILE(4) OVER (ORDER BY score)",
+/// );
+/// ```
+#[inline]
+pub fn ntile<'a, N>(n: N) -> FuncCall<'a>
+where
+    N: Into<Expr<'a>>,
+{
+    builtin("NTILE", [n.into()])
+}
+
+#[test]
+#[cfg(test)]
+fn conditional() {
+    use crate::dialect::Postgres;
+    use crate::ops::div;
+    use crate::stmt::select;
+
+    let expr = coalesce([div("total", nullif("count", 0)), 0.into()]);
+    assert_eq!(expr.to_string(), "COALESCE(total / NULLIF(count, 0), 0)");
+
+    let sql = |expr: FuncCall<'static>| select([expr]).to_sql(Postgres).0;
+    assert_eq!(
+        sql(coalesce(["nickname", "name"])),
+        r#"SELECT COALESCE("nickname", "name")"#
+    );
+    assert_eq!(sql(greatest(["a", "b"])), r#"SELECT GREATEST("a", "b")"#);
+    assert_eq!(sql(least(["a", "b"])), r#"SELECT LEAST("a", "b")"#);
+    assert_eq!(
+        sql(nullif("price", "cost")),
+        r#"SELECT NULLIF("price", "cost")"#
+    );
+}
+
+#[test]
+#[cfg(test)]
+#[should_panic(expected = "`COALESCE` requires at least one argument")]
+fn coalesce_empty() {
+    coalesce(Vec::<Expr>::new());
+}
+
+#[test]
+#[cfg(test)]
+fn filter() {
+    use crate::item::Window;
+    use crate::ops::eq;
+    use crate::ops::gt;
+
+    let expr = count("*").filter(eq("status", 1));
+    assert_eq!(expr.to_string(), "COUNT(*) FILTER (WHERE status = 1)");
+
+    let expr = sum("amount")
+        .filter(eq("status", 1))
+        .filter(gt("amount", 0));
+    assert_eq!(
+        expr.to_string(),
+        "SUM(amount) FILTER (WHERE status = 1 AND amount > 0)"
+    );
+
+    let expr = count("*")
+        .filter(eq("status", 1))
+        .over(Window::default().partition_by(["dept"]));
+    assert_eq!(
+        expr.to_string(),
+        "COUNT(*) FILTER (WHERE status = 1) OVER (PARTITION BY dept)"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn distinct() {
+    use crate::ops::eq;
+
+    assert_eq!(
+        count("user_id").distinct().to_string(),
+        "COUNT(DISTINCT user_id)"
+    );
+    assert_eq!(sum("amount").distinct().to_string(), "SUM(DISTINCT amount)");
+    assert_eq!(avg("amount").distinct().to_string(), "AVG(DISTINCT amount)");
+    assert_eq!(
+        count("user_id")
+            .distinct()
+            .filter(eq("status", 1))
+            .to_string(),
+        "COUNT(DISTINCT user_id) FILTER (WHERE status = 1)"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn order_by() {
+    use crate::ops::desc;
+
+    let expr = string_agg("name", ", ").order_by(["name"]);
+    assert_eq!(expr.to_string(), "STRING_AGG(name, ', ' ORDER BY name)");
+
+    let expr = string_agg("name", ", ")
+        .distinct()
+        .order_by(["name"])
+        .order_by([desc("id")]);
+    assert_eq!(
+        expr.to_string(),
+        "STRING_AGG(DISTINCT name, ', ' ORDER BY name, id DESC)"
+    );
+
+    assert_eq!(
+        string_agg("name", "; ").to_string(),
+        "STRING_AGG(name, '; ')"
+    );
+
+    let (sql, _) = crate::stmt::select([string_agg("name", ", ").order_by(["name"])])
+        .from("user")
+        .to_sql(crate::dialect::Postgres);
+    assert_eq!(
+        sql,
+        r#"SELECT STRING_AGG("name", $1 ORDER BY "name") FROM "user""#
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn scalar() {
+    use crate::ops::gt;
+
+    assert_eq!(ceil("price").to_string(), "CEIL(price)");
+    assert_eq!(floor("price").to_string(), "FLOOR(price)");
+    assert_eq!(
+        gt(length(trim("name")), 0).to_string(),
+        "LENGTH(TRIM(name)) > 0"
+    );
+    assert_eq!(upper("code").to_string(), "UPPER(code)");
+    assert_eq!(gt("created", now()).to_string(), "created > NOW()");
+
+    let (sql, _) = crate::stmt::select(["id"])
+        .from("post")
+        .filter(gt("created", now()))
+        .to_sql(crate::dialect::Postgres);
+    assert_eq!(sql, r#"SELECT "id" FROM "post" WHERE "created" > NOW()"#);
+}
+
+#[test]
+#[cfg(test)]
+fn text_search() {
+    use crate::ops::and;
+    use crate::ops::matches;
+    use crate::ops::or;
+    use crate::stmt::select;
+
+    let rust = "rust".to_string();
+    let sql = "sql".to_string();
+    let query = select(["id"]).from("post").filter(and(
+        matches(to_tsvector("body"), to_tsquery(&rust)),
+        matches(to_tsvector("title"), plainto_tsquery(&sql)),
+    ));
+    assert_eq!(
+        query.to_string(),
+        "SELECT id FROM post WHERE TO_TSVECTOR(body) @@ TO_TSQUERY('rust') \
+        AND TO_TSVECTOR(title) @@ PLAINTO_TSQUERY('sql')"
+    );
+
+    assert_eq!(
+        matches(or("title", "body"), to_tsquery(&rust)).to_string(),
+        "(title OR body) @@ TO_TSQUERY('rust')"
+    );
+
+    let (sql, _) = query.to_sql(crate::dialect::Postgres);
+    assert_eq!(
+        sql,
+        r#"SELECT "id" FROM "post" WHERE TO_TSVECTOR("body") @@ TO_TSQUERY($1) AND TO_TSVECTOR("title") @@ PLAINTO_TSQUERY($2)"#
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn datetime() {
+    use crate::dialect::MySql;
+    use crate::dialect::Postgres;
+    use crate::ops::ge;
+    use crate::ops::sub;
+    use crate::stmt::select;
+
+    let query = select([
+        extract(DateField::Month, "created").alias("month"),
+        count_all().into(),
+    ])
+    .from("post")
+    .filter(ge(date_trunc("day", "created"), sub(current_date(), 30)))
+    .group_by([1]);
+    assert_eq!(
+        query.to_string(),
+        "SELECT EXTRACT(MONTH FROM created) AS month, COUNT(*) FROM post \
+        WHERE DATE_TRUNC('day', created) >= CURRENT_DATE - 30 GROUP BY 1"
+    );
+
+    let elapsed = sub(current_timestamp(), "created");
+    let (sql, args) = select([extract(DateField::Epoch, elapsed)])
+        .from("post")
+        .to_sql(Postgres);
+    assert_eq!(
+        sql,
+        r#"SELECT EXTRACT(EPOCH FROM CURRENT_TIMESTAMP - "created") FROM "post""#
+    );
+    assert!(args.is_empty());
+
+    let (sql, args) = select([date_trunc("it's", "created")])
+        .from("post")
+        .to_sql(Postgres);
+    assert_eq!(sql, r#"SELECT DATE_TRUNC('it''s', "created") FROM "post""#);
+    assert!(args.is_empty());
+
+    let (sql, _) = select([
+        extract(DateField::Microsecond, "created"),
+        extract(DateField::DayHour, "created"),
+    ])
+    .from("post")
+    .to_sql(MySql);
+    assert_eq!(
+        sql,
+        "SELECT EXTRACT(MICROSECOND FROM `created`), EXTRACT(DAY_HOUR FROM `created`) FROM `post`"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn window() {
+    use crate::dialect::Postgres;
+    use crate::item::Window;
+    use crate::stmt::select;
+
+    let window = Window::from("w");
+    let query = select([
+        row_number().over(window).alias("n"),
+        lag("price", 2).over(Window::from("w")).alias("prev"),
+    ])
+    .from("quote")
+    .window(
+        "w",
+        Window::default().partition_by(["symbol"]).order_by(["day"]),
+    );
+    assert_eq!(
+        query.to_string(),
+        "SELECT ROW_NUMBER() OVER w AS n, LAG(price, 2) OVER w AS prev FROM quote \
+        WINDOW w AS (PARTITION BY symbol ORDER BY day)"
+    );
+
+    let window = || Window::default().order_by(["day"]);
+    let query = select([
+        row_number().over(window()),
+        rank().over(window()),
+        dense_rank().over(window()),
+        lag("price", 1).over(window()),
+        lead("price", 1).over(window()),
+        ntile(4).over(window()),
+    ]);
+    assert_eq!(
+        query.to_sql(Postgres).0,
+        "SELECT ROW_NUMBER() OVER (ORDER BY \"day\"), RANK() OVER (ORDER BY \"day\"), \
+        DENSE_RANK() OVER (ORDER BY \"day\"), LAG(\"price\", $1) OVER (ORDER BY \"day\"), \
+        LEAD(\"price\", $2) OVER (ORDER BY \"day\"), NTILE($3) OVER (ORDER BY \"day\")"
+    );
+}
@@ -1,35 +1,328 @@
+use std::borrow::Cow;
+
 use crate::expr::Expr;
 use crate::table_expr::TableExpr;
 
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
-pub struct Ident<'a>(pub &'a str);
+/// An operator or keyword stored in the AST.
+///
+/// Built-in operators are borrowed, while deserialized ones are owned, so
+/// that any operator, including custom ones from [`binop`](crate::ops::binop),
+/// round-trips through serde.
+pub(crate) type Keyword = Cow<'static, str>;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
+pub struct Ident<'a>(#[cfg_attr(feature = "serde", serde(borrow))] pub Cow<'a, str>);
 
 crate::macros::gen_display!(Ident<'_>);
 
+/// Keywords reserved by Postgres, which can't be used as bare identifiers.
+const RESERVED: &[&str] = &[
+    "ALL",
+    "ANALYSE",
+    "ANALYZE",
+    "AND",
+    "ANY",
+    "ARRAY",
+    "AS",
+    "ASC",
+    "ASYMMETRIC",
+    "BOTH",
+    "CASE",
+    "CAST",
+    "CHECK",
+    "COLLATE",
+    "COLUMN",
+    "CONSTRAINT",
+    "CREATE",
+    "CURRENT_CATALOG",
+    "CURRENT_DATE",
+    "CURRENT_ROLE",
+    "CURRENT_TIME",
+    "CURRENT_TIMESTAMP",
+    "CURRENT_USER",
+    "DEFAULT",
+    "DEFERRABLE",
+    "DESC",
+    "DISTINCT",
+    "DO",
+    "ELSE",
+    "END",
+    "EXCEPT",
+    "FALSE",
+    "FETCH",
+    "FOR",
+    "FOREIGN",
+    "FROM",
+    "GRANT",
+    "GROUP",
+    "HAVING",
+    "IN",
+    "INITIALLY",
+    "INTERSECT",
+    "INTO",
+    "LATERAL",
+    "LEADING",
+    "LIMIT",
+    "LOCALTIME",
+    "LOCALTIMESTAMP",
+    "NOT",
+    "NULL",
+    "OFFSET",
+    "ON",
+    "ONLY",
+    "OR",
+    "ORDER",
+    "PLACING",
+    "PRIMARY",
+    "REFERENCES",
+    "RETURNING",
+    "SELECT",
+    "SESSION_USER",
+    "SOME",
+    "SYMMETRIC",
+    "SYSTEM_USER",
+    "TABLE",
+    "THEN",
+    "TO",
+    "TRAILING",
+    "TRUE",
+    "UNION",
+    "UNIQUE",
+    "USER",
+    "USING",
+    "VARIADIC",
+    "WHEN",
+    "WHERE",
+    "WINDOW",
+    "WITH",
+];
+
+impl<'a> Ident<'a> {
+    /// Create an identifier borrowing `val`, usable in constants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::item::Ident;
+    ///
+    /// const ID: Ident = Ident::new("id");
+    /// assert_eq!(ID, Ident::from("id"));
+    /// ```
+    #[inline]
+    pub const fn new(val: &'a str) -> Self {
+        Ident(Cow::Borrowed(val))
+    }
+
+    /// Create an identifier that is safe to render unquoted, i.e. with
+    /// [`Display`](std::fmt::Display).
+    ///
+    /// Identifiers are quoted when rendered for a
+    /// [`Dialect`](crate::dialect::Dialect), so this check is only needed
+    /// for identifiers that come from outside the program and are rendered
+    /// unquoted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::item::Ident;
+    ///
+    /// assert_eq!(Ident::checked("user_id"), Ok(Ident::from("user_id")));
+    /// assert_eq!(
+    ///     Ident::checked("id; DROP TABLE book"),
+    ///     Err("contains characters other than letters, digits and underscores"),
+    /// );
+    /// assert_eq!(Ident::checked("order"), Err("is a reserved word"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `val` is empty, starts with a digit, contains
+    /// characters other than ASCII letters, digits and underscores, or is a
+    /// reserved word.
+    pub fn checked(val: &'a str) -> Result<Self, &'static str> {
+        if val.is_empty() {
+            Err("is empty")
+        } else if val.starts_with(|c: char| c.is_ascii_digit()) {
+            Err("starts with a digit")
+        } else if !val.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            Err("contains characters other than letters, digits and underscores")
+        } else if RESERVED
+            .binary_search(&val.to_ascii_uppercase().as_str())
+            .is_ok()
+        {
+            Err("is a reserved word")
+        } else {
+            Ok(Ident(Cow::Borrowed(val)))
+        }
+    }
+}
+
 impl<'a> std::convert::From<&'a str> for Ident<'a> {
     #[inline]
     fn from(val: &'a str) -> Self {
-        Ident(val)
+        Ident(Cow::Borrowed(val))
+    }
+}
+
+impl std::convert::From<String> for Ident<'_> {
+    #[inline]
+    fn from(val: String) -> Self {
+        Ident(Cow::Owned(val))
     }
 }
 
-impl<'a> std::convert::From<ColumnRef<'a>> for Ident<'a> {
+/// Take the name of a column, without the table or schema it belongs to.
+///
+/// # Errors
+///
+/// Returns an error if the column reference is `*`, `table.*` or
+/// `EXCLUDED.col`, which can't be written as a column name.
+impl<'a> std::convert::TryFrom<ColumnRef<'a>> for Ident<'a> {
+    type Error = &'static str;
+
     #[inline]
-    fn from(val: ColumnRef<'a>) -> Self {
+    fn try_from(val: ColumnRef<'a>) -> Result<Self, Self::Error> {
         match val {
-            ColumnRef::Column(col) => col,
-            ColumnRef::TableColumn(.., col) => col,
-            ColumnRef::SchemaTableColumn(.., col) => col,
+            ColumnRef::Column(col) => Ok(col),
+            ColumnRef::TableColumn(.., col) => Ok(col),
+            ColumnRef::SchemaTableColumn(.., col) => Ok(col),
+            ColumnRef::Star | ColumnRef::TableStar(..) => Err("is not a column name"),
+            ColumnRef::Excluded(..) => Err("is a column of `EXCLUDED`"),
+        }
+    }
+}
+
+/// A key of a JSON object or an index of a JSON array.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
+pub enum JsonKey<'a> {
+    Key(#[cfg_attr(feature = "serde", serde(borrow))] Cow<'a, str>),
+    Index(i32),
+}
+
+impl<'a> std::convert::From<&'a str> for JsonKey<'a> {
+    #[inline]
+    fn from(val: &'a str) -> Self {
+        JsonKey::Key(Cow::Borrowed(val))
+    }
+}
+
+impl<'a> std::convert::From<&'a String> for JsonKey<'a> {
+    #[inline]
+    fn from(val: &'a String) -> Self {
+        JsonKey::Key(Cow::Borrowed(val))
+    }
+}
+
+impl std::convert::From<i32> for JsonKey<'_> {
+    #[inline]
+    fn from(val: i32) -> Self {
+        JsonKey::Index(val)
+    }
+}
+
+impl<'a> std::convert::From<JsonKey<'a>> for Expr<'a> {
+    #[inline]
+    fn from(val: JsonKey<'a>) -> Self {
+        match val {
+            JsonKey::Key(key) => Expr::Literal(crate::value::Value::Text(key)),
+            JsonKey::Index(idx) => Expr::Literal(crate::value::Value::Int(idx)),
+        }
+    }
+}
+
+/// A type name used by `CAST`.
+///
+/// Type names are rendered as is, so they are checked when created: one or
+/// more words separated by single spaces, an optional length or precision
+/// such as `(255)` or `(10, 2)`, then any number of `[]`, e.g.
+/// `varchar(255)`, `double precision` or `integer[]`.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::item::TypeName;
+///
+/// assert!(TypeName::try_from("numeric(10, 2)").is_ok());
+/// assert!(TypeName::try_from("text), (SELECT password FROM users").is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Cow<'a, str>"))]
+pub struct TypeName<'a>(pub(crate) Cow<'a, str>);
+
+crate::macros::gen_display!(TypeName<'_>);
+
+impl<'a> std::convert::TryFrom<&'a str> for TypeName<'a> {
+    type Error = &'static str;
+
+    #[inline]
+    fn try_from(val: &'a str) -> Result<Self, Self::Error> {
+        TypeName::try_from(Cow::Borrowed(val))
+    }
+}
+
+impl<'a> std::convert::TryFrom<Cow<'a, str>> for TypeName<'a> {
+    type Error = &'static str;
+
+    fn try_from(val: Cow<'a, str>) -> Result<Self, Self::Error> {
+        fn word(val: &str) -> Option<&str> {
+            let rest = val.trim_start_matches(|c: char| c.is_ascii_alphanumeric() || c == '_');
+            (rest.len() < val.len() && !val.starts_with(|c: char| c.is_ascii_digit()))
+                .then_some(rest)
+        }
+
+        fn number(val: &str) -> Option<&str> {
+            let rest = val.trim_start_matches(|c: char| c.is_ascii_digit());
+            (rest.len() < val.len()).then_some(rest)
+        }
+
+        if val.is_empty() {
+            return Err("is empty");
+        }
+        let mut rest = word(&val).ok_or("does not start with a word")?;
+        while let Some(next) = rest.strip_prefix(' ').and_then(word) {
+            rest = next;
+        }
+        if let Some(args) = rest.strip_prefix('(') {
+            let mut args = number(args).ok_or("has an invalid length or precision")?;
+            if let Some(scale) = args.strip_prefix(',') {
+                let scale = scale.strip_prefix(' ').unwrap_or(scale);
+                args = number(scale).ok_or("has an invalid length or precision")?;
+            }
+            rest = args
+                .strip_prefix(')')
+                .ok_or("has an invalid length or precision")?;
+        }
+        while let Some(next) = rest.strip_prefix("[]") {
+            rest = next;
+        }
+        if rest.is_empty() {
+            Ok(TypeName(val))
+        } else {
+            Err("is not a type name")
         }
     }
 }
 
 #[allow(clippy::enum_variant_names)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 pub enum ColumnRef<'a> {
+    /// All columns, see [`star`](crate::ops::star).
+    Star,
     Column(Ident<'a>),
     TableColumn(Ident<'a>, Ident<'a>),
     SchemaTableColumn(Ident<'a>, Ident<'a>, Ident<'a>),
+    /// All columns of a table, see [`star_of`](crate::ops::star_of).
+    TableStar(Ident<'a>),
+    /// A column of the row proposed for insertion, see
+    /// [`excluded`](crate::ops::excluded).
+    Excluded(Ident<'a>),
 }
 
 crate::macros::gen_display!(ColumnRef<'_>);
@@ -62,7 +355,9 @@ impl<'a> std::convert::From<(&'a str, &'a str, &'a str)> for ColumnRef<'a> {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 pub enum TableRef<'a> {
     Table(Ident<'a>),
     SchemaTable(Ident<'a>, Ident<'a>),
@@ -92,6 +387,7 @@ impl std::default::Default for TableRef<'_> {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Sort {
     Asc,
     Desc,
@@ -106,18 +402,123 @@ impl Default for Sort {
 
 crate::macros::gen_display!(Sort);
 
+/// Whether `NULL`s are sorted before or after the other values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Nulls {
+    First,
+    Last,
+}
+
+crate::macros::gen_display!(Nulls);
+
+/// An element of a `GROUP BY` clause.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
+pub enum Grouping<'a> {
+    Expr(Expr<'a>),
+    /// `GROUPING SETS ((a, b), (a), ())`
+    Sets(Vec<Vec<Expr<'a>>>),
+    /// `ROLLUP(a, b)`
+    Rollup(Vec<Expr<'a>>),
+    /// `CUBE(a, b)`
+    Cube(Vec<Expr<'a>>),
+}
+
+crate::macros::gen_display!(Grouping<'_>);
+
+impl<'a, E> std::convert::From<E> for Grouping<'a>
+where
+    E: Into<Expr<'a>>,
+{
+    #[inline]
+    fn from(val: E) -> Self {
+        Grouping::Expr(val.into())
+    }
+}
+
+/// The kind of a join between two tables.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum JoinKind {
+    Inner,
+    Left,
+    Right,
+    Full,
+}
+
+impl Default for JoinKind {
+    #[inline]
+    fn default() -> Self {
+        JoinKind::Inner
+    }
+}
+
+crate::macros::gen_display!(JoinKind);
+
+/// The condition of a join between two tables.
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct Order<'a>(pub(crate) Expr<'a>, pub(crate) Option<Sort>);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
+pub enum JoinCond<'a> {
+    On(Expr<'a>),
+    Using(Vec<Ident<'a>>),
+    Natural,
+}
+
+crate::macros::gen_display!(JoinCond<'_>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
+pub struct Order<'a>(
+    pub(crate) Expr<'a>,
+    pub(crate) Option<Sort>,
+    pub(crate) Option<Nulls>,
+);
 
 crate::macros::gen_display!(Order<'_>);
 
+impl<'a> Order<'a> {
+    /// Sort `NULL`s before the other values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::asc;
+    ///
+    /// assert_eq!(asc("id").nulls_first().to_string(), "id ASC NULLS FIRST");
+    /// ```
+    #[inline]
+    pub fn nulls_first(mut self) -> Order<'a> {
+        self.2 = Some(Nulls::First);
+        self
+    }
+
+    /// Sort `NULL`s after the other values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::desc;
+    ///
+    /// assert_eq!(desc("id").nulls_last().to_string(), "id DESC NULLS LAST");
+    /// ```
+    #[inline]
+    pub fn nulls_last(mut self) -> Order<'a> {
+        self.2 = Some(Nulls::Last);
+        self
+    }
+}
+
 impl<'a, E> std::convert::From<(E, Sort)> for Order<'a>
 where
     E: Into<Expr<'a>>,
 {
     #[inline]
     fn from(val: (E, Sort)) -> Self {
-        Order(val.0.into(), Some(val.1))
+        Order(val.0.into(), Some(val.1), None)
     }
 }
 
@@ -127,7 +528,7 @@ where
 {
     #[inline]
     fn from(val: (E, Option<Sort>)) -> Self {
-        Order(val.0.into(), val.1)
+        Order(val.0.into(), val.1, None)
     }
 }
 
@@ -137,19 +538,32 @@ where
 {
     #[inline]
     fn from(val: E) -> Self {
-        Order(val.into(), None)
+        Order(val.into(), None, None)
     }
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
-pub struct FuncCall<'a>(pub(crate) FuncRef<'a>, pub(crate) Vec<Expr<'a>>);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
+pub struct FuncCall<'a> {
+    pub(crate) func: FuncRef<'a>,
+    pub(crate) args: Vec<Expr<'a>>,
+    pub(crate) filter: Option<Box<crate::clause::Where<'a>>>,
+    pub(crate) distinct: bool,
+    pub(crate) orders: Option<crate::clause::OrderBy<'a>>,
+}
 
 crate::macros::gen_display!(FuncCall<'_>);
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 pub enum FuncRef<'a> {
     Func(Ident<'a>),
     SchemaFunc(Ident<'a>, Ident<'a>),
+    /// Built-in function, written as is since a quoted name such as
+    /// `"COUNT"` is not found by the database.
+    Builtin(Keyword),
 }
 
 crate::macros::gen_display!(FuncRef<'_>);
@@ -176,6 +590,8 @@ impl<'a> std::convert::From<(&'a str, &'a str)> for FuncRef<'a> {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 pub struct Field<'a> {
     pub expr: Expr<'a>,
     pub alias: Option<Ident<'a>>,
@@ -197,9 +613,12 @@ where
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 pub struct Table<'a> {
     pub table: TableExpr<'a>,
     pub alias: Option<Ident<'a>>,
+    pub sample: Option<TableSample<'a>>,
 }
 
 crate::macros::gen_display!(Table<'_>);
@@ -213,11 +632,159 @@ where
         Table {
             table: val.into(),
             alias: None,
+            sample: None,
         }
     }
 }
 
+impl<'a> Table<'a> {
+    /// Set the alias of the table, see also [`as_table`](crate::ops::as_table).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::item::Table;
+    ///
+    /// assert_eq!(Table::from("user").alias("u").to_string(), "user AS u");
+    /// ```
+    #[inline]
+    pub fn alias<A>(mut self, alias: A) -> Table<'a>
+    where
+        A: Into<Ident<'a>>,
+    {
+        self.alias = Some(alias.into());
+        self
+    }
+
+    /// Sample a percentage of the table with `TABLESAMPLE`.
+    ///
+    /// The sampling is rendered after the alias, as Postgres expects.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::as_table;
+    /// use qians_xql::item::SampleMethod;
+    /// use qians_xql::item::Table;
+    ///
+    /// assert_eq!(
+    ///     Table::from("event").tablesample(SampleMethod::Bernoulli, 10).to_string(),
+    ///     "event TABLESAMPLE BERNOULLI (10)",
+    /// );
+    /// assert_eq!(
+    ///     as_table("event", "e")
+    ///         .tablesample(SampleMethod::System, 1)
+    ///         .repeatable(42)
+    ///         .to_string(),
+    ///     "event AS e TABLESAMPLE SYSTEM (1) REPEATABLE (42)",
+    /// );
+    /// ```
+    #[inline]
+    pub fn tablesample<P>(mut self, method: SampleMethod, percent: P) -> Table<'a>
+    where
+        P: Into<Expr<'a>>,
+    {
+        self.sample = Some(TableSample(method, percent.into(), None));
+        self
+    }
+
+    /// Set the seed of `TABLESAMPLE` with `REPEATABLE`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table is not sampled with
+    /// [`tablesample`](Table::tablesample).
+    #[inline]
+    pub fn repeatable<S>(mut self, seed: S) -> Table<'a>
+    where
+        S: Into<Expr<'a>>,
+    {
+        let sample = self
+            .sample
+            .as_mut()
+            .expect("`Table::repeatable` requires `TABLESAMPLE`");
+        sample.2 = Some(seed.into());
+        self
+    }
+}
+
+/// A `TABLESAMPLE method (percent) [REPEATABLE (seed)]` of a table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
+pub struct TableSample<'a>(
+    pub(crate) SampleMethod,
+    pub(crate) Expr<'a>,
+    pub(crate) Option<Expr<'a>>,
+);
+
+crate::macros::gen_display!(TableSample<'_>);
+
+/// The sampling method of `TABLESAMPLE`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SampleMethod {
+    /// Pick each row with the given probability.
+    Bernoulli,
+    /// Pick each block of rows with the given probability.
+    System,
+}
+
+crate::macros::gen_display!(SampleMethod);
+
+/// A field of a date, time or interval, see [`extract`](crate::func::extract).
+///
+/// Both PostgreSQL and MySQL fields are listed, each database only accepting
+/// its own.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DateField {
+    // common, `MICROSECOND` being MySQL's and `MICROSECONDS` PostgreSQL's
+    Microsecond,
+    Microseconds,
+    Milliseconds,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+    Decade,
+    Century,
+    Millennium,
+
+    // PostgreSQL only
+    Dow,
+    Doy,
+    Epoch,
+    IsoDow,
+    IsoYear,
+    Julian,
+    Timezone,
+    TimezoneHour,
+    TimezoneMinute,
+
+    // MySQL only, spanning several units
+    SecondMicrosecond,
+    MinuteMicrosecond,
+    MinuteSecond,
+    HourMicrosecond,
+    HourSecond,
+    HourMinute,
+    DayMicrosecond,
+    DaySecond,
+    DayMinute,
+    DayHour,
+    YearMonth,
+}
+
+crate::macros::gen_display!(DateField);
+
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 #[repr(transparent)]
 pub struct Row<'a>(pub(crate) Vec<Expr<'a>>);
 
@@ -226,7 +793,30 @@ crate::macros::gen_impl_from_arr!(Row[Expr]<'a>);
 crate::macros::gen_impl_from_vec!(Row[Expr]<'a>);
 crate::macros::gen_impl_from_tup!(Row[Expr]<'a>);
 
+/// Collect a row out of expressions, for rows whose columns are only known at
+/// runtime.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::item::Row;
+///
+/// let row: Row = (1..=3).collect();
+/// assert_eq!(row.to_string(), "(1, 2, 3)");
+/// ```
+impl<'a, T> std::iter::FromIterator<T> for Row<'a>
+where
+    T: Into<Expr<'a>>,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Row(iter.into_iter().map(Into::into).collect())
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 pub struct Cte<'a> {
     pub(crate) name: Ident<'a>,
     pub(crate) columns: Vec<Ident<'a>>,
@@ -234,3 +824,359 @@ pub struct Cte<'a> {
 }
 
 crate::macros::gen_display!(Cte<'_>);
+
+impl<'a> Cte<'a> {
+    /// Construct a common table expression of a statement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    /// use qians_xql::item::Cte;
+    ///
+    /// let cte = Cte::new("adult", select(["id"]).from("user")).columns(["user_id"]);
+    /// assert_eq!(cte.to_string(), "adult(user_id) AS (SELECT id FROM user)");
+    /// ```
+    #[inline]
+    pub fn new<N, S>(name: N, stmt: S) -> Cte<'a>
+    where
+        N: Into<Ident<'a>>,
+        S: Into<crate::stmt::Stmt<'a>>,
+    {
+        Cte {
+            name: name.into(),
+            columns: Vec::new(),
+            stmt: stmt.into(),
+        }
+    }
+
+    /// Add more column(s) to the column list of the common table expression.
+    pub fn columns<C, I>(mut self, columns: I) -> Cte<'a>
+    where
+        C: Into<Ident<'a>>,
+        I: IntoIterator<Item = C>,
+    {
+        self.columns.extend(columns.into_iter().map(Into::into));
+        self
+    }
+}
+
+/// A window definition used by `OVER` clause.
+///
+/// A window made from a name refers to a window defined in `WINDOW` clause.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::item::Window;
+///
+/// let window = Window::default().partition_by(["dept"]).order_by(["salary"]);
+/// assert_eq!(window.to_string(), "(PARTITION BY dept ORDER BY salary)");
+/// assert_eq!(Window::default().to_string(), "()");
+/// assert_eq!(Window::from("w").to_string(), "w");
+/// assert_eq!(Window::from("w").order_by(["salary"]).to_string(), "(w ORDER BY salary)");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
+pub struct Window<'a> {
+    pub(crate) name: Option<Ident<'a>>,
+    pub(crate) partition: Vec<Expr<'a>>,
+    pub(crate) orders: Option<crate::clause::OrderBy<'a>>,
+    pub(crate) frame: Option<Frame<'a>>,
+}
+
+crate::macros::gen_display!(Window<'_>);
+
+impl<'a> std::convert::From<&'a str> for Window<'a> {
+    #[inline]
+    fn from(val: &'a str) -> Self {
+        Window {
+            name: Some(val.into()),
+            ..Default::default()
+        }
+    }
+}
+
+impl<'a> Window<'a> {
+    /// Check whether the window is only a reference to a named window.
+    pub(crate) fn is_ref(&self) -> bool {
+        self.name.is_some()
+            && self.partition.is_empty()
+            && self.orders.is_none()
+            && self.frame.is_none()
+    }
+
+    /// Add more expression(s) to `PARTITION BY` part of the window.
+    pub fn partition_by<E, I>(mut self, exprs: I) -> Window<'a>
+    where
+        E: Into<Expr<'a>>,
+        I: IntoIterator<Item = E>,
+    {
+        self.partition.extend(exprs.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add more order(s) to `ORDER BY` part of the window.
+    pub fn order_by<O>(mut self, orders: O) -> Window<'a>
+    where
+        O: Into<crate::clause::OrderBy<'a>>,
+    {
+        self.orders = match self.orders.take() {
+            Some(mut inner) => {
+                inner.0.extend(orders.into().0);
+                Some(inner)
+            }
+            None => Some(orders.into()),
+        };
+        self
+    }
+
+    /// Set the frame of the window to `ROWS start`.
+    #[inline]
+    pub fn rows(mut self, start: FrameBound<'a>) -> Window<'a> {
+        self.frame = Some(Frame("ROWS".into(), start, None));
+        self
+    }
+
+    /// Set the frame of the window to `ROWS BETWEEN start AND end`.
+    #[inline]
+    pub fn rows_between(mut self, start: FrameBound<'a>, end: FrameBound<'a>) -> Window<'a> {
+        self.frame = Some(Frame("ROWS".into(), start, Some(end)));
+        self
+    }
+
+    /// Set the frame of the window to `RANGE start`.
+    #[inline]
+    pub fn range(mut self, start: FrameBound<'a>) -> Window<'a> {
+        self.frame = Some(Frame("RANGE".into(), start, None));
+        self
+    }
+
+    /// Set the frame of the window to `RANGE BETWEEN start AND end`.
+    #[inline]
+    pub fn range_between(mut self, start: FrameBound<'a>, end: FrameBound<'a>) -> Window<'a> {
+        self.frame = Some(Frame("RANGE".into(), start, Some(end)));
+        self
+    }
+
+    /// Set the frame of the window to `GROUPS start`.
+    #[inline]
+    pub fn groups(mut self, start: FrameBound<'a>) -> Window<'a> {
+        self.frame = Some(Frame("GROUPS".into(), start, None));
+        self
+    }
+
+    /// Set the frame of the window to `GROUPS BETWEEN start AND end`.
+    #[inline]
+    pub fn groups_between(mut self, start: FrameBound<'a>, end: FrameBound<'a>) -> Window<'a> {
+        self.frame = Some(Frame("GROUPS".into(), start, Some(end)));
+        self
+    }
+}
+
+/// The frame of a window.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
+pub struct Frame<'a>(
+    pub(crate) Keyword,
+    pub(crate) FrameBound<'a>,
+    pub(crate) Option<FrameBound<'a>>,
+);
+
+crate::macros::gen_display!(Frame<'_>);
+
+/// A start or an end of a window frame.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::item::FrameBound;
+/// use qians_xql::item::Window;
+///
+/// let window = Window::default()
+///     .order_by(["day"])
+///     .rows_between(FrameBound::UnboundedPreceding, FrameBound::CurrentRow);
+/// assert_eq!(
+///     window.to_string(),
+///     "(ORDER BY day ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW)",
+/// );
+///
+/// let window = Window::default().rows(FrameBound::Preceding(5.into()));
+/// assert_eq!(window.to_string(), "(ROWS 5 PRECEDING)");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
+pub enum FrameBound<'a> {
+    UnboundedPreceding,
+    Preceding(Expr<'a>),
+    CurrentRow,
+    Following(Expr<'a>),
+    UnboundedFollowing,
+}
+
+crate::macros::gen_display!(FrameBound<'_>);
+
+impl<'a> FuncCall<'a> {
+    /// Turn the function call into a window function call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::func;
+    /// use qians_xql::item::Window;
+    ///
+    /// assert_eq!(
+    ///     func("row_number", Vec::<i32>::new())
+    ///         .over(Window::default().order_by(["id"]))
+    ///         .to_string(),
+    ///     "row_number() OVER (ORDER BY id)",
+    /// );
+    /// ```
+    #[inline]
+    pub fn over<W>(self, window: W) -> Expr<'a>
+    where
+        W: Into<Window<'a>>,
+    {
+        crate::ops::over(self, window)
+    }
+
+    /// Make an alias out of the function call, see also
+    /// [`as_field`](crate::ops::as_field).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::count;
+    /// use qians_xql::select;
+    /// use qians_xql::sum;
+    ///
+    /// let query = select([count("*").alias("orders"), sum("amount").alias("total")])
+    ///     .select(["user_id"])
+    ///     .from("order")
+    ///     .group_by("user_id");
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "SELECT COUNT(*) AS orders, SUM(amount) AS total, user_id FROM order GROUP BY user_id",
+    /// );
+    /// ```
+    #[inline]
+    pub fn alias<A>(self, alias: A) -> Field<'a>
+    where
+        A: Into<Ident<'a>>,
+    {
+        crate::ops::as_field(self, alias)
+    }
+
+    /// Apply an aggregate function to `DISTINCT` values of its arguments only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::count;
+    ///
+    /// assert_eq!(
+    ///     count("user_id").distinct().to_string(),
+    ///     "COUNT(DISTINCT user_id)",
+    /// );
+    /// ```
+    #[inline]
+    pub fn distinct(mut self) -> FuncCall<'a> {
+        self.distinct = true;
+        self
+    }
+
+    /// Add more order(s) to `ORDER BY` inside of an aggregate function call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::func;
+    /// use qians_xql::desc;
+    ///
+    /// assert_eq!(
+    ///     func("array_agg", ["name"]).order_by([desc("age")]).to_string(),
+    ///     "array_agg(name ORDER BY age DESC)",
+    /// );
+    /// ```
+    pub fn order_by<O>(mut self, orders: O) -> FuncCall<'a>
+    where
+        O: Into<crate::clause::OrderBy<'a>>,
+    {
+        self.orders = match self.orders.take() {
+            Some(mut inner) => {
+                inner.0.extend(orders.into().0);
+                Some(inner)
+            }
+            None => Some(orders.into()),
+        };
+        self
+    }
+
+    /// Set condition to `FILTER (WHERE ...)` of an aggregate function call.
+    ///
+    /// Successive calls combine new condition with previous condition with
+    /// [`and`](crate::ops::and).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::count;
+    /// use qians_xql::eq;
+    ///
+    /// assert_eq!(
+    ///     count("*").filter(eq("status", 1)).to_string(),
+    ///     "COUNT(*) FILTER (WHERE status = 1)",
+    /// );
+    /// ```
+    pub fn filter<E>(mut self, expr: E) -> FuncCall<'a>
+    where
+        E: Into<Expr<'a>>,
+    {
+        self.filter = match self.filter.take() {
+            Some(inner) => Some(Box::new(crate::ops::and(inner.0, expr.into()).into())),
+            None => Some(Box::new(expr.into().into())),
+        };
+        self
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn checked_ident() {
+    assert!(RESERVED.windows(2).all(|w| w[0] < w[1]));
+
+    for val in ["id", "user_id", "_tmp", "Book2", "users", "selection"] {
+        assert_eq!(Ident::checked(val), Ok(Ident::from(val)));
+    }
+    assert_eq!(Ident::checked(""), Err("is empty"));
+    assert_eq!(Ident::checked("2fa"), Err("starts with a digit"));
+    for val in ["a-b", "a b", "a.b", "a\"b", "name'--", "naïve"] {
+        assert_eq!(
+            Ident::checked(val),
+            Err("contains characters other than letters, digits and underscores")
+        );
+    }
+    for val in ["user", "Select", "ORDER", "limit"] {
+        assert_eq!(Ident::checked(val), Err("is a reserved word"));
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn ident_from_column() {
+    assert_eq!(
+        Ident::try_from(ColumnRef::from(("user", "name"))),
+        Ok(Ident::from("name"))
+    );
+    assert_eq!(
+        Ident::try_from(ColumnRef::TableStar("user".into())),
+        Err("is not a column name")
+    );
+    assert_eq!(
+        Ident::try_from(ColumnRef::Excluded("name".into())),
+        Err("is a column of `EXCLUDED`")
+    );
+}
@@ -4,6 +4,7 @@
 
 pub mod blanket;
 pub mod clause;
+pub mod dialect;
 pub mod expr;
 pub mod func;
 pub mod item;
@@ -17,31 +18,61 @@ pub mod schema;
 
 mod build;
 mod macros;
+mod owned;
 
 // re exports statement builder
 pub use stmt::delete;
+pub use stmt::explain;
+pub use stmt::explain_analyze;
 pub use stmt::insert;
+pub use stmt::insert_into;
 pub use stmt::select;
+pub use stmt::select_distinct;
 pub use stmt::update;
 pub use stmt::values;
 pub use stmt::{except, except_all, intersect, intersect_all, union, union_all};
 
 // re exports functions
 pub use func::func;
-pub use func::{avg, count, max, min, sum};
+pub use func::{avg, count, count_all, max, min, sum};
+pub use func::{coalesce, greatest, least, nullif};
+pub use func::string_agg;
+pub use func::{abs, ceil, floor, round};
+pub use func::{length, lower, trim, upper};
+pub use func::{plainto_tsquery, to_tsquery, to_tsvector};
+pub use func::now;
+pub use func::{current_date, current_timestamp, date_trunc, extract};
+pub use func::{dense_rank, lag, lead, ntile, rank, row_number};
 
 // re exports ops
-pub use ops::{add, div, mul, rem, sub};
+pub use ops::{add, div, modulo, mul, rem, sub};
 pub use ops::{and, not, or};
-pub use ops::{as_field, as_table, asc, desc, paren};
+pub use ops::{as_field, as_table, asc, cast, desc, lateral, over, paren};
+pub use ops::values_table;
+pub use ops::ordinal;
+pub use ops::interval;
+pub use ops::{star, star_of};
+pub use ops::{raw, table_raw};
+pub use ops::{between, in_, not_between, not_in};
 pub use ops::{binop, postop, preop};
 pub use ops::{eq, ge, gt, le, lt, ne};
-pub use ops::{ilike, isnull, like};
+pub use ops::{is_distinct_from, is_not_distinct_from};
+pub use ops::{is_not_null, is_null};
+pub use ops::{ilike, isnull, like, like_escape, not_ilike, not_like};
+pub use ops::{ilike_escape, not_ilike_escape, not_like_escape};
+pub use ops::matches;
+pub use ops::{not_regex_imatch, not_regex_match, regex_imatch, regex_match};
+pub use ops::{all, any, eq_any};
+pub use ops::{array, array_typed, index, slice};
+pub use ops::{contained_by, contains, overlaps};
+pub use ops::{json_get, json_get_text, json_path, json_path_text};
+pub use ops::{exists, in_subquery, not_exists, not_in_subquery};
 
-pub use ops::{cross_join, join, natural_join};
+pub use ops::{cross_join, join, join_using, natural_join};
+pub use ops::{cross_apply, outer_apply};
 pub use ops::{full_join, left_join, right_join};
 pub use ops::{natural_full_join, natural_left_join, natural_right_join};
 
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
-pub use schema::Schema;
+pub use schema::{Columns, Schema};
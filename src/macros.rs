@@ -127,6 +127,99 @@ macro_rules! gen_impl_from_tup {
                 (8) -> I
                 (9) -> J
             }
+            Tuple11 {
+                (0) -> A
+                (1) -> B
+                (2) -> C
+                (3) -> D
+                (4) -> E
+                (5) -> F
+                (6) -> G
+                (7) -> H
+                (8) -> I
+                (9) -> J
+                (10) -> K
+            }
+            Tuple12 {
+                (0) -> A
+                (1) -> B
+                (2) -> C
+                (3) -> D
+                (4) -> E
+                (5) -> F
+                (6) -> G
+                (7) -> H
+                (8) -> I
+                (9) -> J
+                (10) -> K
+                (11) -> L
+            }
+            Tuple13 {
+                (0) -> A
+                (1) -> B
+                (2) -> C
+                (3) -> D
+                (4) -> E
+                (5) -> F
+                (6) -> G
+                (7) -> H
+                (8) -> I
+                (9) -> J
+                (10) -> K
+                (11) -> L
+                (12) -> M
+            }
+            Tuple14 {
+                (0) -> A
+                (1) -> B
+                (2) -> C
+                (3) -> D
+                (4) -> E
+                (5) -> F
+                (6) -> G
+                (7) -> H
+                (8) -> I
+                (9) -> J
+                (10) -> K
+                (11) -> L
+                (12) -> M
+                (13) -> N
+            }
+            Tuple15 {
+                (0) -> A
+                (1) -> B
+                (2) -> C
+                (3) -> D
+                (4) -> E
+                (5) -> F
+                (6) -> G
+                (7) -> H
+                (8) -> I
+                (9) -> J
+                (10) -> K
+                (11) -> L
+                (12) -> M
+                (13) -> N
+                (14) -> O
+            }
+            Tuple16 {
+                (0) -> A
+                (1) -> B
+                (2) -> C
+                (3) -> D
+                (4) -> E
+                (5) -> F
+                (6) -> G
+                (7) -> H
+                (8) -> I
+                (9) -> J
+                (10) -> K
+                (11) -> L
+                (12) -> M
+                (13) -> N
+                (14) -> O
+                (15) -> P
+            }
         });
     };
     ($type:ident[$elem:ty] {$(
@@ -223,6 +316,99 @@ macro_rules! gen_impl_from_tup {
                 (8) -> I
                 (9) -> J
             }
+            Tuple11 {
+                (0) -> A
+                (1) -> B
+                (2) -> C
+                (3) -> D
+                (4) -> E
+                (5) -> F
+                (6) -> G
+                (7) -> H
+                (8) -> I
+                (9) -> J
+                (10) -> K
+            }
+            Tuple12 {
+                (0) -> A
+                (1) -> B
+                (2) -> C
+                (3) -> D
+                (4) -> E
+                (5) -> F
+                (6) -> G
+                (7) -> H
+                (8) -> I
+                (9) -> J
+                (10) -> K
+                (11) -> L
+            }
+            Tuple13 {
+                (0) -> A
+                (1) -> B
+                (2) -> C
+                (3) -> D
+                (4) -> E
+                (5) -> F
+                (6) -> G
+                (7) -> H
+                (8) -> I
+                (9) -> J
+                (10) -> K
+                (11) -> L
+                (12) -> M
+            }
+            Tuple14 {
+                (0) -> A
+                (1) -> B
+                (2) -> C
+                (3) -> D
+                (4) -> E
+                (5) -> F
+                (6) -> G
+                (7) -> H
+                (8) -> I
+                (9) -> J
+                (10) -> K
+                (11) -> L
+                (12) -> M
+                (13) -> N
+            }
+            Tuple15 {
+                (0) -> A
+                (1) -> B
+                (2) -> C
+                (3) -> D
+                (4) -> E
+                (5) -> F
+                (6) -> G
+                (7) -> H
+                (8) -> I
+                (9) -> J
+                (10) -> K
+                (11) -> L
+                (12) -> M
+                (13) -> N
+                (14) -> O
+            }
+            Tuple16 {
+                (0) -> A
+                (1) -> B
+                (2) -> C
+                (3) -> D
+                (4) -> E
+                (5) -> F
+                (6) -> G
+                (7) -> H
+                (8) -> I
+                (9) -> J
+                (10) -> K
+                (11) -> L
+                (12) -> M
+                (13) -> N
+                (14) -> O
+                (15) -> P
+            }
         });
     };
     ($type:ident[$elem:ident]<$lf:lifetime> {$(
@@ -248,14 +434,158 @@ macro_rules! gen_display {
     ($type:ty) => {
         impl ::std::fmt::Display for $type {
             fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                let (sql, ..) =
-                    $crate::build::ToSql::to_sql::<$crate::build::Display>(self.clone());
-                write!(f, "{sql}")
+                let mut sql = String::new();
+                if f.alternate() {
+                    $crate::build::ToSql::build::<$crate::build::Pretty>(
+                        self,
+                        &mut sql,
+                        &mut Vec::new(),
+                    );
+                } else {
+                    $crate::build::ToSql::build::<$crate::build::Display>(
+                        self,
+                        &mut sql,
+                        &mut Vec::new(),
+                    );
+                }
+                f.write_str(&sql)
             }
         }
     };
 }
 
+/// Construct a `SELECT` statement with an SQL like syntax.
+///
+/// The fields and the `FROM`, `GROUP BY` and `ORDER BY` sections are comma
+/// separated lists, where a bare (`name`) or qualified (`user.name`)
+/// identifier is a column and anything else is a Rust expression. The `WHERE`
+/// section is a single Rust expression. Items of `ORDER BY` may be followed by
+/// `ASC` or `DESC`.
+///
+/// Wrap a variable in parentheses to use its value instead of a column of the
+/// same name.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::func::count;
+/// use qians_xql::ops::{as_field, gt};
+/// use qians_xql::select;
+///
+/// let min = 1000;
+/// let query = select!(
+///     dept, as_field(count("id"), "total")
+///     FROM employee
+///     WHERE gt("salary", min)
+///     GROUP BY dept
+///     ORDER BY total DESC, dept
+/// );
+/// assert_eq!(
+///     query.to_string(),
+///     "SELECT dept, COUNT(id) AS total FROM employee WHERE salary > 1000 \
+///     GROUP BY dept ORDER BY total DESC, dept",
+/// );
+/// ```
+#[macro_export]
+macro_rules! select {
+    // a single item of a list
+    (@expr $name:ident) => { ::core::stringify!($name) };
+    (@expr $table:ident . $column:ident) => {
+        (::core::stringify!($table), ::core::stringify!($column))
+    };
+    (@expr $($expr:tt)+) => { ($($expr)+) };
+
+    // call the builder method of a finished list
+    (@apply fields ($($query:tt)*) [$([$($item:tt)+])*]) => {
+        $($query)*.select([$(
+            <$crate::item::Field as ::core::convert::From<_>>::from($crate::select!(@expr $($item)+)),
+        )*])
+    };
+    (@apply from ($($query:tt)*) [$([$($item:tt)+])*]) => {
+        $($query)*.from([$(
+            <$crate::item::Table as ::core::convert::From<_>>::from($crate::select!(@expr $($item)+)),
+        )*])
+    };
+    (@apply group ($($query:tt)*) [$([$($item:tt)+])*]) => {
+        $($query)*.group_by([$(
+            <$crate::expr::Expr as ::core::convert::From<_>>::from($crate::select!(@expr $($item)+)),
+        )*])
+    };
+    (@apply order ($($query:tt)*) [$([$($item:tt)+])*]) => {
+        $($query)*.order_by([$(
+            <$crate::item::Order as ::core::convert::From<_>>::from($crate::select!(@expr $($item)+)),
+        )*])
+    };
+
+    // start the next section
+    (@clause ($($query:tt)*) FROM $($rest:tt)+) => {
+        $crate::select!(@list from ($($query)*) [] [] $($rest)+)
+    };
+    (@clause ($($query:tt)*) WHERE $($rest:tt)+) => {
+        $crate::select!(@where ($($query)*) [] $($rest)+)
+    };
+    (@clause ($($query:tt)*) GROUP BY $($rest:tt)+) => {
+        $crate::select!(@list group ($($query)*) [] [] $($rest)+)
+    };
+    (@clause ($($query:tt)*) ORDER BY $($rest:tt)+) => {
+        $crate::select!(@list order ($($query)*) [] [] $($rest)+)
+    };
+    (@clause ($($query:tt)*)) => { $($query)* };
+
+    // munch a list until the next section
+    (@list order $query:tt [$($items:tt)*] [$($item:tt)+] ASC $($rest:tt)*) => {
+        $crate::select!(@list order $query [$($items)*] [
+            $crate::ops::asc($crate::select!(@expr $($item)+))
+        ] $($rest)*)
+    };
+    (@list order $query:tt [$($items:tt)*] [$($item:tt)+] DESC $($rest:tt)*) => {
+        $crate::select!(@list order $query [$($items)*] [
+            $crate::ops::desc($crate::select!(@expr $($item)+))
+        ] $($rest)*)
+    };
+    (@list $kind:ident $query:tt [$($items:tt)*] [$($item:tt)+] , $($rest:tt)+) => {
+        $crate::select!(@list $kind $query [$($items)* [$($item)+]] [] $($rest)+)
+    };
+    (@list $kind:ident $query:tt [$($items:tt)*] [$($item:tt)+] FROM $($rest:tt)+) => {
+        $crate::select!(@clause ($crate::select!(@apply $kind $query [$($items)* [$($item)+]])) FROM $($rest)+)
+    };
+    (@list $kind:ident $query:tt [$($items:tt)*] [$($item:tt)+] WHERE $($rest:tt)+) => {
+        $crate::select!(@clause ($crate::select!(@apply $kind $query [$($items)* [$($item)+]])) WHERE $($rest)+)
+    };
+    (@list $kind:ident $query:tt [$($items:tt)*] [$($item:tt)+] GROUP BY $($rest:tt)+) => {
+        $crate::select!(@clause ($crate::select!(@apply $kind $query [$($items)* [$($item)+]])) GROUP BY $($rest)+)
+    };
+    (@list $kind:ident $query:tt [$($items:tt)*] [$($item:tt)+] ORDER BY $($rest:tt)+) => {
+        $crate::select!(@clause ($crate::select!(@apply $kind $query [$($items)* [$($item)+]])) ORDER BY $($rest)+)
+    };
+    (@list $kind:ident $query:tt [$($items:tt)*] [$($item:tt)+]) => {
+        $crate::select!(@apply $kind $query [$($items)* [$($item)+]])
+    };
+    (@list $kind:ident $query:tt [$($items:tt)*] [$($item:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::select!(@list $kind $query [$($items)*] [$($item)* $next] $($rest)*)
+    };
+
+    // munch the condition until the next section
+    (@where ($($query:tt)*) [$($cond:tt)+] GROUP BY $($rest:tt)+) => {
+        $crate::select!(@clause ($($query)*.filter($($cond)+)) GROUP BY $($rest)+)
+    };
+    (@where ($($query:tt)*) [$($cond:tt)+] ORDER BY $($rest:tt)+) => {
+        $crate::select!(@clause ($($query)*.filter($($cond)+)) ORDER BY $($rest)+)
+    };
+    (@where ($($query:tt)*) [$($cond:tt)+]) => {
+        $($query)*.filter($($cond)+)
+    };
+    (@where $query:tt [$($cond:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::select!(@where $query [$($cond)* $next] $($rest)*)
+    };
+
+    ($($tokens:tt)+) => {
+        $crate::select!(@list fields (
+            <$crate::stmt::select::Select as ::core::default::Default>::default()
+        ) [] [] $($tokens)+)
+    };
+}
+
 pub(crate) use gen_display;
 pub(crate) use gen_impl_from_arr;
 pub(crate) use gen_impl_from_tup;
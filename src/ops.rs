@@ -1,10 +1,18 @@
 use crate::expr::Expr;
+use crate::item::ColumnRef;
 use crate::item::Field;
 use crate::item::Ident;
+use crate::item::JoinCond;
+use crate::item::JoinKind;
+use crate::item::JsonKey;
 use crate::item::Order;
 use crate::item::Sort;
 use crate::item::Table;
+use crate::item::TypeName;
+use crate::item::Window;
+use crate::stmt::data::Data;
 use crate::table_expr::TableExpr;
+use crate::value::Value;
 
 /// Make an alias out of an expression.
 ///
@@ -45,295 +53,1358 @@ where
     Table {
         table: table.into(),
         alias: Some(alias.into()),
+        sample: None,
     }
 }
 
+/// Mark a subquery or a function call in `FROM` clause as `LATERAL`.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::func;
+/// use qians_xql::lateral;
+///
+/// assert_eq!(
+///     lateral(func("unnest", [("book", "tags")])).to_string(),
+///     "LATERAL unnest(book.tags)",
+/// );
+/// ```
+#[inline]
+pub fn lateral<'a, T>(table: T) -> TableExpr<'a>
+where
+    T: Into<TableExpr<'a>>,
+{
+    TableExpr::Lateral(Box::new(table.into()))
+}
+
+/// Use rows of `VALUES` as a table in `FROM` clause, under an alias and
+/// optionally named columns.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::values_table;
+///
+/// let (a, b) = (&"a".to_string(), &"b".to_string());
+/// assert_eq!(
+///     values_table([(1, a), (2, b)], "t", ["id", "name"]).to_string(),
+///     "(VALUES (1, 'a'), (2, 'b')) AS t(id, name)",
+/// );
+/// ```
+///
+/// # Panics
+///
+/// Panics if the alias is empty, as Postgres requires one.
+pub fn values_table<'a, V, A, C, I>(rows: V, alias: A, columns: I) -> Table<'a>
+where
+    V: Into<crate::clause::Values<'a>>,
+    A: Into<Ident<'a>>,
+    C: Into<Ident<'a>>,
+    I: IntoIterator<Item = C>,
+{
+    let alias = alias.into();
+    assert!(!alias.0.is_empty(), "`values_table` requires an alias");
+    as_table(
+        TableExpr::Values(rows.into(), columns.into_iter().map(Into::into).collect()),
+        alias,
+    )
+}
+
+/// Write a piece of SQL verbatim, for constructs that can't be built otherwise.
+///
+/// # Security
+///
+/// The SQL is neither escaped nor quoted. Never build it out of user input,
+/// which is an SQL injection. Pass values as separate expressions instead, so
+/// they can be bound as parameters.
+///
+/// The SQL is taken as a single operand, so parenthesize it yourself when it
+/// contains operators that bind looser than the surrounding ones.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::gt;
+/// use qians_xql::raw;
+/// use qians_xql::select;
+///
+/// let query = select(("id", raw("pg_column_size(payload)")))
+///     .from("event")
+///     .filter(gt("created", raw("now() - interval '1 day'")));
+/// assert_eq!(
+///     query.to_string(),
+///     "SELECT id, pg_column_size(payload) FROM event WHERE created > now() - interval '1 day'",
+/// );
+/// ```
+#[inline]
+pub fn raw<'a, S>(sql: S) -> Expr<'a>
+where
+    S: Into<std::borrow::Cow<'a, str>>,
+{
+    Expr::Raw(sql.into())
+}
+
+/// Write a table source of `FROM` clause verbatim, for sources that can't be
+/// built otherwise. It can still take an alias with [`as_table`].
+///
+/// # Security
+///
+/// The SQL is neither escaped nor quoted. Never build it out of user input,
+/// which is an SQL injection.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::as_table;
+/// use qians_xql::select;
+/// use qians_xql::table_raw;
+///
+/// let query = select(["n"]).from(as_table(table_raw("generate_series(1, 3)"), "n"));
+/// assert_eq!(query.to_string(), "SELECT n FROM generate_series(1, 3) AS n");
+/// ```
+#[inline]
+pub fn table_raw<'a, S>(sql: S) -> TableExpr<'a>
+where
+    S: Into<std::borrow::Cow<'a, str>>,
+{
+    TableExpr::Raw(sql.into())
+}
+
+/// Refer to a selected field by its 1-based position, in `ORDER BY` or
+/// `GROUP BY`.
+///
+/// Unlike an integer literal, the position is never bound as a parameter, so
+/// it keeps its meaning when the statement is rendered with placeholders.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::desc;
+/// use qians_xql::ordinal;
+/// use qians_xql::select;
+/// use qians_xql::dialect::Postgres;
+///
+/// let query = select(["name", "year"])
+///     .from("book")
+///     .order_by([desc(ordinal(2)), ordinal(1).into()]);
+/// assert_eq!(query.to_string(), "SELECT name, year FROM book ORDER BY 2 DESC, 1");
+///
+/// let (sql, args) = query.to_sql(Postgres);
+/// assert_eq!(sql, r#"SELECT "name", "year" FROM "book" ORDER BY 2 DESC, 1"#);
+/// assert!(args.is_empty());
+/// ```
+///
+/// # Panics
+///
+/// Panics if the position is 0.
+#[inline]
+pub fn ordinal<'a>(pos: u32) -> Expr<'a> {
+    assert!(pos > 0, "ordinal position starts from 1");
+    Expr::Ordinal(pos)
+}
+
+/// Construct an `INTERVAL` literal, e.g. for date arithmetic.
+///
+/// The interval is always written as a quoted string literal, since it can't
+/// be bound as a parameter.
+///
+/// The literal is written as `INTERVAL '7 days'` whatever the dialect, which
+/// is the PostgreSQL and standard SQL syntax. MySQL expects `INTERVAL 7 DAY`
+/// instead, and neither SQLite nor SQL Server has interval literals, so use
+/// [`raw`] or their date functions there.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::gt;
+/// use qians_xql::interval;
+/// use qians_xql::now;
+/// use qians_xql::sub;
+///
+/// assert_eq!(
+///     gt("created_at", sub(now(), interval("7 days"))).to_string(),
+///     "created_at > NOW() - INTERVAL '7 days'",
+/// );
+/// ```
+#[inline]
+pub fn interval(val: &str) -> Expr<'_> {
+    Expr::Interval(val.into())
+}
+
+/// Construct `*` selecting all columns, it's never quoted as an identifier.
+///
+/// See [`star_of`] for all columns of a single table.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::now;
+/// use qians_xql::select;
+/// use qians_xql::star;
+/// use qians_xql::dialect::Postgres;
+///
+/// let query = select([star()]).from("book");
+/// assert_eq!(query.to_string(), "SELECT * FROM book");
+///
+/// let (sql, _) = query.to_sql(Postgres);
+/// assert_eq!(sql, r#"SELECT * FROM "book""#);
+///
+/// let query = select((star(), now())).from("book");
+/// assert_eq!(query.to_string(), "SELECT *, NOW() FROM book");
+/// ```
+#[inline]
+pub fn star<'a>() -> Expr<'a> {
+    Expr::Column(ColumnRef::Star)
+}
+
+/// Construct `table.*` selecting all columns of a table, only the table name
+/// is quoted as an identifier.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::eq;
+/// use qians_xql::select;
+/// use qians_xql::star_of;
+/// use qians_xql::dialect::Postgres;
+///
+/// let query = select((star_of("user"), ("order", "total")))
+///     .from("user")
+///     .join("order", eq(("order", "user_id"), ("user", "id")));
+/// assert_eq!(
+///     query.to_string(),
+///     "SELECT user.*, order.total FROM user JOIN order ON order.user_id = user.id",
+/// );
+///
+/// let (sql, _) = query.to_sql(Postgres);
+/// assert_eq!(
+///     sql,
+///     r#"SELECT "user".*, "order"."total" FROM "user" JOIN "order" ON "order"."user_id" = "user"."id""#,
+/// );
+/// ```
+#[inline]
+pub fn star_of<'a, T>(table: T) -> Expr<'a>
+where
+    T: Into<Ident<'a>>,
+{
+    Expr::Column(ColumnRef::TableStar(table.into()))
+}
+
+/// Call a window function over a window.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::over;
+/// use qians_xql::sum;
+/// use qians_xql::item::Window;
+///
+/// assert_eq!(
+///     over(sum("salary"), Window::default().partition_by(["dept"])).to_string(),
+///     "SUM(salary) OVER (PARTITION BY dept)",
+/// );
+/// ```
+#[inline]
+pub fn over<'a, E, W>(expr: E, window: W) -> Expr<'a>
+where
+    E: Into<Expr<'a>>,
+    W: Into<Window<'a>>,
+{
+    Expr::Window(Box::new(expr.into()), Box::new(window.into()))
+}
+
+/// Cast an expression to another type.
+///
+/// The type name is written as is, so it is checked when converted into a
+/// [`TypeName`].
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::cast;
+/// use qians_xql::item::TypeName;
+///
+/// let text = TypeName::try_from("text").unwrap();
+/// assert_eq!(cast("id", text).to_string(), "CAST(id AS text)");
+///
+/// let varchar = TypeName::try_from("varchar(16)").unwrap();
+/// assert_eq!(cast(1, varchar).to_string(), "CAST(1 AS varchar(16))");
+/// ```
+#[inline]
+pub fn cast<'a, E, T>(expr: E, ty: T) -> Expr<'a>
+where
+    E: Into<Expr<'a>>,
+    T: Into<TypeName<'a>>,
+{
+    Expr::Cast(Box::new(expr.into()), ty.into())
+}
+
+/// Construct an array out of expressions.
+///
+/// An empty array has no element type to be inferred from, Postgres needs it
+/// to be casted explicitly, see [`array_typed`].
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::array;
+///
+/// assert_eq!(array([1, 2, 3]).to_string(), "ARRAY[1, 2, 3]");
+/// ```
+#[inline]
+pub fn array<'a, A, I>(items: I) -> Expr<'a>
+where
+    A: Into<Expr<'a>>,
+    I: IntoIterator<Item = A>,
+{
+    Expr::Array(items.into_iter().map(Into::into).collect())
+}
+
+/// Construct an array out of expressions and cast it to the given type.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::array_typed;
+/// use qians_xql::item::TypeName;
+///
+/// let bigints = TypeName::try_from("bigint[]").unwrap();
+/// assert_eq!(array_typed([1, 2], bigints).to_string(), "CAST(ARRAY[1, 2] AS bigint[])");
+///
+/// let ints = TypeName::try_from("int[]").unwrap();
+/// assert_eq!(array_typed(Vec::<i32>::new(), ints).to_string(), "CAST(ARRAY[] AS int[])");
+/// ```
+#[inline]
+pub fn array_typed<'a, A, I, T>(items: I, ty: T) -> Expr<'a>
+where
+    A: Into<Expr<'a>>,
+    I: IntoIterator<Item = A>,
+    T: Into<TypeName<'a>>,
+{
+    cast(array(items), ty)
+}
+
+/// Access an element of an array.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::index;
+///
+/// assert_eq!(index("tags", 1).to_string(), "tags[1]");
+/// ```
+#[inline]
+pub fn index<'a, E, I>(expr: E, idx: I) -> Expr<'a>
+where
+    E: Into<Expr<'a>>,
+    I: Into<Expr<'a>>,
+{
+    Expr::Subscript(Box::new(expr.into()), Box::new(idx.into()), None)
+}
+
+/// Access a slice of an array.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::slice;
+///
+/// assert_eq!(slice("tags", 1, 3).to_string(), "tags[1:3]");
+/// ```
+#[inline]
+pub fn slice<'a, E, F, T>(expr: E, from: F, to: T) -> Expr<'a>
+where
+    E: Into<Expr<'a>>,
+    F: Into<Expr<'a>>,
+    T: Into<Expr<'a>>,
+{
+    Expr::Subscript(
+        Box::new(expr.into()),
+        Box::new(from.into()),
+        Some(Box::new(to.into())),
+    )
+}
+
+/// Get a JSON object field by key, or a JSON array element by index.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::json_get;
+///
+/// assert_eq!(json_get("data", "user").to_string(), "data -> 'user'");
+/// assert_eq!(json_get("data", 0).to_string(), "data -> 0");
+/// ```
+#[inline]
+pub fn json_get<'a, E, K>(expr: E, key: K) -> Expr<'a>
+where
+    E: Into<Expr<'a>>,
+    K: Into<JsonKey<'a>>,
+{
+    binop(expr, "->", key.into())
+}
+
+/// Get a JSON object field by key, or a JSON array element by index, as text.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::json_get_text;
+///
+/// assert_eq!(json_get_text("data", "name").to_string(), "data ->> 'name'");
+/// ```
+#[inline]
+pub fn json_get_text<'a, E, K>(expr: E, key: K) -> Expr<'a>
+where
+    E: Into<Expr<'a>>,
+    K: Into<JsonKey<'a>>,
+{
+    binop(expr, "->>", key.into())
+}
+
+/// Get a JSON object at the specified path.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::json_path;
+///
+/// assert_eq!(json_path("data", ["a", "b"]).to_string(), "data #> ARRAY['a', 'b']");
+/// ```
+#[inline]
+pub fn json_path<'a, E, I>(expr: E, path: I) -> Expr<'a>
+where
+    E: Into<Expr<'a>>,
+    I: IntoIterator<Item = &'a str>,
+{
+    binop(expr, "#>", array(path.into_iter().map(|key| Value::Text(key.into()))))
+}
+
+/// Get a JSON object at the specified path as text.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::json_path_text;
+///
+/// assert_eq!(json_path_text("data", ["a", "b"]).to_string(), "data #>> ARRAY['a', 'b']");
+/// ```
+#[inline]
+pub fn json_path_text<'a, E, I>(expr: E, path: I) -> Expr<'a>
+where
+    E: Into<Expr<'a>>,
+    I: IntoIterator<Item = &'a str>,
+{
+    binop(expr, "#>>", array(path.into_iter().map(|key| Value::Text(key.into()))))
+}
+
 /// Group an expression with parenthesis.
 ///
 /// # Examples
 ///
 /// ```
-/// use qians_xql::{paren, not, and};
+/// use qians_xql::{paren, not, and};
+///
+/// assert_eq!(not(paren(and(true, false))).to_string(), "NOT (true AND false)");
+/// ```
+#[inline]
+pub fn paren<'a, E>(expr: E) -> Expr<'a>
+where
+    E: Into<Expr<'a>>,
+{
+    Expr::Paren(Box::new(expr.into()))
+}
+
+/// Combine two conditions with `OR`, wrapping either of them that is an `AND`
+/// in parenthesis, e.g. `(a AND b) OR c`.
+pub(crate) fn or_grouped<'a>(left: Expr<'a>, right: Expr<'a>) -> Expr<'a> {
+    fn group(expr: Expr<'_>) -> Expr<'_> {
+        match expr {
+            Expr::Infix(_, ref op, _) if op == "AND" => paren(expr),
+            expr => expr,
+        }
+    }
+    or(group(left), group(right))
+}
+
+/// Make an ascending sort out of an expression.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::asc;
+///
+/// assert_eq!(asc("id").to_string(), "id ASC");
+/// assert_eq!(asc(("user", "id")).to_string(), "user.id ASC");
+/// ```
+#[inline]
+pub fn asc<'a, E: Into<Expr<'a>>>(expr: E) -> Order<'a> {
+    Order(expr.into(), Some(Sort::Asc), None)
+}
+
+/// Make a descending sort out of an expression.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::desc;
+///
+/// assert_eq!(desc("id").to_string(), "id DESC");
+/// assert_eq!(desc(("user", "id")).to_string(), "user.id DESC");
+/// ```
+#[inline]
+pub fn desc<'a, E: Into<Expr<'a>>>(expr: E) -> Order<'a> {
+    Order(expr.into(), Some(Sort::Desc), None)
+}
+
+/// Construct a binary operation on expression.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::binop;
+///
+/// assert_eq!(binop(1, "+", 2).to_string(), "1 + 2");
+/// ```
+#[inline]
+pub fn binop<'a, L, R>(left: L, op: &'static str, right: R) -> Expr<'a>
+where
+    L: Into<Expr<'a>>,
+    R: Into<Expr<'a>>,
+{
+    Expr::Infix(Box::new(left.into()), op.into(), Box::new(right.into()))
+}
+
+#[inline]
+/// Construct a unary prefix operation on expression.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::preop;
+///
+/// assert_eq!(preop("NOT", true).to_string(), "NOT true");
+/// ```
+pub fn preop<'a, E>(op: &'static str, expr: E) -> Expr<'a>
+where
+    E: Into<Expr<'a>>,
+{
+    Expr::Prefix(op.into(), Box::new(expr.into()))
+}
+
+#[inline]
+/// Construct a unary postfix operation on expression.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::postop;
+///
+/// assert_eq!(postop("id", "ISNULL").to_string(), "id ISNULL");
+/// ```
+pub fn postop<'a, E>(expr: E, op: &'static str) -> Expr<'a>
+where
+    E: Into<Expr<'a>>,
+{
+    Expr::Postfix(Box::new(expr.into()), op.into())
+}
+
+/// Compare `left` with `right` using `op`, or test the other side with
+/// `null_op` if either of them is a `NULL`, which `op` would never match.
+fn compare_nullable<'a>(
+    left: Expr<'a>,
+    op: &'static str,
+    null_op: &'static str,
+    right: Expr<'a>,
+) -> Expr<'a> {
+    match (left, right) {
+        (expr, Expr::Literal(Value::Null(..))) | (Expr::Literal(Value::Null(..)), expr) => {
+            postop(expr, null_op)
+        }
+        (left, right) => binop(left, op, right),
+    }
+}
+
+/// Construct an `equal` comparison between two expression.
+///
+/// Since `= NULL` is never true, comparing with a `None` turns into an `IS
+/// NULL` test instead.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::eq;
+///
+/// assert_eq!(eq(1, 2).to_string(), "1 = 2");
+/// assert_eq!(eq("parent_id", None::<i32>).to_string(), "parent_id IS NULL");
+/// ```
+#[inline]
+pub fn eq<'a, L, R>(left: L, right: R) -> Expr<'a>
+where
+    L: Into<Expr<'a>>,
+    R: Into<Expr<'a>>,
+{
+    compare_nullable(left.into(), "=", "IS NULL", right.into())
+}
+
+/// Construct a `not equal` comparison between two expression.
+///
+/// Since `<> NULL` is never true, comparing with a `None` turns into an `IS
+/// NOT NULL` test instead.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::ne;
+///
+/// assert_eq!(ne(1, 2).to_string(), "1 <> 2");
+/// assert_eq!(ne("parent_id", None::<i32>).to_string(), "parent_id IS NOT NULL");
+/// ```
+#[inline]
+pub fn ne<'a, L, R>(left: L, right: R) -> Expr<'a>
+where
+    L: Into<Expr<'a>>,
+    R: Into<Expr<'a>>,
+{
+    compare_nullable(left.into(), "<>", "IS NOT NULL", right.into())
+}
+
+macro_rules! generate_binop_funcs {
+    ($trait:ident {$($(#[$comment:meta])* $name:ident: $op:expr),+}) => {
+        $(
+            $(#[$comment])*
+            #[inline]
+            pub fn $name<'a, L, R>(left: L, right: R) -> $crate::expr::Expr<'a>
+            where L: Into<$crate::expr::Expr<'a>>,
+                  R: Into<$crate::expr::Expr<'a>>,
+            {
+                crate::expr::Expr::Infix(
+                    Box::new(left.into()),
+                    std::borrow::Cow::Borrowed($op),
+                    Box::new(right.into()),
+                )
+            }
+        )+
+    };
+}
+
+generate_binop_funcs!(BinOps {
+    /// Construct an `addition` operation between two expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::add;
+    ///
+    /// assert_eq!(add(1, 2).to_string(), "1 + 2");
+    /// ```
+    add: "+",
+    /// Construct a `multiplication` operation between two expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::sub;
+    ///
+    /// assert_eq!(sub(1, 2).to_string(), "1 - 2");
+    /// ```
+    sub: "-",
+    /// Construct a `division` operation between two expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::mul;
+    ///
+    /// assert_eq!(mul(1, 2).to_string(), "1 * 2");
+    /// ```
+    mul: "*",
+    /// Construct a `modulo` operation between two expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::div;
+    ///
+    /// assert_eq!(div(1, 2).to_string(), "1 / 2");
+    /// ```
+    div: "/",
+    /// Construct an `equal` comparison between two expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::rem;
+    ///
+    /// assert_eq!(rem(1, 2).to_string(), "1 % 2");
+    /// ```
+    rem: "%",
+    /// Construct a `modulo` operation between two expression, an alias for
+    /// [`rem`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::ops::modulo;
+    ///
+    /// assert_eq!(modulo(7, 2).to_string(), "7 % 2");
+    /// ```
+    modulo: "%",
+    /// Construct a `greater or equal` comparison between two expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::gt;
+    ///
+    /// assert_eq!(gt(1, 2).to_string(), "1 > 2");
+    /// ```
+    gt: ">",
+    /// Construct a `less than` comparison between two expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::ge;
+    ///
+    /// assert_eq!(ge(1, 2).to_string(), "1 >= 2");
+    /// ```
+    ge: ">=",
+    /// Construct a `less or equal` comparison between two expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::lt;
+    ///
+    /// assert_eq!(lt(1, 2).to_string(), "1 < 2");
+    /// ```
+    lt: "<",
+    /// Construct a `less or equal` comparison between two expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::le;
+    ///
+    /// assert_eq!(le(1, 2).to_string(), "1 <= 2");
+    /// ```
+    le: "<=",
+    /// Construct a `boolean and` operation between two expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::and;
+    ///
+    /// assert_eq!(and(true, false).to_string(), "true AND false");
+    /// ```
+    and: "AND",
+    /// Construct a `boolean or` operation between two expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::or;
+    ///
+    /// assert_eq!(or(true, false).to_string(), "true OR false");
+    /// ```
+    or: "OR",
+    /// Construct a `LIKE` operation between two expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::like;
+    ///
+    /// assert_eq!(like("name", &"%name".to_string()).to_string(), "name LIKE '%name'");
+    /// ```
+    like: "LIKE",
+    /// Construct a `ILIKE` operation between two expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::ilike;
+    ///
+    /// assert_eq!(ilike("name", &"%name".to_string()).to_string(), "name ILIKE '%name'");
+    /// ```
+    ilike: "ILIKE",
+    /// Construct a `NOT LIKE` operation between two expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::not_like;
+    ///
+    /// assert_eq!(not_like("name", &"%name".to_string()).to_string(), "name NOT LIKE '%name'");
+    /// ```
+    not_like: "NOT LIKE",
+    /// Construct a `NOT ILIKE` operation between two expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::not_ilike;
+    ///
+    /// assert_eq!(not_ilike("name", &"%name".to_string()).to_string(), "name NOT ILIKE '%name'");
+    /// ```
+    not_ilike: "NOT ILIKE",
+    /// Construct a null-safe `IS DISTINCT FROM` comparison between two expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::is_distinct_from;
+    ///
+    /// assert_eq!(is_distinct_from("a", "b").to_string(), "a IS DISTINCT FROM b");
+    /// ```
+    is_distinct_from: "IS DISTINCT FROM",
+    /// Construct a null-safe `IS NOT DISTINCT FROM` comparison between two expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::is_not_distinct_from;
+    ///
+    /// assert_eq!(is_not_distinct_from("a", "b").to_string(), "a IS NOT DISTINCT FROM b");
+    /// ```
+    is_not_distinct_from: "IS NOT DISTINCT FROM",
+    /// Construct a full-text search `@@` match between a `tsvector` and a
+    /// `tsquery`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::and;
+    /// use qians_xql::matches;
+    /// use qians_xql::to_tsquery;
+    /// use qians_xql::to_tsvector;
+    ///
+    /// let query = "rust".to_string();
+    /// assert_eq!(
+    ///     and(matches(to_tsvector("body"), to_tsquery(&query)), "published").to_string(),
+    ///     "TO_TSVECTOR(body) @@ TO_TSQUERY('rust') AND published",
+    /// );
+    /// ```
+    matches: "@@",
+    /// Construct a case sensitive `~` regular expression match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::regex_match;
+    ///
+    /// let pattern = r"^\d+$".to_string();
+    /// assert_eq!(regex_match("code", &pattern).to_string(), r"code ~ E'^\\d+$'");
+    /// ```
+    regex_match: "~",
+    /// Construct a case insensitive `~*` regular expression match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::regex_imatch;
+    ///
+    /// let pattern = "^rust".to_string();
+    /// assert_eq!(regex_imatch("title", &pattern).to_string(), "title ~* '^rust'");
+    /// ```
+    regex_imatch: "~*",
+    /// Construct a case sensitive `!~` regular expression mismatch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::not_regex_match;
+    ///
+    /// let pattern = "^tmp_".to_string();
+    /// assert_eq!(not_regex_match("name", &pattern).to_string(), "name !~ '^tmp_'");
+    /// ```
+    not_regex_match: "!~",
+    /// Construct a case insensitive `!~*` regular expression mismatch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::not_regex_imatch;
+    ///
+    /// let pattern = "test".to_string();
+    /// assert_eq!(not_regex_imatch("email", &pattern).to_string(), "email !~* 'test'");
+    /// ```
+    not_regex_imatch: "!~*",
+    /// Construct a `@>` containment test, whether the left array or JSON
+    /// document contains the right one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::array;
+    /// use qians_xql::contains;
+    ///
+    /// let tag = "rust".to_string();
+    /// assert_eq!(contains("tags", array([&tag])).to_string(), "tags @> ARRAY['rust']");
+    /// ```
+    contains: "@>",
+    /// Construct a `<@` containment test, whether the left array or JSON
+    /// document is contained by the right one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::array;
+    /// use qians_xql::contained_by;
+    ///
+    /// assert_eq!(contained_by("ids", array([1, 2, 3])).to_string(), "ids <@ ARRAY[1, 2, 3]");
+    /// ```
+    contained_by: "<@",
+    /// Construct an `&&` overlap test, whether two arrays have any element in
+    /// common.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::array;
+    /// use qians_xql::overlaps;
+    ///
+    /// assert_eq!(overlaps("ids", array([1, 2])).to_string(), "ids && ARRAY[1, 2]");
+    /// ```
+    overlaps: "&&"
+});
+
+macro_rules! generate_like_escape_funcs {
+    ($($(#[$comment:meta])* $func:ident: $op:literal),+ $(,)?) => {
+        $(
+            $(#[$comment])*
+            #[inline]
+            pub fn $func<'a, L, R>(left: L, right: R, escape: &'a str) -> Expr<'a>
+            where
+                L: Into<Expr<'a>>,
+                R: Into<Expr<'a>>,
+            {
+                Expr::LikeEscape(
+                    Box::new(left.into()),
+                    std::borrow::Cow::Borrowed($op),
+                    Box::new(right.into()),
+                    Box::new(Expr::Literal(Value::Text(escape.into()))),
+                )
+            }
+        )+
+    };
+}
+
+generate_like_escape_funcs!(
+    /// Construct a `LIKE` operation with an `ESCAPE` character.
+    ///
+    /// The escape character is a value like the pattern, so it is bound as a
+    /// parameter when rendered for a [`Dialect`](crate::dialect::Dialect).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::dialect::Postgres;
+    /// use qians_xql::like_escape;
+    /// use qians_xql::select;
+    ///
+    /// assert_eq!(
+    ///     like_escape("rate", &"100!%".to_string(), "!").to_string(),
+    ///     "rate LIKE '100!%' ESCAPE '!'",
+    /// );
+    /// assert_eq!(
+    ///     like_escape("name", &"it's%".to_string(), "'").to_string(),
+    ///     "name LIKE 'it''s%' ESCAPE ''''",
+    /// );
+    ///
+    /// let pattern = "100!%".to_string();
+    /// let query = select(["id"]).filter(like_escape("rate", &pattern, "!"));
+    /// assert_eq!(query.to_sql(Postgres).0, r#"SELECT "id" WHERE "rate" LIKE $1 ESCAPE $2"#);
+    /// ```
+    like_escape: "LIKE",
+    /// Construct an `ILIKE` operation with an `ESCAPE` character, see
+    /// [`like_escape`].
+    ilike_escape: "ILIKE",
+    /// Construct a `NOT LIKE` operation with an `ESCAPE` character, see
+    /// [`like_escape`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::not_like_escape;
+    ///
+    /// assert_eq!(
+    ///     not_like_escape("rate", &"100!%".to_string(), "!").to_string(),
+    ///     "rate NOT LIKE '100!%' ESCAPE '!'",
+    /// );
+    /// ```
+    not_like_escape: "NOT LIKE",
+    /// Construct a `NOT ILIKE` operation with an `ESCAPE` character, see
+    /// [`like_escape`].
+    not_ilike_escape: "NOT ILIKE"
+);
+
+/// Refer to the value that would have been inserted into a column, to be used
+/// in MySQL `ON DUPLICATE KEY UPDATE`.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::ops::values_ref;
+///
+/// assert_eq!(values_ref("name").to_string(), "VALUES (name)");
+/// ```
+#[inline]
+pub fn values_ref<'a, C>(column: C) -> Expr<'a>
+where
+    C: Into<Ident<'a>>,
+{
+    preop(
+        "VALUES",
+        paren(crate::item::ColumnRef::Column(column.into())),
+    )
+}
+
+/// Refer to the value that would have been inserted into a column, to be used
+/// in `ON CONFLICT DO UPDATE`.
+///
+/// `EXCLUDED` is written as a keyword, only the column is quoted.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::insert;
+/// use qians_xql::ops::excluded;
+/// use qians_xql::dialect::Postgres;
+///
+/// let query = insert("user", ["id", "name"])
+///     .values([(1, 2)])
+///     .on_conflict(["id"])
+///     .do_update([("name", excluded("name"))]);
+/// assert_eq!(
+///     query.to_string(),
+///     "INSERT INTO user(id, name) VALUES (1, 2) ON CONFLICT (id) DO UPDATE SET name = EXCLUDED.name",
+/// );
+///
+/// let (sql, _) = query.to_sql(Postgres);
+/// assert_eq!(
+///     sql,
+///     r#"INSERT INTO "user"("id", "name") VALUES ($1, $2) ON CONFLICT ("id") DO UPDATE SET "name" = EXCLUDED."name""#,
+/// );
+/// ```
+#[inline]
+pub fn excluded<'a, C>(column: C) -> Expr<'a>
+where
+    C: Into<Ident<'a>>,
+{
+    Expr::Column(ColumnRef::Excluded(column.into()))
+}
+
+/// Construct a `boolean not` operation on an expression.
+///
+/// Operators are wrapped in parentheses, while columns, literals and other
+/// prefix operations like `EXISTS` are not.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::eq;
+/// use qians_xql::not;
+///
+/// assert_eq!(not(true).to_string(), "NOT true");
+/// assert_eq!(not(eq("a", 1)).to_string(), "NOT (a = 1)");
+/// ```
+#[inline]
+pub fn not<'a, E>(expr: E) -> Expr<'a>
+where
+    E: Into<Expr<'a>>,
+{
+    preop("NOT", expr)
+}
+
+/// Construct a `BETWEEN` operation on an expression.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::between;
+///
+/// assert_eq!(between("age", 18, 65).to_string(), "age BETWEEN 18 AND 65");
+/// ```
+#[inline]
+pub fn between<'a, E, L, H>(expr: E, low: L, high: H) -> Expr<'a>
+where
+    E: Into<Expr<'a>>,
+    L: Into<Expr<'a>>,
+    H: Into<Expr<'a>>,
+{
+    Expr::Between(
+        Box::new(expr.into()),
+        "BETWEEN".into(),
+        Box::new(low.into()),
+        Box::new(high.into()),
+    )
+}
+
+/// Construct a `NOT BETWEEN` operation on an expression.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::not_between;
+///
+/// assert_eq!(not_between("age", 18, 65).to_string(), "age NOT BETWEEN 18 AND 65");
+/// ```
+#[inline]
+pub fn not_between<'a, E, L, H>(expr: E, low: L, high: H) -> Expr<'a>
+where
+    E: Into<Expr<'a>>,
+    L: Into<Expr<'a>>,
+    H: Into<Expr<'a>>,
+{
+    Expr::Between(
+        Box::new(expr.into()),
+        "NOT BETWEEN".into(),
+        Box::new(low.into()),
+        Box::new(high.into()),
+    )
+}
+
+/// Construct an `IN` operation between an expression and a list of values.
+///
+/// Since `IN ()` is rejected by most databases, an empty list renders
+/// `false` instead, which is what `IN` over an empty set evaluates to.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::in_;
+///
+/// assert_eq!(in_("id", [1, 2, 3]).to_string(), "id IN (1, 2, 3)");
+/// assert_eq!(in_("id", Vec::<i32>::new()).to_string(), "false");
+/// ```
+#[inline]
+pub fn in_<'a, E, A, I>(expr: E, list: I) -> Expr<'a>
+where
+    E: Into<Expr<'a>>,
+    A: Into<Expr<'a>>,
+    I: IntoIterator<Item = A>,
+{
+    let list: Vec<Expr> = list.into_iter().map(Into::into).collect();
+    if list.is_empty() {
+        return false.into();
+    }
+    Expr::In(Box::new(expr.into()), "IN".into(), list)
+}
+
+/// Construct a `NOT IN` operation between an expression and a list of values.
+///
+/// Since `NOT IN ()` is rejected by most databases, an empty list renders
+/// `true` instead, which is what `NOT IN` over an empty set evaluates to.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::not_in;
+///
+/// assert_eq!(not_in("id", [1, 2, 3]).to_string(), "id NOT IN (1, 2, 3)");
+/// assert_eq!(not_in("id", Vec::<i32>::new()).to_string(), "true");
+/// ```
+#[inline]
+pub fn not_in<'a, E, A, I>(expr: E, list: I) -> Expr<'a>
+where
+    E: Into<Expr<'a>>,
+    A: Into<Expr<'a>>,
+    I: IntoIterator<Item = A>,
+{
+    let list: Vec<Expr> = list.into_iter().map(Into::into).collect();
+    if list.is_empty() {
+        return true.into();
+    }
+    Expr::In(Box::new(expr.into()), "NOT IN".into(), list)
+}
+
+/// Construct an `IN` operation between an expression and a subquery.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::in_subquery;
+/// use qians_xql::select;
+///
+/// assert_eq!(
+///     in_subquery("id", select(["user_id"]).from("orders")).to_string(),
+///     "id IN (SELECT user_id FROM orders)",
+/// );
+/// ```
+#[inline]
+pub fn in_subquery<'a, E, S>(expr: E, subquery: S) -> Expr<'a>
+where
+    E: Into<Expr<'a>>,
+    S: Into<Data<'a>>,
+{
+    binop(expr, "IN", Expr::SubQuery(subquery.into()))
+}
+
+/// Construct a `NOT IN` operation between an expression and a subquery.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::not_in_subquery;
+/// use qians_xql::select;
 ///
-/// assert_eq!(not(paren(and(true, false))).to_string(), "NOT (true AND false)");
+/// assert_eq!(
+///     not_in_subquery("id", select(["user_id"]).from("orders")).to_string(),
+///     "id NOT IN (SELECT user_id FROM orders)",
+/// );
 /// ```
 #[inline]
-pub fn paren<'a, E>(expr: E) -> Expr<'a>
+pub fn not_in_subquery<'a, E, S>(expr: E, subquery: S) -> Expr<'a>
 where
     E: Into<Expr<'a>>,
+    S: Into<Data<'a>>,
 {
-    Expr::Paren(Box::new(expr.into()))
+    binop(expr, "NOT IN", Expr::SubQuery(subquery.into()))
 }
 
-/// Make an ascending sort out of an expression.
+/// Construct an `EXISTS` test on a subquery.
 ///
 /// # Examples
 ///
 /// ```
-/// use qians_xql::asc;
+/// use qians_xql::eq;
+/// use qians_xql::exists;
+/// use qians_xql::select;
 ///
-/// assert_eq!(asc("id").to_string(), "id ASC");
-/// assert_eq!(asc(("user", "id")).to_string(), "user.id ASC");
+/// let orders = select([1])
+///     .from("orders")
+///     .filter(eq(("orders", "user_id"), ("users", "id")));
+/// assert_eq!(
+///     select(["name"]).from("users").filter(exists(orders)).to_string(),
+///     "SELECT name FROM users WHERE EXISTS (SELECT 1 FROM orders WHERE orders.user_id = users.id)",
+/// );
 /// ```
 #[inline]
-pub fn asc<'a, E: Into<Expr<'a>>>(expr: E) -> Order<'a> {
-    Order(expr.into(), Some(Sort::Asc))
+pub fn exists<'a, S>(subquery: S) -> Expr<'a>
+where
+    S: Into<Data<'a>>,
+{
+    preop("EXISTS", Expr::SubQuery(subquery.into()))
 }
 
-/// Make a descending sort out of an expression.
+/// Construct a `NOT EXISTS` test on a subquery.
 ///
 /// # Examples
 ///
 /// ```
-/// use qians_xql::desc;
+/// use qians_xql::not_exists;
+/// use qians_xql::select;
 ///
-/// assert_eq!(desc("id").to_string(), "id DESC");
-/// assert_eq!(desc(("user", "id")).to_string(), "user.id DESC");
+/// assert_eq!(
+///     not_exists(select([1]).from("orders")).to_string(),
+///     "NOT EXISTS (SELECT 1 FROM orders)",
+/// );
 /// ```
 #[inline]
-pub fn desc<'a, E: Into<Expr<'a>>>(expr: E) -> Order<'a> {
-    Order(expr.into(), Some(Sort::Desc))
+pub fn not_exists<'a, S>(subquery: S) -> Expr<'a>
+where
+    S: Into<Data<'a>>,
+{
+    preop("NOT EXISTS", Expr::SubQuery(subquery.into()))
 }
 
-/// Construct a binary operation on expression.
+/// Construct an `IS NULL` test on an expression.
 ///
 /// # Examples
 ///
 /// ```
-/// use qians_xql::binop;
+/// use qians_xql::is_null;
 ///
-/// assert_eq!(binop(1, "+", 2).to_string(), "1 + 2");
+/// assert_eq!(is_null("deleted_at").to_string(), "deleted_at IS NULL");
 /// ```
 #[inline]
-pub fn binop<'a, L, R>(left: L, op: &'static str, right: R) -> Expr<'a>
+pub fn is_null<'a, E>(expr: E) -> Expr<'a>
 where
-    L: Into<Expr<'a>>,
-    R: Into<Expr<'a>>,
+    E: Into<Expr<'a>>,
 {
-    Expr::Infix(Box::new(left.into()), op, Box::new(right.into()))
+    postop(expr, "IS NULL")
 }
 
-#[inline]
-/// Construct a unary prefix operation on expression.
+/// Construct an `IS NOT NULL` test on an expression.
 ///
 /// # Examples
 ///
 /// ```
-/// use qians_xql::preop;
+/// use qians_xql::is_not_null;
 ///
-/// assert_eq!(preop("NOT", true).to_string(), "NOT true");
+/// assert_eq!(is_not_null("deleted_at").to_string(), "deleted_at IS NOT NULL");
 /// ```
-pub fn preop<'a, E>(op: &'static str, expr: E) -> Expr<'a>
+#[inline]
+pub fn is_not_null<'a, E>(expr: E) -> Expr<'a>
 where
     E: Into<Expr<'a>>,
 {
-    Expr::Prefix(op, Box::new(expr.into()))
+    postop(expr, "IS NOT NULL")
 }
 
-#[inline]
-/// Construct a unary postfix operation on expression.
+/// Construct an `ANY` quantifier on an array or a subquery, to be used as the
+/// right side of a comparison.
 ///
 /// # Examples
 ///
 /// ```
-/// use qians_xql::postop;
+/// use qians_xql::any;
+/// use qians_xql::gt;
+/// use qians_xql::select;
 ///
-/// assert_eq!(postop("id", "ISNULL").to_string(), "id ISNULL");
+/// assert_eq!(gt("price", any("prices")).to_string(), "price > ANY(prices)");
+/// assert_eq!(
+///     gt("price", any(select(["price"]).from("book"))).to_string(),
+///     "price > ANY(SELECT price FROM book)",
+/// );
 /// ```
-pub fn postop<'a, E>(expr: E, op: &'static str) -> Expr<'a>
+#[inline]
+pub fn any<'a, E>(expr: E) -> Expr<'a>
 where
     E: Into<Expr<'a>>,
 {
-    Expr::Postfix(Box::new(expr.into()), op)
+    Expr::Quantified("ANY".into(), Box::new(expr.into()))
 }
 
-macro_rules! generate_binop_funcs {
-    ($trait:ident {$($(#[$comment:meta])* $name:ident: $op:expr),+}) => {
-        $(
-            $(#[$comment])*
-            #[inline]
-            pub fn $name<'a, L, R>(left: L, right: R) -> $crate::expr::Expr<'a>
-            where L: Into<$crate::expr::Expr<'a>>,
-                  R: Into<$crate::expr::Expr<'a>>,
-            {
-                crate::expr::Expr::Infix(
-                    Box::new(left.into()),
-                    $op,
-                    Box::new(right.into()),
-                )
-            }
-        )+
-    };
+/// Construct an `ALL` quantifier on an array or a subquery, to be used as the
+/// right side of a comparison.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::all;
+/// use qians_xql::ne;
+///
+/// assert_eq!(ne("id", all("banned")).to_string(), "id <> ALL(banned)");
+/// ```
+#[inline]
+pub fn all<'a, E>(expr: E) -> Expr<'a>
+where
+    E: Into<Expr<'a>>,
+{
+    Expr::Quantified("ALL".into(), Box::new(expr.into()))
 }
 
-generate_binop_funcs!(BinOps {
-    /// Construct an `addition` operation between two expression.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use qians_xql::add;
-    ///
-    /// assert_eq!(add(1, 2).to_string(), "1 + 2");
-    /// ```
-    add: "+",
-    /// Construct a `multiplication` operation between two expression.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use qians_xql::sub;
-    ///
-    /// assert_eq!(sub(1, 2).to_string(), "1 - 2");
-    /// ```
-    sub: "-",
-    /// Construct a `division` operation between two expression.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use qians_xql::mul;
-    ///
-    /// assert_eq!(mul(1, 2).to_string(), "1 * 2");
-    /// ```
-    mul: "*",
-    /// Construct a `modulo` operation between two expression.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use qians_xql::div;
-    ///
-    /// assert_eq!(div(1, 2).to_string(), "1 / 2");
-    /// ```
-    div: "/",
-    /// Construct an `equal` comparison between two expression.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use qians_xql::rem;
-    ///
-    /// assert_eq!(rem(1, 2).to_string(), "1 % 2");
-    /// ```
-    rem: "%",
-    /// Construct a `not equal` comparison between two expression.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use qians_xql::eq;
-    ///
-    /// assert_eq!(eq(1, 2).to_string(), "1 = 2");
-    /// ```
-    eq: "=",
-    /// Construct a `greater than` comparison between two expression.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use qians_xql::ne;
-    ///
-    /// assert_eq!(ne(1, 2).to_string(), "1 <> 2");
-    /// ```
-    ne: "<>",
-    /// Construct a `greater or equal` comparison between two expression.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use qians_xql::gt;
-    ///
-    /// assert_eq!(gt(1, 2).to_string(), "1 > 2");
-    /// ```
-    gt: ">",
-    /// Construct a `less than` comparison between two expression.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use qians_xql::ge;
-    ///
-    /// assert_eq!(ge(1, 2).to_string(), "1 >= 2");
-    /// ```
-    ge: ">=",
-    /// Construct a `less or equal` comparison between two expression.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use qians_xql::lt;
-    ///
-    /// assert_eq!(lt(1, 2).to_string(), "1 < 2");
-    /// ```
-    lt: "<",
-    /// Construct a `less or equal` comparison between two expression.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use qians_xql::le;
-    ///
-    /// assert_eq!(le(1, 2).to_string(), "1 <= 2");
-    /// ```
-    le: "<=",
-    /// Construct a `boolean and` operation between two expression.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use qians_xql::and;
-    ///
-    /// assert_eq!(and(true, false).to_string(), "true AND false");
-    /// ```
-    and: "AND",
-    /// Construct a `boolean or` operation between two expression.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use qians_xql::or;
-    ///
-    /// assert_eq!(or(true, false).to_string(), "true OR false");
-    /// ```
-    or: "OR",
-    /// Construct a `LIKE` operation between two expression.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use qians_xql::like;
-    ///
-    /// assert_eq!(like("name", &"%name".to_string()).to_string(), "name LIKE '%name'");
-    /// ```
-    like: "LIKE",
-    /// Construct a `ILIKE` operation between two expression.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use qians_xql::ilike;
-    ///
-    /// assert_eq!(ilike("name", &"%name".to_string()).to_string(), "name ILIKE '%name'");
-    /// ```
-    ilike: "ILIKE"
-});
-
-/// Construct a `boolean not` operation on an expression.
+/// Construct an `= ANY(...)` comparison, a short hand for `eq(expr, any(rhs))`.
 ///
 /// # Examples
 ///
 /// ```
-/// use qians_xql::not;
+/// use qians_xql::eq_any;
 ///
-/// assert_eq!(not(true).to_string(), "NOT true");
+/// assert_eq!(eq_any("id", "ids").to_string(), "id = ANY(ids)");
 /// ```
 #[inline]
-pub fn not<'a, E>(expr: E) -> Expr<'a>
+pub fn eq_any<'a, E, R>(expr: E, rhs: R) -> Expr<'a>
 where
     E: Into<Expr<'a>>,
+    R: Into<Expr<'a>>,
 {
-    preop("NOT", expr)
+    eq(expr, any(rhs))
 }
 
 /// Construct an `ISNULL` operation on an expression.
@@ -343,7 +1414,7 @@ where
 /// ```
 /// use qians_xql::isnull;
 ///
-/// assert_eq!(isnull(None::<i32>).to_string(), "null ISNULL");
+/// assert_eq!(isnull(None::<i32>).to_string(), "NULL ISNULL");
 /// ```
 #[inline]
 pub fn isnull<'a, E>(expr: E) -> Expr<'a>
@@ -354,30 +1425,47 @@ where
 }
 
 macro_rules! generate_join_funcs {
+    ($(#[$comment:meta])* $kind:ident $fn:ident natural) => {
+        $(#[$comment])*
+        #[inline]
+        pub fn $fn<'a, L, R>(left: L, right: R) -> crate::table_expr::TableExpr<'a>
+        where
+            L: Into<crate::item::Table<'a>>,
+            R: Into<crate::item::Table<'a>>,
+        {
+            crate::table_expr::TableExpr::Join(
+                Box::new(left.into()),
+                crate::item::JoinKind::$kind,
+                Box::new(right.into()),
+                crate::item::JoinCond::Natural,
+            )
+        }
+    };
     ($(#[$comment:meta])* $join:ident $fn:ident) => {
         $(#[$comment])*
         #[inline]
         pub fn $fn<'a, L, R>(left: L, right: R) -> crate::table_expr::TableExpr<'a>
         where
-            L: Into<crate::table_expr::TableExpr<'a>>,
-            R: Into<crate::table_expr::TableExpr<'a>>,
+            L: Into<crate::item::Table<'a>>,
+            R: Into<crate::item::Table<'a>>,
         {
             crate::table_expr::TableExpr::$join(Box::new(left.into()), Box::new(right.into()))
         }
     };
-    ($(#[$comment:meta])* $join:ident $fn:ident cond) => {
+    ($(#[$comment:meta])* $kind:ident $fn:ident cond) => {
         $(#[$comment])*
         #[inline]
         pub fn $fn<'a, L, R, E>(left: L, right: R, cond: E) -> crate::table_expr::TableExpr<'a>
         where
-            L: Into<crate::table_expr::TableExpr<'a>>,
-            R: Into<crate::table_expr::TableExpr<'a>>,
+            L: Into<crate::item::Table<'a>>,
+            R: Into<crate::item::Table<'a>>,
             E: Into<crate::expr::Expr<'a>>,
         {
-            crate::table_expr::TableExpr::$join(
+            crate::table_expr::TableExpr::Join(
                 Box::new(left.into()),
+                crate::item::JoinKind::$kind,
                 Box::new(right.into()),
-                cond.into(),
+                crate::item::JoinCond::On(cond.into()),
             )
         }
     };
@@ -397,7 +1485,7 @@ generate_join_funcs!(
     ///     "category JOIN book ON category.id = book.category_id",
     /// );
     /// ```
-    Join join cond);
+    Inner join cond);
 generate_join_funcs!(
     /// Construct a `LEFT JOIN` operation on a table expression.
     /// 
@@ -412,7 +1500,7 @@ generate_join_funcs!(
     ///     "category LEFT JOIN book ON category.id = book.category_id",
     /// );
     /// ```
-    LeftJoin left_join cond);
+    Left left_join cond);
 generate_join_funcs!(
     /// Construct a `RIGHT JOIN` operation on a table expression.
     /// 
@@ -427,7 +1515,7 @@ generate_join_funcs!(
     ///     "category RIGHT JOIN book ON category.id = book.category_id",
     /// );
     /// ```
-    RightJoin right_join cond);
+    Right right_join cond);
 generate_join_funcs!(
     /// Construct a `FULL JOIN` operation on a table expression.
     /// 
@@ -442,7 +1530,7 @@ generate_join_funcs!(
     ///     "category FULL JOIN book ON category.id = book.category_id",
     /// );
     /// ```
-    FullJoin full_join cond);
+    Full full_join cond);
 generate_join_funcs!(
     /// Construct a `NATURAL JOIN` operation on a table expression.
     /// 
@@ -456,7 +1544,7 @@ generate_join_funcs!(
     ///     "category NATURAL JOIN book",
     /// );
     /// ```
-    NaturalJoin natural_join);
+    Inner natural_join natural);
 generate_join_funcs!(
     /// Construct a `NATURAL LEFT JOIN` operation on a table expression.
     /// 
@@ -470,7 +1558,7 @@ generate_join_funcs!(
     ///     "category NATURAL LEFT JOIN book",
     /// );
     /// ```
-    NaturalLeftJoin natural_left_join);
+    Left natural_left_join natural);
 generate_join_funcs!(
     /// Construct a `NATURAL RIGHT JOIN` operation on a table expression.
     /// 
@@ -484,7 +1572,7 @@ generate_join_funcs!(
     ///     "category NATURAL RIGHT JOIN book",
     /// );
     /// ```
-    NaturalRightJoin natural_right_join);
+    Right natural_right_join natural);
 generate_join_funcs!(
     /// Construct a `NATURAL FULL JOIN` operation on a table expression.
     /// 
@@ -498,7 +1586,7 @@ generate_join_funcs!(
     ///     "category NATURAL FULL JOIN book",
     /// );
     /// ```
-    NaturalFullJoin natural_full_join);
+    Full natural_full_join natural);
 generate_join_funcs!(
     /// Construct a `CROSS JOIN` operation on a table expression.
     /// 
@@ -513,3 +1601,67 @@ generate_join_funcs!(
     /// );
     /// ```
     CrossJoin cross_join);
+generate_join_funcs!(
+    /// Construct a `CROSS APPLY` operation on a table expression, rendered as
+    /// `CROSS JOIN LATERAL` unless the dialect supports `APPLY`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use qians_xql::cross_apply;
+    /// 
+    /// assert_eq!(
+    ///     cross_apply("category", "book").to_string(),
+    ///     "category CROSS JOIN LATERAL book",
+    /// );
+    /// ```
+    CrossApply cross_apply);
+generate_join_funcs!(
+    /// Construct an `OUTER APPLY` operation on a table expression, rendered
+    /// as `LEFT JOIN LATERAL ... ON TRUE` unless the dialect supports `APPLY`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use qians_xql::outer_apply;
+    /// 
+    /// assert_eq!(
+    ///     outer_apply("category", "book").to_string(),
+    ///     "category LEFT JOIN LATERAL book ON TRUE",
+    /// );
+    /// ```
+    OuterApply outer_apply);
+
+/// Construct a `JOIN` operation with `USING` on a table expression.
+///
+/// # Panics
+///
+/// Panics if `columns` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::join_using;
+///
+/// assert_eq!(
+///     join_using("book", "author", ["author_id"]).to_string(),
+///     "book JOIN author USING (author_id)",
+/// );
+/// ```
+#[inline]
+pub fn join_using<'a, L, R, C, I>(left: L, right: R, columns: I) -> TableExpr<'a>
+where
+    L: Into<Table<'a>>,
+    R: Into<Table<'a>>,
+    C: Into<Ident<'a>>,
+    I: IntoIterator<Item = C>,
+{
+    let columns: Vec<Ident<'a>> = columns.into_iter().map(Into::into).collect();
+    assert!(!columns.is_empty(), "`USING` requires at least one column");
+    TableExpr::Join(
+        Box::new(left.into()),
+        JoinKind::Inner,
+        Box::new(right.into()),
+        JoinCond::Using(columns),
+    )
+}
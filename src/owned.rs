@@ -0,0 +1,401 @@
+//! Conversion of the AST into owned, `'static` values.
+
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+/// Convert every borrowed string into an owned one, so that the value no
+/// longer borrows from where it was built.
+pub(crate) trait IntoOwned {
+    type Owned: 'static;
+
+    fn into_owned(self) -> Self::Owned;
+}
+
+impl<B: ?Sized + ToOwned + 'static> IntoOwned for Cow<'_, B> {
+    type Owned = Cow<'static, B>;
+
+    #[inline]
+    fn into_owned(self) -> Cow<'static, B> {
+        Cow::Owned(Cow::into_owned(self))
+    }
+}
+
+impl<T: IntoOwned> IntoOwned for Vec<T> {
+    type Owned = Vec<T::Owned>;
+
+    #[inline]
+    fn into_owned(self) -> Vec<T::Owned> {
+        self.into_iter().map(IntoOwned::into_owned).collect()
+    }
+}
+
+impl<T: IntoOwned> IntoOwned for Option<T> {
+    type Owned = Option<T::Owned>;
+
+    #[inline]
+    fn into_owned(self) -> Option<T::Owned> {
+        self.map(IntoOwned::into_owned)
+    }
+}
+
+impl<T: IntoOwned> IntoOwned for Box<T> {
+    type Owned = Box<T::Owned>;
+
+    #[inline]
+    fn into_owned(self) -> Box<T::Owned> {
+        Box::new(IntoOwned::into_owned(*self))
+    }
+}
+
+impl<A: IntoOwned, B: IntoOwned> IntoOwned for (A, B) {
+    type Owned = (A::Owned, B::Owned);
+
+    #[inline]
+    fn into_owned(self) -> Self::Owned {
+        (IntoOwned::into_owned(self.0), IntoOwned::into_owned(self.1))
+    }
+}
+
+/// Types that never borrow, which are kept as is.
+macro_rules! owned_static {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl IntoOwned for $ty {
+                type Owned = $ty;
+
+                #[inline]
+                fn into_owned(self) -> $ty {
+                    self
+                }
+            }
+        )+
+    };
+}
+
+/// Structs converted field by field, named or positional.
+macro_rules! owned_struct {
+    ($($ty:ident)::+ { $($field:ident),+ $(,)? }) => {
+        impl IntoOwned for $($ty)::+<'_> {
+            type Owned = $($ty)::+<'static>;
+
+            fn into_owned(self) -> Self::Owned {
+                $($ty)::+ {
+                    $($field: IntoOwned::into_owned(self.$field),)+
+                }
+            }
+        }
+    };
+
+    ($($ty:ident)::+ ($($idx:tt),+ $(,)?)) => {
+        impl IntoOwned for $($ty)::+<'_> {
+            type Owned = $($ty)::+<'static>;
+
+            fn into_owned(self) -> Self::Owned {
+                $($ty)::+($(IntoOwned::into_owned(self.$idx),)+)
+            }
+        }
+    };
+}
+
+/// Enums converted variant by variant, naming the fields of each variant.
+macro_rules! owned_enum {
+    ($($ty:ident)::+ { $($variant:ident $(($($val:ident),+))?),+ $(,)? }) => {
+        impl IntoOwned for $($ty)::+<'_> {
+            type Owned = $($ty)::+<'static>;
+
+            fn into_owned(self) -> Self::Owned {
+                type Owned = $($ty)::+<'static>;
+
+                match self {
+                    $(
+                        Self::$variant $(($($val),+))? => {
+                            Owned::$variant $(($(IntoOwned::into_owned($val)),+))?
+                        }
+                    )+
+                }
+            }
+        }
+    };
+}
+
+owned_static!(
+    bool,
+    u32,
+    &'static str,
+    crate::item::Sort,
+    crate::item::Nulls,
+    crate::item::JoinKind,
+    crate::item::SampleMethod,
+    crate::item::DateField,
+);
+
+// values
+owned_struct!(crate::value::Decimal(0));
+owned_struct!(crate::value::Json(0));
+
+impl IntoOwned for crate::value::Null<'_> {
+    type Owned = crate::value::Null<'static>;
+
+    fn into_owned(self) -> Self::Owned {
+        use crate::value::Null;
+
+        match self {
+            Null::Bool(..) => Null::Bool(PhantomData),
+            Null::TinyInt(..) => Null::TinyInt(PhantomData),
+            Null::SmallInt(..) => Null::SmallInt(PhantomData),
+            Null::Int(..) => Null::Int(PhantomData),
+            Null::BigInt(..) => Null::BigInt(PhantomData),
+            Null::TinyUInt(..) => Null::TinyUInt(PhantomData),
+            Null::SmallUInt(..) => Null::SmallUInt(PhantomData),
+            Null::UInt(..) => Null::UInt(PhantomData),
+            Null::BigUInt(..) => Null::BigUInt(PhantomData),
+            Null::Real(..) => Null::Real(PhantomData),
+            Null::Double(..) => Null::Double(PhantomData),
+            Null::Decimal(..) => Null::Decimal(PhantomData),
+            Null::Text(..) => Null::Text(PhantomData),
+            Null::Bytes(..) => Null::Bytes(PhantomData),
+            Null::Json(..) => Null::Json(PhantomData),
+            #[cfg(feature = "use-chrono")]
+            Null::Date(..) => Null::Date(PhantomData),
+            #[cfg(feature = "use-chrono")]
+            Null::Timestamp(..) => Null::Timestamp(PhantomData),
+            #[cfg(feature = "use-chrono")]
+            Null::DateTime(..) => Null::DateTime(PhantomData),
+            #[cfg(feature = "use-decimal")]
+            Null::Numeric(..) => Null::Numeric(PhantomData),
+        }
+    }
+}
+
+impl IntoOwned for crate::value::Value<'_> {
+    type Owned = crate::value::Value<'static>;
+
+    fn into_owned(self) -> Self::Owned {
+        use crate::value::Value;
+
+        match self {
+            Value::Null(val) => Value::Null(val.into_owned()),
+            Value::Bool(val) => Value::Bool(val),
+            Value::TinyInt(val) => Value::TinyInt(val),
+            Value::SmallInt(val) => Value::SmallInt(val),
+            Value::Int(val) => Value::Int(val),
+            Value::BigInt(val) => Value::BigInt(val),
+            Value::TinyUInt(val) => Value::TinyUInt(val),
+            Value::SmallUInt(val) => Value::SmallUInt(val),
+            Value::UInt(val) => Value::UInt(val),
+            Value::BigUInt(val) => Value::BigUInt(val),
+            Value::Real(val) => Value::Real(val),
+            Value::Double(val) => Value::Double(val),
+            Value::Decimal(val) => Value::Decimal(val.into_owned()),
+            Value::Text(val) => Value::Text(IntoOwned::into_owned(val)),
+            Value::Bytes(val) => Value::Bytes(IntoOwned::into_owned(val)),
+            Value::Json(val) => Value::Json(val.into_owned()),
+            #[cfg(feature = "use-chrono")]
+            Value::Date(val) => Value::Date(val),
+            #[cfg(feature = "use-chrono")]
+            Value::Timestamp(val) => Value::Timestamp(val),
+            #[cfg(feature = "use-chrono")]
+            Value::DateTime(val) => Value::DateTime(val),
+            #[cfg(feature = "use-decimal")]
+            Value::Numeric(val) => Value::Numeric(val),
+        }
+    }
+}
+
+// items
+owned_struct!(crate::item::Ident(0));
+owned_struct!(crate::item::TypeName(0));
+owned_struct!(crate::item::Order(0, 1, 2));
+owned_struct!(crate::item::FuncCall {
+    func,
+    args,
+    filter,
+    distinct,
+    orders,
+});
+owned_struct!(crate::item::Field { expr, alias });
+owned_struct!(crate::item::Table {
+    table,
+    alias,
+    sample
+});
+owned_struct!(crate::item::TableSample(0, 1, 2));
+owned_struct!(crate::item::Row(0));
+owned_struct!(crate::item::Cte {
+    name,
+    columns,
+    stmt
+});
+owned_struct!(crate::item::Window {
+    name,
+    partition,
+    orders,
+    frame
+});
+owned_struct!(crate::item::Frame(0, 1, 2));
+owned_enum!(crate::item::ColumnRef {
+    Star,
+    Column(col),
+    TableColumn(table, col),
+    SchemaTableColumn(schema, table, col),
+    TableStar(table),
+    Excluded(col),
+});
+owned_enum!(crate::item::TableRef {
+    Table(table),
+    SchemaTable(schema, table),
+});
+owned_enum!(crate::item::Grouping {
+    Expr(expr),
+    Sets(sets),
+    Rollup(exprs),
+    Cube(exprs),
+});
+owned_enum!(crate::item::JoinCond {
+    On(expr),
+    Using(cols),
+    Natural,
+});
+owned_enum!(crate::item::FuncRef {
+    Func(func),
+    SchemaFunc(schema, func),
+    Builtin(name),
+});
+owned_enum!(crate::item::FrameBound {
+    UnboundedPreceding,
+    Preceding(expr),
+    CurrentRow,
+    Following(expr),
+    UnboundedFollowing,
+});
+
+// expressions
+owned_enum!(crate::expr::Expr {
+    Column(col),
+    Literal(val),
+    FuncCall(func),
+    Prefix(op, val),
+    Infix(left, op, right),
+    Postfix(val, op),
+    Paren(val),
+    SubQuery(data),
+    Window(val, window),
+    Cast(val, ty),
+    Extract(field, val),
+    Between(val, op, low, high),
+    LikeEscape(val, op, pattern, escape),
+    In(val, op, list),
+    Quantified(op, val),
+    Array(elems),
+    Subscript(val, from, to),
+    Ordinal(pos),
+    Interval(val),
+    Quoted(val),
+    Raw(sql),
+});
+owned_enum!(crate::table_expr::TableExpr {
+    TableRef(table),
+    FuncCall(func),
+    Join(left, kind, right, cond),
+    CrossJoin(left, right),
+    Lateral(table),
+    CrossApply(left, right),
+    OuterApply(left, right),
+    Values(rows, columns),
+    Raw(sql),
+    SubQuery(data),
+});
+
+// clauses
+owned_struct!(crate::clause::With(0, 1));
+owned_struct!(crate::clause::Select(0));
+owned_struct!(crate::clause::Distinct(0));
+owned_struct!(crate::clause::From(0));
+owned_struct!(crate::clause::Using(0));
+owned_struct!(crate::clause::Where(0));
+owned_struct!(crate::clause::GroupBy(0));
+owned_struct!(crate::clause::Having(0));
+owned_struct!(crate::clause::Window(0));
+owned_struct!(crate::clause::OrderBy(0));
+owned_struct!(crate::clause::Insert(0, 1));
+owned_struct!(crate::clause::Values(0));
+owned_struct!(crate::clause::Returning(0));
+owned_struct!(crate::clause::Delete(0));
+owned_struct!(crate::clause::Update(0));
+owned_struct!(crate::clause::OnConflict(0, 1, 2));
+owned_struct!(crate::clause::Set(0));
+owned_struct!(crate::clause::Limit(0));
+owned_struct!(crate::clause::Offset(0));
+owned_struct!(crate::clause::Lock(0, 1, 2));
+
+// statements
+owned_struct!(crate::stmt::select::Select {
+    with,
+    distinct,
+    fields,
+    tables,
+    filter,
+    groups,
+    having,
+    windows,
+    orders,
+});
+owned_struct!(crate::stmt::insert::Insert {
+    with,
+    table,
+    values,
+    defaults,
+    on_duplicate,
+    on_conflict,
+    returns,
+});
+owned_struct!(crate::stmt::update::Update {
+    with,
+    table,
+    set,
+    from,
+    filter,
+    returns,
+});
+owned_struct!(crate::stmt::delete::Delete {
+    with,
+    table,
+    using,
+    filter,
+    returns,
+});
+owned_struct!(crate::stmt::values::Values { with, rows });
+owned_struct!(crate::stmt::binary::Binary {
+    with,
+    left,
+    op,
+    right,
+});
+owned_struct!(crate::stmt::result::Result {
+    with,
+    data,
+    orders,
+    limit,
+    offset,
+    lock,
+});
+owned_struct!(crate::stmt::explain::Explain {
+    analyze,
+    options,
+    stmt,
+});
+owned_enum!(crate::stmt::data::Data {
+    Select(stmt),
+    Values(stmt),
+    Binary(stmt),
+});
+owned_enum!(crate::stmt::Stmt {
+    Insert(stmt),
+    Select(stmt),
+    Update(stmt),
+    Delete(stmt),
+    Values(stmt),
+    Binary(stmt),
+    Result(stmt),
+    Explain(stmt),
+});
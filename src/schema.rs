@@ -2,8 +2,57 @@
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
 pub use xql_derive::Schema;
 
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use xql_derive::Columns;
+
 pub trait Schema<const N: usize> {
     fn table() -> crate::item::TableRef<'static>;
 
     fn columns() -> [crate::item::ColumnRef<'static>; N];
 }
+
+/// Map a struct into the columns and a row of an `INSERT` statement.
+///
+/// With `derive` feature, it can be derived. Fields become columns of the same
+/// name, unless renamed with `#[column(rename = "...")]` or skipped with
+/// `#[column(skip)]`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "derive")]
+/// # fn main() {
+/// use qians_xql::insert;
+/// use qians_xql::schema::Columns;
+///
+/// #[derive(Columns)]
+/// struct User {
+///     id: i32,
+///     #[column(rename = "user_name")]
+///     name: String,
+///     #[column(skip)]
+///     session: Vec<u8>,
+/// }
+///
+/// let user = User {
+///     id: 1,
+///     name: "alice".to_string(),
+///     session: Vec::new(),
+/// };
+/// let query = insert("user", User::column_names()).values([user.as_row()]);
+/// assert_eq!(
+///     query.to_string(),
+///     "INSERT INTO user(id, user_name) VALUES (1, 'alice')",
+/// );
+/// # }
+/// # #[cfg(not(feature = "derive"))]
+/// # fn main() {}
+/// ```
+pub trait Columns<const N: usize> {
+    /// Names of the columns, in the same order as [`as_row`](Columns::as_row).
+    fn column_names() -> [crate::item::Ident<'static>; N];
+
+    /// Values of the columns.
+    fn as_row(&self) -> crate::item::Row<'_>;
+}
@@ -1,4 +1,5 @@
 use crate::clause;
+use crate::item::Keyword;
 use crate::stmt::result::Result;
 
 /// Binary statement builder.
@@ -6,16 +7,93 @@ use crate::stmt::result::Result;
 /// Binary statement is two statement combined with `UNION [ALL]`, `EXCEPT
 /// [ALL]` or `INTERSECT [ALL]`.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 pub struct Binary<'a> {
     pub(crate) with: Option<clause::With<'a>>,
     pub(crate) left: Box<Result<'a>>,
-    pub(crate) op: &'static str,
+    pub(crate) op: Keyword,
     pub(crate) right: Box<Result<'a>>,
 }
 
 stmt_common!(Binary);
 crate::macros::gen_display!(Binary<'_>);
 
+impl<'a> Binary<'a> {
+    binary_methods!(union union_all intersect intersect_all except except_all);
+
+    /// Set `ORDER BY` clause of the whole statement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    /// use qians_xql::desc;
+    ///
+    /// let query = select(["id"]).from("book")
+    ///     .union(select(["id"]).from("film"))
+    ///     .order_by([desc("id")]);
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "SELECT id FROM book UNION SELECT id FROM film ORDER BY id DESC",
+    /// );
+    /// ```
+    pub fn order_by<O>(self, orders: O) -> Result<'a>
+    where
+        O: Into<clause::OrderBy<'a>>,
+    {
+        Result {
+            data: self.into(),
+            orders: Some(orders.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn pagination<L, O>(self, limit: L, offset: O) -> Result<'a>
+    where
+        L: Into<clause::Limit<'a>>,
+        O: Into<clause::Offset<'a>>,
+    {
+        Result {
+            data: self.into(),
+            limit: Some(limit.into()),
+            offset: Some(offset.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn limit<L>(self, limit: L) -> Result<'a>
+    where
+        L: Into<clause::Limit<'a>>,
+    {
+        Result {
+            data: self.into(),
+            limit: Some(limit.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn limit_all(self) -> Result<'a> {
+        Result {
+            data: self.into(),
+            limit: Some(clause::Limit(None)),
+            ..Default::default()
+        }
+    }
+
+    pub fn offset<O>(self, offset: O) -> Result<'a>
+    where
+        O: Into<clause::Offset<'a>>,
+    {
+        Result {
+            data: self.into(),
+            offset: Some(offset.into()),
+            ..Default::default()
+        }
+    }
+}
+
 #[test]
 #[cfg(test)]
 fn test() {
@@ -33,4 +111,37 @@ fn test() {
     assert_eq!(query.to_string(), "SELECT 1 INTERSECT SELECT 2");
     let query = stmt::intersect_all(stmt::select([1]), stmt::select([2]));
     assert_eq!(query.to_string(), "SELECT 1 INTERSECT ALL SELECT 2");
+
+    let query = stmt::select([1])
+        .union(stmt::select([2]))
+        .union_all(stmt::select([3]));
+    assert_eq!(query.to_string(), "SELECT 1 UNION SELECT 2 UNION ALL SELECT 3");
+
+    let query = stmt::select([1]).union(stmt::select([2]).intersect(stmt::select([3])));
+    assert_eq!(query.to_string(), "SELECT 1 UNION SELECT 2 INTERSECT SELECT 3");
+    let query = stmt::select([1])
+        .union(stmt::select([2]))
+        .intersect(stmt::select([3]));
+    assert_eq!(query.to_string(), "(SELECT 1 UNION SELECT 2) INTERSECT SELECT 3");
+    let query = stmt::select([1])
+        .intersect_all(stmt::select([2]))
+        .except_all(stmt::select([3]));
+    assert_eq!(query.to_string(), "SELECT 1 INTERSECT ALL SELECT 2 EXCEPT ALL SELECT 3");
+    let query = stmt::select([1]).except(stmt::select([2]).union(stmt::select([3])));
+    assert_eq!(query.to_string(), "SELECT 1 EXCEPT (SELECT 2 UNION SELECT 3)");
+    let query = stmt::select([1])
+        .limit(1)
+        .union(stmt::select(["id"]).from("a").order_by(["id"]));
+    assert_eq!(query.to_string(), "(SELECT 1 LIMIT 1) UNION (SELECT id FROM a ORDER BY id)");
+
+    let query = stmt::select(["id"])
+        .from("a")
+        .union(stmt::select(["id"]).from("b"))
+        .order_by(["id"])
+        .limit(10)
+        .offset(5);
+    assert_eq!(
+        query.to_string(),
+        "SELECT id FROM a UNION SELECT id FROM b ORDER BY id LIMIT 10 OFFSET 5"
+    );
 }
@@ -2,6 +2,8 @@ use crate::clause;
 use crate::item::Row;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 pub enum Data<'a> {
     Select(Box<crate::stmt::select::Select<'a>>),
     Values(crate::stmt::values::Values<'a>),
@@ -51,6 +53,13 @@ impl<'a> std::convert::From<crate::stmt::select::Select<'a>> for Data<'a> {
     }
 }
 
+impl<'a> std::convert::From<crate::stmt::binary::Binary<'a>> for Data<'a> {
+    #[inline]
+    fn from(val: crate::stmt::binary::Binary<'a>) -> Self {
+        Data::Binary(val)
+    }
+}
+
 impl<'a> std::convert::From<Box<crate::stmt::select::Select<'a>>> for Data<'a> {
     #[inline]
     fn from(val: Box<crate::stmt::select::Select<'a>>) -> Self {
@@ -4,9 +4,12 @@ use crate::ops::and;
 
 /// `DELETE` statement builder.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 pub struct Delete<'a> {
     pub(crate) with: Option<clause::With<'a>>,
     pub(crate) table: clause::Delete<'a>,
+    pub(crate) using: Option<clause::Using<'a>>,
     pub(crate) filter: Option<clause::Where<'a>>,
     pub(crate) returns: Option<clause::Returning<'a>>,
 }
@@ -16,6 +19,39 @@ stmt_common!(Delete);
 crate::macros::gen_display!(Delete<'_>);
 
 impl<'a> Delete<'a> {
+    /// Set/Add table(s) to `USING` clause.
+    ///
+    /// Successive calls add more tables into the clause.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::delete;
+    /// use qians_xql::eq;
+    ///
+    /// let query = delete("book")
+    ///     .using(["author"])
+    ///     .filter(eq(("book", "author_id"), ("author", "id")));
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "DELETE FROM book USING author WHERE book.author_id = author.id",
+    /// );
+    /// ```
+    pub fn using<T>(mut self, tables: T) -> Delete<'a>
+    where
+        T: Into<clause::Using<'a>>,
+    {
+        self.using = match self.using.take() {
+            Some(mut inner) => {
+                inner.0.extend(tables.into().0);
+                Some(inner)
+            }
+            None => Some(tables.into()),
+        };
+        self
+    }
+
     /// Set condition to `WHERE` clause.
     ///
     /// Successive calls combine new condition with previous condition with
@@ -48,6 +84,37 @@ impl<'a> Delete<'a> {
         self
     }
 
+    /// Set condition to `WHERE` clause, combining it with previous condition
+    /// with [`or`](crate::ops::or).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::delete;
+    /// use qians_xql::eq;
+    /// use qians_xql::lt;
+    ///
+    /// let query = delete("session")
+    ///     .filter(eq("active", false))
+    ///     .filter(lt("expires", 100))
+    ///     .filter_or(eq("revoked", true));
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "DELETE FROM session WHERE (active = false AND expires < 100) OR revoked = true",
+    /// );
+    /// ```
+    pub fn filter_or<E>(mut self, expr: E) -> Delete<'a>
+    where
+        E: Into<Expr<'a>>,
+    {
+        self.filter = match self.filter.take() {
+            Some(inner) => Some(crate::ops::or_grouped(inner.0, expr.into()).into()),
+            None => Some(expr.into().into()),
+        };
+        self
+    }
+
     /// Set/Add field(s) to `RETURNING` clause.
     ///
     /// Successive calls combine adds more field into the clause.
@@ -65,6 +132,7 @@ impl<'a> Delete<'a> {
     ///     .returning(["name"]);
     ///
     /// assert_eq!(query1, query2);
+    /// assert_eq!(query1.to_string(), "DELETE FROM book RETURNING id, name");
     /// ```
     pub fn returning<T>(mut self, returns: T) -> Delete<'a>
     where
@@ -79,6 +147,21 @@ impl<'a> Delete<'a> {
         };
         self
     }
+
+    /// Add `*` to `RETURNING` clause, returning all columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::delete;
+    ///
+    /// let query = delete("book").returning_all();
+    /// assert_eq!(query.to_string(), "DELETE FROM book RETURNING *");
+    /// ```
+    #[inline]
+    pub fn returning_all(self) -> Delete<'a> {
+        self.returning([crate::ops::star()])
+    }
 }
 
 #[test]
@@ -97,3 +180,54 @@ fn test() {
         "DELETE FROM user WHERE NOT user.active AND user.name ISNULL RETURNING id, name"
     );
 }
+
+#[test]
+#[cfg(test)]
+fn using() {
+    use crate::ops;
+
+    let query = crate::delete("order")
+        .using(ops::as_table("user", "u"))
+        .using(["shop"])
+        .filter(ops::eq(("order", "user_id"), ("u", "id")))
+        .filter(ops::eq(("u", "shop_id"), ("shop", "id")))
+        .filter(ops::eq(("shop", "closed"), true));
+    assert_eq!(
+        query.to_string(),
+        "DELETE FROM order USING user AS u, shop WHERE order.user_id = u.id AND u.shop_id = shop.id AND shop.closed = true"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn filter_or() {
+    use crate::ops;
+
+    let query = crate::delete("user")
+        .filter_or(ops::eq("id", 1))
+        .filter_or(ops::eq("id", 2));
+    assert_eq!(query.to_string(), "DELETE FROM user WHERE id = 1 OR id = 2");
+
+    let query = crate::delete("user")
+        .filter(ops::eq("a", 1))
+        .filter(ops::eq("b", 2))
+        .filter_or(ops::and(ops::eq("c", 3), ops::eq("d", 4)));
+    assert_eq!(
+        query.to_string(),
+        "DELETE FROM user WHERE (a = 1 AND b = 2) OR (c = 3 AND d = 4)"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn returning_all() {
+    use crate::dialect::Postgres;
+
+    let query = crate::delete("user")
+        .filter(crate::ops::eq("id", 1))
+        .returning_all();
+    assert_eq!(
+        query.to_sql(Postgres).0,
+        r#"DELETE FROM "user" WHERE "id" = $1 RETURNING *"#
+    );
+}
@@ -0,0 +1,178 @@
+use std::borrow::Cow;
+
+use crate::stmt::Stmt;
+
+/// `EXPLAIN` prefix of a statement, see [`explain`](crate::stmt::explain).
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
+pub struct Explain<'a> {
+    pub(crate) analyze: bool,
+    pub(crate) options: Vec<(Cow<'a, str>, Option<Cow<'a, str>>)>,
+    pub(crate) stmt: Box<Stmt<'a>>,
+}
+
+stmt_common!(@render Explain);
+
+crate::macros::gen_display!(Explain<'_>);
+
+impl<'a> Explain<'a> {
+    /// Add an option, e.g. `FORMAT JSON` or `VERBOSE`.
+    ///
+    /// The name and value are written as is, so they must be bare keywords
+    /// made of ASCII letters, digits and underscores.
+    ///
+    /// Along with any option, `ANALYZE` is written as an option too, as
+    /// Postgres requires.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::explain;
+    /// use qians_xql::explain_analyze;
+    /// use qians_xql::select;
+    ///
+    /// let query = explain(select(["id"]).from("book")).option("FORMAT", "JSON");
+    /// assert_eq!(query.to_string(), "EXPLAIN (FORMAT JSON) SELECT id FROM book");
+    ///
+    /// let query = explain_analyze(select(["id"]).from("book"))
+    ///     .option("VERBOSE", None)
+    ///     .option("BUFFERS", "OFF");
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "EXPLAIN (ANALYZE, VERBOSE, BUFFERS OFF) SELECT id FROM book",
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name or the value is not a bare keyword.
+    pub fn option<V>(mut self, name: &'a str, value: V) -> Explain<'a>
+    where
+        V: Into<Option<&'a str>>,
+    {
+        fn keyword(val: &str) -> bool {
+            !val.is_empty()
+                && !val.starts_with(|c: char| c.is_ascii_digit())
+                && val.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+
+        let value = value.into();
+        assert!(keyword(name), "invalid `EXPLAIN` option `{name}`");
+        if let Some(value) = value {
+            assert!(
+                keyword(value),
+                "invalid value `{value}` of `EXPLAIN` option `{name}`"
+            );
+        }
+        self.options.push((name.into(), value.map(Into::into)));
+        self
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn explain_prefix() {
+    use crate::dialect::Postgres;
+    use crate::ops::eq;
+    use crate::stmt::delete;
+    use crate::stmt::select;
+    use crate::value::Value;
+
+    let query = crate::stmt::explain(select(["id"]).from("user").filter(eq("id", 1)));
+    assert_eq!(
+        query.to_string(),
+        "EXPLAIN SELECT id FROM user WHERE id = 1"
+    );
+    assert_eq!(
+        query.to_sql(Postgres),
+        (
+            r#"EXPLAIN SELECT "id" FROM "user" WHERE "id" = $1"#.to_string(),
+            vec![Value::Int(1)]
+        )
+    );
+
+    let query = crate::stmt::explain_analyze(delete("user").filter(eq("id", 1)));
+    assert_eq!(
+        query.to_string(),
+        "EXPLAIN ANALYZE DELETE FROM user WHERE id = 1"
+    );
+
+    let query: Stmt = crate::stmt::explain(select(["id"]).from("user")).into();
+    assert_eq!(
+        query.to_sql(Postgres).0,
+        r#"EXPLAIN SELECT "id" FROM "user""#
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn options() {
+    use crate::stmt::select;
+
+    let query = crate::stmt::explain(select(["id"]).from("user"))
+        .option("VERBOSE", None)
+        .option("FORMAT", "JSON");
+    assert_eq!(
+        query.to_string(),
+        "EXPLAIN (VERBOSE, FORMAT JSON) SELECT id FROM user"
+    );
+
+    let query = crate::stmt::explain_analyze(select(["id"]).from("user")).option("FORMAT", "JSON");
+    assert_eq!(
+        query.to_string(),
+        "EXPLAIN (ANALYZE, FORMAT JSON) SELECT id FROM user"
+    );
+    assert_eq!(
+        format!("{query:#}"),
+        "EXPLAIN (ANALYZE, FORMAT JSON)\nSELECT id\nFROM user"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn render() {
+    use crate::dialect::Postgres;
+    use crate::dialect::RenderMode;
+    use crate::ops::eq;
+    use crate::stmt::select;
+    use crate::value::Value;
+
+    let query = crate::stmt::explain(select(["id"]).from("user").filter(eq("id", 1)));
+    assert_eq!(query.params(Postgres), [Value::Int(1)]);
+    assert_eq!(
+        query.to_sql_with(Postgres, RenderMode::Inline).0,
+        r#"EXPLAIN SELECT "id" FROM "user" WHERE "id" = 1"#
+    );
+    assert_eq!(query.clone().into_owned(), query);
+}
+
+#[test]
+#[cfg(test)]
+#[should_panic(expected = "`EXPLAIN` can't be explained")]
+fn explain_explain() {
+    use crate::stmt::select;
+
+    crate::stmt::explain(crate::stmt::explain(select([1])));
+}
+
+#[test]
+#[cfg(test)]
+#[should_panic(
+    expected = "invalid value `JSON) SELECT 1; DROP TABLE x; --` of `EXPLAIN` option `FORMAT`"
+)]
+fn invalid_option_value() {
+    use crate::stmt::select;
+
+    let _ = crate::stmt::explain(select(["id"]).from("user"))
+        .option("FORMAT", "JSON) SELECT 1; DROP TABLE x; --");
+}
+
+#[test]
+#[cfg(test)]
+#[should_panic(expected = "invalid `EXPLAIN` option `VERBOSE) SELECT 1; --`")]
+fn invalid_option_name() {
+    use crate::stmt::select;
+
+    let _ = crate::stmt::explain(select(["id"]).from("user")).option("VERBOSE) SELECT 1; --", None);
+}
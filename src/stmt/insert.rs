@@ -1,41 +1,282 @@
 use crate::clause;
+use crate::expr::Expr;
+use crate::item::Ident;
 use crate::item::Row;
 
 /// `INSERT` statement builder.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 pub struct Insert<'a> {
     pub(crate) with: Option<clause::With<'a>>,
     pub(crate) table: clause::Insert<'a>,
     pub(crate) values: crate::stmt::data::Data<'a>,
+    pub(crate) defaults: bool,
+    pub(crate) on_duplicate: Option<clause::Set<'a>>,
+    pub(crate) on_conflict: Option<clause::OnConflict<'a>>,
     pub(crate) returns: Option<clause::Returning<'a>>,
 }
 
 stmt_common!(Insert);
 
 impl<'a> Insert<'a> {
+    /// Insert rows from a `VALUES` clause, replacing any `SELECT` source.
     pub fn values<I, R>(mut self, values: I) -> Insert<'a>
     where
         R: Into<Row<'a>>,
         I: IntoIterator<Item = R>,
     {
+        self.assert_no_defaults("values");
         self.values = match self.values {
             crate::stmt::data::Data::Values(mut inner) => {
                 inner.rows.0.extend(values.into_iter().map(Into::into));
                 crate::stmt::data::Data::Values(inner)
             }
-            _ => unreachable!(),
+            _ => crate::stmt::values(values).into(),
         };
         self
     }
 
+    /// Insert a row out of each struct as a single multi-row `VALUES`, taking
+    /// the columns from the struct unless they were already given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "derive")]
+    /// # fn main() {
+    /// use qians_xql::insert_into;
+    /// use qians_xql::schema::Columns;
+    ///
+    /// #[derive(Columns)]
+    /// struct User {
+    ///     id: i32,
+    ///     name: String,
+    /// }
+    ///
+    /// let users = vec![
+    ///     User { id: 1, name: "alice".to_string() },
+    ///     User { id: 2, name: "bob".to_string() },
+    /// ];
+    /// let query = insert_into("user").values_from(&users);
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "INSERT INTO user(id, name) VALUES (1, 'alice'), (2, 'bob')",
+    /// );
+    /// # }
+    /// # #[cfg(not(feature = "derive"))]
+    /// # fn main() {}
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no rows, as an empty `VALUES` is invalid.
+    pub fn values_from<T, I, const N: usize>(mut self, rows: I) -> Insert<'a>
+    where
+        T: crate::schema::Columns<N> + 'a,
+        I: IntoIterator<Item = &'a T>,
+    {
+        let mut rows = rows.into_iter().map(T::as_row).peekable();
+        assert!(
+            rows.peek().is_some(),
+            "`Insert::values_from` requires at least one row"
+        );
+        if self.table.1.is_empty() {
+            self.table.1 = T::column_names().into();
+        }
+        self.values(rows)
+    }
+
+    /// Insert rows returned by a query, replacing any `VALUES` source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::insert;
+    /// use qians_xql::select;
+    ///
+    /// let query = insert("archive", ["id", "name"]).select(select(["id", "name"]).from("book"));
+    /// assert_eq!(query.to_string(), "INSERT INTO archive(id, name) SELECT id, name FROM book");
+    /// ```
     pub fn select<T>(mut self, select: T) -> Insert<'a>
     where
-        T: Into<crate::stmt::select::Select<'a>>,
+        T: Into<crate::stmt::data::Data<'a>>,
+    {
+        self.assert_no_defaults("select");
+        self.values = select.into();
+        self
+    }
+
+    /// Insert a single row filled with the default value of each column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::insert;
+    ///
+    /// let query = insert("log", None::<&str>).default_values().returning(["id"]);
+    /// assert_eq!(query.to_string(), "INSERT INTO log DEFAULT VALUES RETURNING id");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if rows were already given by [`values`](Insert::values) or
+    /// [`select`](Insert::select).
+    pub fn default_values(mut self) -> Insert<'a> {
+        assert!(
+            self.values == crate::stmt::data::Data::default(),
+            "`Insert::default_values` can not be mixed with explicit values"
+        );
+        self.defaults = true;
+        self
+    }
+
+    /// Set columns to update when the row conflicts with an existing one, only
+    /// rendered for MySQL.
+    ///
+    /// Successive calls extend the list of updated columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::insert;
+    /// use qians_xql::ops::values_ref;
+    ///
+    /// let name = "John".to_string();
+    /// let query = insert("user", ["id", "name"])
+    ///     .values([(1, &name)])
+    ///     .on_duplicate_key_update([("name", values_ref("name"))]);
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "INSERT INTO user(id, name) VALUES (1, 'John') ON DUPLICATE KEY UPDATE name = VALUES (name)",
+    /// );
+    /// ```
+    pub fn on_duplicate_key_update<V>(mut self, values: V) -> Insert<'a>
+    where
+        V: Into<clause::Set<'a>>,
+    {
+        self.on_duplicate = match self.on_duplicate.take() {
+            Some(mut inner) => {
+                inner.0.extend(values.into().0);
+                Some(inner)
+            }
+            None => Some(values.into()),
+        };
+        self
+    }
+
+    /// Set `ON CONFLICT` clause on the `target` column(s), doing nothing
+    /// unless [`do_update`](Insert::do_update) is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::insert;
+    ///
+    /// let query = insert("user", ["id"]).values([(1,)]).on_conflict(["id"]);
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "INSERT INTO user(id) VALUES (1) ON CONFLICT (id) DO NOTHING",
+    /// );
+    /// ```
+    pub fn on_conflict<C, I>(mut self, target: I) -> Insert<'a>
+    where
+        C: Into<Ident<'a>>,
+        I: IntoIterator<Item = C>,
+    {
+        let on_conflict = self.on_conflict.get_or_insert_with(Default::default);
+        on_conflict.0 = target.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the predicate of a partial unique index to `ON CONFLICT` clause.
+    ///
+    /// Successive calls combine new predicate with previous predicate with
+    /// [`and`](crate::ops::and).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::insert;
+    ///
+    /// let email = "a@b.c".to_string();
+    /// let query = insert("user", ["email"])
+    ///     .values([(&email,)])
+    ///     .on_conflict(["email"])
+    ///     .conflict_where("active");
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "INSERT INTO user(email) VALUES ('a@b.c') ON CONFLICT (email) WHERE active DO NOTHING",
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`on_conflict`](Insert::on_conflict) was not called.
+    pub fn conflict_where<E>(mut self, predicate: E) -> Insert<'a>
+    where
+        E: Into<Expr<'a>>,
+    {
+        let on_conflict = self.on_conflict_mut("conflict_where");
+        on_conflict.1 = Some(match on_conflict.1.take() {
+            Some(prev) => crate::ops::and(prev, predicate),
+            None => predicate.into(),
+        });
+        self
+    }
+
+    /// Set columns to update in `ON CONFLICT` clause instead of doing nothing.
+    ///
+    /// Successive calls extend the list of updated columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::add;
+    /// use qians_xql::insert;
+    ///
+    /// let query = insert("stock", ["id", "amount"])
+    ///     .values([(1, 10)])
+    ///     .on_conflict(["id"])
+    ///     .do_update([("amount", add("amount", 10))]);
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "INSERT INTO stock(id, amount) VALUES (1, 10) ON CONFLICT (id) DO UPDATE SET amount = amount + 10",
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`on_conflict`](Insert::on_conflict) was not called.
+    pub fn do_update<V>(mut self, values: V) -> Insert<'a>
+    where
+        V: Into<clause::Set<'a>>,
     {
-        self.values = crate::stmt::data::Data::Select(Box::new(select.into()));
+        let on_conflict = self.on_conflict_mut("do_update");
+        on_conflict.2 = match on_conflict.2.take() {
+            Some(mut inner) => {
+                inner.0.extend(values.into().0);
+                Some(inner)
+            }
+            None => Some(values.into()),
+        };
         self
     }
 
+    fn on_conflict_mut(&mut self, method: &str) -> &mut clause::OnConflict<'a> {
+        match self.on_conflict.as_mut() {
+            Some(on_conflict) => on_conflict,
+            None => panic!("`Insert::{method}` requires `ON CONFLICT`"),
+        }
+    }
+
+    fn assert_no_defaults(&self, method: &str) {
+        assert!(
+            !self.defaults,
+            "`Insert::{method}` can not be mixed with `DEFAULT VALUES`"
+        );
+    }
+
     pub fn returning<T>(mut self, returns: T) -> Insert<'a>
     where
         T: Into<clause::Returning<'a>>,
@@ -49,6 +290,21 @@ impl<'a> Insert<'a> {
         };
         self
     }
+
+    /// Add `*` to `RETURNING` clause, returning all columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::insert;
+    ///
+    /// let query = insert("book", ["id"]).values([(1,)]).returning_all();
+    /// assert_eq!(query.to_string(), "INSERT INTO book(id) VALUES (1) RETURNING *");
+    /// ```
+    #[inline]
+    pub fn returning_all(self) -> Insert<'a> {
+        self.returning([crate::ops::star()])
+    }
 }
 
 crate::macros::gen_display!(Insert<'_>);
@@ -76,3 +332,144 @@ fn test() {
         "INSERT INTO user(id, name) SELECT 1, 'name' RETURNING id, name"
     );
 }
+
+#[test]
+#[cfg(test)]
+fn insert_select() {
+    use crate::ops::eq;
+    use crate::stmt::select;
+
+    let query = crate::stmt::insert("user_archive", ["id", "name"]).select(
+        select(["id", "name"])
+            .from("user")
+            .filter(eq("active", false)),
+    );
+    assert_eq!(
+        query.to_string(),
+        "INSERT INTO user_archive(id, name) SELECT id, name FROM user WHERE active = false"
+    );
+
+    let query = crate::stmt::insert("user_archive", None::<&str>).select(
+        select(["*"])
+            .from("user")
+            .union(select(["*"]).from("guest")),
+    );
+    assert_eq!(
+        query.to_string(),
+        "INSERT INTO user_archive SELECT * FROM user UNION SELECT * FROM guest"
+    );
+
+    let query = crate::stmt::insert("user", ["id"])
+        .select(select([1]))
+        .values([(2,)]);
+    assert_eq!(query.to_string(), "INSERT INTO user(id) VALUES (2)");
+}
+
+#[test]
+#[cfg(test)]
+fn runtime_rows() {
+    use crate::expr::Expr;
+    use crate::item::Row;
+
+    let name = "John".to_string();
+    let mut columns = vec!["id"];
+    let mut row: Vec<Expr> = vec![1.into()];
+    columns.push("name");
+    row.push((&name).into());
+
+    let other: Row = [Expr::from(2), (&name).into()].into_iter().collect();
+    let query = crate::stmt::insert("user", columns).values([Row::from(row), other]);
+    assert_eq!(
+        query.to_string(),
+        "INSERT INTO user(id, name) VALUES (1, 'John'), (2, 'John')"
+    );
+}
+
+#[test]
+#[cfg(test)]
+#[should_panic(expected = "`Insert::values` can not be mixed with `DEFAULT VALUES`")]
+fn default_values_mixed() {
+    crate::stmt::insert("user", ["id"])
+        .default_values()
+        .values([(1,)]);
+}
+
+#[test]
+#[cfg(test)]
+fn on_duplicate_key_update() {
+    use crate::ops::add;
+    use crate::ops::values_ref;
+
+    let query = crate::stmt::insert("stock", ["id", "amount"])
+        .values([(1, 10)])
+        .on_duplicate_key_update([("amount", add("amount", values_ref("amount")))]);
+    assert_eq!(
+        query.clone().to_string(),
+        "INSERT INTO stock(id, amount) VALUES (1, 10) ON DUPLICATE KEY UPDATE amount = amount + VALUES (amount)"
+    );
+
+    struct Ansi;
+    impl crate::build::Dialect for Ansi {
+        fn quote_literal(val: &str, buff: &mut String) {
+            crate::build::Display::quote_literal(val, buff)
+        }
+        fn quote_ident(name: &str, buff: &mut String) {
+            crate::build::Display::quote_ident(name, buff)
+        }
+        fn placeholder(_: usize, _: &mut String) {}
+        fn bind_param<'a>(
+            n: usize,
+            val: crate::value::Value<'a>,
+            buff: &mut String,
+        ) -> crate::value::Value<'a> {
+            crate::build::Display::bind_param(n, val, buff)
+        }
+    }
+    let (sql, _) = query.to_sql(Ansi);
+    assert_eq!(sql, "INSERT INTO stock(id, amount) VALUES (1, 10)");
+}
+
+#[test]
+#[cfg(test)]
+fn on_conflict() {
+    use crate::dialect::Postgres;
+    use crate::ops::eq;
+    use crate::ops::is_null;
+
+    let email = "a@b.c".to_string();
+    let query = crate::stmt::insert("user", ["email", "name"])
+        .values([(&email, &email)])
+        .on_conflict(["email"])
+        .conflict_where(eq("active", true))
+        .conflict_where(is_null("deleted_at"))
+        .do_update([("name", crate::ops::excluded("name"))]);
+    assert_eq!(
+        query.clone().to_string(),
+        "INSERT INTO user(email, name) VALUES ('a@b.c', 'a@b.c') \
+        ON CONFLICT (email) WHERE active = true AND deleted_at IS NULL \
+        DO UPDATE SET name = EXCLUDED.name"
+    );
+    let (sql, args) = query.to_sql(Postgres);
+    assert_eq!(
+        sql,
+        r#"INSERT INTO "user"("email", "name") VALUES ($1, $2) ON CONFLICT ("email") WHERE "active" = $3 AND "deleted_at" IS NULL DO UPDATE SET "name" = EXCLUDED."name""#
+    );
+    assert_eq!(args.len(), 3);
+
+    let query = crate::stmt::insert("user", ["id"])
+        .values([(1,)])
+        .on_conflict(None::<&str>);
+    assert_eq!(
+        query.to_string(),
+        "INSERT INTO user(id) VALUES (1) ON CONFLICT DO NOTHING"
+    );
+}
+
+#[test]
+#[cfg(test)]
+#[should_panic(expected = "`Insert::do_update` requires `ON CONFLICT`")]
+fn do_update_without_on_conflict() {
+    crate::stmt::insert("user", ["id"])
+        .values([(1,)])
+        .do_update([("id", 2)]);
+}
@@ -4,7 +4,8 @@ use crate::item::Row;
 use crate::item::TableRef;
 
 macro_rules! stmt_common {
-    ($stmt:ident) => {
+    // conversion and rendering, for statements without a `WITH` clause
+    (@render $stmt:ident) => {
         impl<'a> std::convert::From<$stmt<'a>> for $crate::stmt::Stmt<'a> {
             #[inline]
             fn from(val: $stmt<'a>) -> Self {
@@ -12,36 +13,70 @@ macro_rules! stmt_common {
             }
         }
 
+        impl<'a> $stmt<'a> {
+            /// Render the statement in the given [`Dialect`](crate::dialect::Dialect),
+            /// returning the SQL with the values bound to its placeholders.
+            ///
+            /// This is the entry point for running the statement with a driver.
+            #[inline]
+            pub fn to_sql<D>(&self, _: D) -> (String, Vec<$crate::value::Value<'a>>)
+            where
+                D: $crate::dialect::Dialect,
+            {
+                $crate::build::ToSql::to_sql::<D>(self)
+            }
+
+            /// Collect the values the statement binds in the given
+            /// [`Dialect`](crate::dialect::Dialect), in the order of their
+            /// placeholders, without keeping the SQL.
+            #[inline]
+            pub fn params<D>(&self, _: D) -> Vec<$crate::value::Value<'a>>
+            where
+                D: $crate::dialect::Dialect,
+            {
+                $crate::build::ToSql::params::<D>(self)
+            }
+
+            /// Render the statement in the given [`Dialect`](crate::dialect::Dialect),
+            /// binding values to placeholders or writing them inline depending
+            /// on `mode`. See [`RenderMode`](crate::dialect::RenderMode).
+            #[inline]
+            pub fn to_sql_with<D>(
+                &self,
+                _: D,
+                mode: $crate::dialect::RenderMode,
+            ) -> (String, Vec<$crate::value::Value<'a>>)
+            where
+                D: $crate::dialect::Dialect,
+            {
+                $crate::build::ToSql::render::<D>(self, mode)
+            }
+
+            /// Convert every borrowed identifier and value into an owned one,
+            /// so that the statement can outlive what it was built from.
+            #[inline]
+            pub fn into_owned(self) -> $stmt<'static> {
+                $crate::owned::IntoOwned::into_owned(self)
+            }
+        }
+    };
+
+    ($stmt:ident) => {
+        stmt_common!(@render $stmt);
+
         impl<'a> $stmt<'a> {
             /// Add [`With`](crate::clause::With) clause to the statement.
             ///
             /// ```sql
             /// WITH name AS (stmt) ...
             /// ```
-            pub fn with<N, S>(mut self, name: N, stmt: S) -> $stmt<'a>
+            #[inline]
+            pub fn with<N, S>(self, name: N, stmt: S) -> $stmt<'a>
             where
                 N: Into<$crate::item::Ident<'a>>,
                 S: Into<$crate::stmt::Stmt<'a>>,
             {
-                self.with = match self.with.take() {
-                    Some(mut with) => {
-                        with.1.push($crate::item::Cte {
-                            name: name.into(),
-                            columns: Vec::new(),
-                            stmt: stmt.into(),
-                        });
-                        Some(with)
-                    }
-                    None => Some(
-                        [$crate::item::Cte {
-                            name: name.into(),
-                            columns: Vec::new(),
-                            stmt: stmt.into(),
-                        }]
-                        .into(),
-                    ),
-                };
-                self
+                self.with_cte($crate::item::Cte::new(name, stmt))
             }
 
             /// Add [`With`](crate::clause::With) clause to the statement.
@@ -49,34 +84,85 @@ macro_rules! stmt_common {
             /// ```sql
             /// WITH name(fields ...) AS (stmt) ...
             /// ```
-            pub fn with_labeled<N, C, I, S>(mut self, name: N, fields: I, stmt: S) -> $stmt<'a>
+            #[inline]
+            pub fn with_labeled<N, C, I, S>(self, name: N, fields: I, stmt: S) -> $stmt<'a>
             where
                 N: Into<$crate::item::Ident<'a>>,
                 C: Into<$crate::item::Ident<'a>>,
                 I: IntoIterator<Item = C>,
                 S: Into<$crate::stmt::Stmt<'a>>,
             {
+                self.with_cte($crate::item::Cte::new(name, stmt).columns(fields))
+            }
+
+            /// Add a [`Cte`](crate::item::Cte) to [`With`](crate::clause::With)
+            /// clause of the statement.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use qians_xql::ge;
+            /// use qians_xql::select;
+            /// use qians_xql::item::Cte;
+            ///
+            /// let query = select(["name"])
+            ///     .from("adult")
+            ///     .with_cte(Cte::new("adult", select(["id", "name"]).from("user").filter(ge("age", 18))))
+            ///     .with_cte(Cte::new("admin", select(["id"]).from("role")).columns(["user_id"]));
+            ///
+            /// assert_eq!(
+            ///     query.to_string(),
+            ///     "WITH adult AS (SELECT id, name FROM user WHERE age >= 18), \
+            ///     admin(user_id) AS (SELECT id FROM role) \
+            ///     SELECT name FROM adult",
+            /// );
+            /// ```
+            pub fn with_cte(mut self, cte: $crate::item::Cte<'a>) -> $stmt<'a> {
                 self.with = match self.with.take() {
                     Some(mut with) => {
-                        with.1.push($crate::item::Cte {
-                            name: name.into(),
-                            columns: fields.into_iter().map(Into::into).collect(),
-                            stmt: stmt.into(),
-                        });
+                        with.1.push(cte);
                         Some(with)
                     }
-                    None => Some(
-                        [$crate::item::Cte {
-                            name: name.into(),
-                            columns: fields.into_iter().map(Into::into).collect(),
-                            stmt: stmt.into(),
-                        }]
-                        .into(),
-                    ),
+                    None => Some([cte].into()),
                 };
                 self
             }
 
+            /// Add a recursive [`Cte`](crate::item::Cte) to
+            /// [`With`](crate::clause::With) clause of the statement, and turn
+            /// the clause into recursive.
+            ///
+            /// ```sql
+            /// WITH RECURSIVE name(fields ...) AS (base UNION ALL step) ...
+            /// ```
+            ///
+            /// # Panics
+            ///
+            /// Panics if no field is given.
+            pub fn with_recursive<N, C, I, B, S>(
+                self,
+                name: N,
+                fields: I,
+                base: B,
+                step: S,
+            ) -> $stmt<'a>
+            where
+                N: Into<$crate::item::Ident<'a>>,
+                C: Into<$crate::item::Ident<'a>>,
+                I: IntoIterator<Item = C>,
+                B: Into<$crate::stmt::result::Result<'a>>,
+                S: Into<$crate::stmt::result::Result<'a>>,
+            {
+                let cte = $crate::item::Cte::new(name, $crate::stmt::union_all(base, step))
+                    .columns(fields);
+                assert!(
+                    !cte.columns.is_empty(),
+                    "recursive CTE `{}` requires a column list",
+                    cte.name.0
+                );
+                self.with_cte(cte).recursive()
+            }
+
             /// Turns the [`With`](crate::clause::With) into recursive.
             ///
             /// ```sql
@@ -118,9 +204,25 @@ macro_rules! stmt_common {
     };
 }
 
+macro_rules! binary_methods {
+    ($($method:ident)+) => {
+        $(
+            #[doc = concat!("Combine the statement with another one, see [`", stringify!($method), "`](crate::stmt::", stringify!($method), ").")]
+            #[inline]
+            pub fn $method<R>(self, right: R) -> $crate::stmt::binary::Binary<'a>
+            where
+                R: Into<$crate::stmt::result::Result<'a>>,
+            {
+                $crate::stmt::$method(self, right)
+            }
+        )+
+    };
+}
+
 pub mod binary;
 pub mod data;
 pub mod delete;
+pub mod explain;
 pub mod insert;
 pub mod result;
 pub mod select;
@@ -128,6 +230,8 @@ pub mod update;
 pub mod values;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 pub enum Stmt<'a> {
     Insert(insert::Insert<'a>),
     Select(select::Select<'a>),
@@ -136,10 +240,88 @@ pub enum Stmt<'a> {
     Values(values::Values<'a>),
     Binary(binary::Binary<'a>),
     Result(result::Result<'a>),
+    Explain(explain::Explain<'a>),
 }
 
 crate::macros::gen_display!(Stmt<'_>);
 
+impl<'a> Stmt<'a> {
+    /// Render the statement in the given [`Dialect`](crate::dialect::Dialect),
+    /// returning the SQL with the values bound to its placeholders.
+    #[inline]
+    pub fn to_sql<D>(&self, _: D) -> (String, Vec<crate::value::Value<'a>>)
+    where
+        D: crate::dialect::Dialect,
+    {
+        crate::build::ToSql::to_sql::<D>(self)
+    }
+
+    /// Collect the values the statement binds in the given
+    /// [`Dialect`](crate::dialect::Dialect), in the order of their
+    /// placeholders, without keeping the SQL.
+    ///
+    /// The values can change while the SQL stays the same, so a prepared
+    /// statement can be cached by its SQL and run with fresh values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::eq;
+    /// use qians_xql::select;
+    /// use qians_xql::dialect::Postgres;
+    /// use qians_xql::stmt::Stmt;
+    /// use qians_xql::value::Value;
+    ///
+    /// let query: Stmt = select(["id"]).from("user").filter(eq("age", 18)).into();
+    /// assert_eq!(query.params(Postgres), [Value::Int(18)]);
+    /// ```
+    #[inline]
+    pub fn params<D>(&self, _: D) -> Vec<crate::value::Value<'a>>
+    where
+        D: crate::dialect::Dialect,
+    {
+        crate::build::ToSql::params::<D>(self)
+    }
+
+    /// Render the statement in the given [`Dialect`](crate::dialect::Dialect),
+    /// binding values to placeholders or writing them inline depending on
+    /// `mode`. See [`RenderMode`](crate::dialect::RenderMode).
+    #[inline]
+    pub fn to_sql_with<D>(
+        &self,
+        _: D,
+        mode: crate::dialect::RenderMode,
+    ) -> (String, Vec<crate::value::Value<'a>>)
+    where
+        D: crate::dialect::Dialect,
+    {
+        crate::build::ToSql::render::<D>(self, mode)
+    }
+
+    /// Convert every borrowed identifier and value into an owned one, so
+    /// that the statement can outlive what it was built from, e.g. to be
+    /// kept in a struct or sent to another thread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::eq;
+    /// use qians_xql::select;
+    /// use qians_xql::stmt::Stmt;
+    ///
+    /// let query: Stmt<'static> = {
+    ///     let table = String::from("user");
+    ///     let query: Stmt = select(["id"]).from(table.as_str()).filter(eq("age", 18)).into();
+    ///     query.into_owned()
+    /// };
+    /// assert_eq!(query.to_string(), "SELECT id FROM user WHERE age = 18");
+    /// ```
+    #[inline]
+    pub fn into_owned(self) -> Stmt<'static> {
+        crate::owned::IntoOwned::into_owned(self)
+    }
+}
+
 /// Construct a `SELECT` statement.
 ///
 /// # Examples
@@ -163,6 +345,26 @@ where
     }
 }
 
+/// Construct a `SELECT DISTINCT` statement, same as `select(fields).distinct()`.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::select;
+/// use qians_xql::select_distinct;
+///
+/// let query = select_distinct(["city"]).from("user");
+/// assert_eq!(query.to_string(), "SELECT DISTINCT city FROM user");
+/// assert_eq!(query, select(["city"]).from("user").distinct());
+/// ```
+#[inline]
+pub fn select_distinct<'a, F>(fields: F) -> select::Select<'a>
+where
+    F: Into<clause::Select<'a>>,
+{
+    select(fields).distinct()
+}
+
 /// Construct a `VALUES` statement.
 ///
 /// # Examples
@@ -220,6 +422,16 @@ where
     }
 }
 
+/// Construct an `INSERT` statement whose columns are taken from the rows, see
+/// [`Insert::values_from`](insert::Insert::values_from).
+#[inline]
+pub fn insert_into<'a, T>(table: T) -> insert::Insert<'a>
+where
+    T: Into<TableRef<'a>>,
+{
+    insert(table, None::<Ident>)
+}
+
 /// Construct a `DELETE` statement.
 ///
 /// # Examples
@@ -275,6 +487,69 @@ where
     }
 }
 
+/// Prefix a statement with `EXPLAIN`, showing its execution plan.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::explain;
+/// use qians_xql::select;
+///
+/// assert_eq!(
+///     explain(select(["id"]).from("book")).to_string(),
+///     "EXPLAIN SELECT id FROM book",
+/// );
+/// ```
+///
+/// # Panics
+///
+/// Panics if the statement is itself an `EXPLAIN`.
+#[inline]
+pub fn explain<'a, S>(stmt: S) -> explain::Explain<'a>
+where
+    S: Into<Stmt<'a>>,
+{
+    let stmt = stmt.into();
+    assert!(
+        !matches!(stmt, Stmt::Explain(..)),
+        "`EXPLAIN` can't be explained"
+    );
+    explain::Explain {
+        analyze: false,
+        options: Vec::new(),
+        stmt: Box::new(stmt),
+    }
+}
+
+/// Prefix a statement with `EXPLAIN ANALYZE`, which runs it to show its
+/// actual execution plan.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::explain_analyze;
+/// use qians_xql::select;
+///
+/// assert_eq!(
+///     explain_analyze(select(["id"]).from("book")).to_string(),
+///     "EXPLAIN ANALYZE SELECT id FROM book",
+/// );
+/// ```
+///
+/// # Panics
+///
+/// Panics if the statement is itself an `EXPLAIN`.
+#[inline]
+pub fn explain_analyze<'a, S>(stmt: S) -> explain::Explain<'a>
+where
+    S: Into<Stmt<'a>>,
+{
+    explain::Explain {
+        analyze: true,
+        ..explain(stmt)
+    }
+}
+
 macro_rules! generate_binary_funcs {
     ($(#[$comment:meta])* $fn:ident $op:expr) => {
         $(#[$comment])*
@@ -286,7 +561,7 @@ macro_rules! generate_binary_funcs {
         {
             $crate::stmt::binary::Binary {
                 with: None,
-                op: $op,
+                op: ::std::borrow::Cow::Borrowed($op),
                 left: ::std::boxed::Box::new(left.into()),
                 right: ::std::boxed::Box::new(right.into()),
             }
@@ -387,6 +662,78 @@ generate_binary_funcs!(
 
 #[cfg(test)]
 mod tests {
+    #[test]
+    fn params() {
+        use crate::dialect::MySqlLimitComma;
+        use crate::dialect::Postgres;
+        use crate::dialect::SqlServer;
+        use crate::ops::{and, eq, gt};
+        use crate::value::Value;
+
+        let name = "alice".to_string();
+        let query = crate::stmt::select(["id"])
+            .from("user")
+            .filter(and(eq("name", &name), gt("age", 18)))
+            .pagination(10, 20);
+        assert_eq!(
+            query.params(Postgres),
+            [
+                Value::from(&name),
+                Value::Int(18),
+                Value::Int(10),
+                Value::Int(20)
+            ]
+        );
+        // the offset is written before the count
+        assert_eq!(
+            query.params(MySqlLimitComma),
+            [
+                Value::from(&name),
+                Value::Int(18),
+                Value::Int(20),
+                Value::Int(10)
+            ]
+        );
+        assert_eq!(query.params(MySqlLimitComma), query.to_sql(MySqlLimitComma).1);
+        assert_eq!(query.params(SqlServer), query.to_sql(SqlServer).1);
+
+        let query = crate::stmt::select(["id"]).from("user").limit(10);
+        assert_eq!(query.params(SqlServer), [Value::Int(10)]);
+        assert_eq!(query.params(SqlServer), query.to_sql(SqlServer).1);
+    }
+
+    #[test]
+    fn to_sql() {
+        use crate::dialect::Postgres;
+        use crate::ops::eq;
+        use crate::value::Value;
+
+        let name = "alice".to_string();
+
+        let query = crate::stmt::select(["id"]).from("user").filter(eq("name", &name));
+        let (sql, args) = query.to_sql(Postgres);
+        assert_eq!(sql, r#"SELECT "id" FROM "user" WHERE "name" = $1"#);
+        assert_eq!(args, [Value::from(&name)]);
+        assert_eq!(query.to_sql(Postgres), (sql, args));
+
+        let query = crate::stmt::insert("user", ["id", "name"]).values([(1, &name)]);
+        let (sql, args) = query.to_sql(Postgres);
+        assert_eq!(sql, r#"INSERT INTO "user"("id", "name") VALUES ($1, $2)"#);
+        assert_eq!(args, [Value::Int(1), Value::from(&name)]);
+
+        let query = crate::stmt::update("user")
+            .set("name", &name)
+            .filter(eq("id", 1));
+        let (sql, args) = query.to_sql(Postgres);
+        assert_eq!(sql, r#"UPDATE "user" SET "name" = $1 WHERE "id" = $2"#);
+        assert_eq!(args, [Value::from(&name), Value::Int(1)]);
+
+        let query = crate::stmt::delete("user").filter(eq("name", &name));
+        let (sql, args) = query.to_sql(Postgres);
+        assert_eq!(sql, r#"DELETE FROM "user" WHERE "name" = $1"#);
+        assert_eq!(args, [Value::from(&name)]);
+    }
+
     #[test]
     fn cte() {
         let tbl1 = &"tbl1".to_string();
@@ -404,4 +751,66 @@ mod tests {
             "WITH tbl1(name) AS (VALUES ('tbl1')), tbl2 AS (SELECT 'tbl2' AS name) SELECT name FROM tbl1, tbl2"
         );
     }
+
+    #[test]
+    fn recursive_cte() {
+        use crate::ops::eq;
+
+        let query = crate::stmt::select(["id", "name"])
+            .from("ancestors")
+            .with_recursive(
+                "ancestors",
+                ["id", "name", "parent_id"],
+                crate::stmt::select(["id", "name", "parent_id"])
+                    .from("person")
+                    .filter(eq("id", 42)),
+                crate::stmt::select([("p", "id"), ("p", "name"), ("p", "parent_id")])
+                    .from([
+                        crate::ops::as_table("person", "p"),
+                        crate::ops::as_table("ancestors", "a"),
+                    ])
+                    .filter(eq(("p", "id"), ("a", "parent_id"))),
+            );
+
+        assert_eq!(
+            query.to_string(),
+            "WITH RECURSIVE ancestors(id, name, parent_id) AS (\
+            SELECT id, name, parent_id FROM person WHERE id = 42 \
+            UNION ALL \
+            SELECT p.id, p.name, p.parent_id FROM person AS p, ancestors AS a WHERE p.id = a.parent_id\
+            ) SELECT id, name FROM ancestors"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "recursive CTE `t` requires a column list")]
+    fn recursive_cte_without_columns() {
+        crate::stmt::select(["n"]).from("t").with_recursive(
+            "t",
+            Vec::<&str>::new(),
+            crate::stmt::select([1]),
+            crate::stmt::select([2]),
+        );
+    }
+
+    #[test]
+    fn into_owned() {
+        use crate::dialect::Postgres;
+        use crate::ops::eq;
+        use crate::value::Value;
+
+        let query = {
+            let table = "user".to_string();
+            let name = "alice".to_string();
+            crate::stmt::select(["id"])
+                .from(table.as_str())
+                .filter(eq("name", &name))
+                .into_owned()
+        };
+        let (sql, args) = std::thread::spawn(move || query.to_sql(Postgres))
+            .join()
+            .unwrap();
+        assert_eq!(sql, r#"SELECT "id" FROM "user" WHERE "name" = $1"#);
+        assert_eq!(args, [Value::Text("alice".into())]);
+    }
 }
@@ -1,11 +1,16 @@
 use crate::clause;
+use crate::item::Ident;
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 pub struct Result<'a> {
     pub(crate) with: Option<clause::With<'a>>,
     pub(crate) data: crate::stmt::data::Data<'a>,
-    pub(crate) limit: Option<clause::Limit>,
-    pub(crate) offset: Option<clause::Offset>,
+    pub(crate) orders: Option<clause::OrderBy<'a>>,
+    pub(crate) limit: Option<clause::Limit<'a>>,
+    pub(crate) offset: Option<clause::Offset<'a>>,
+    pub(crate) lock: Option<clause::Lock<'a>>,
 }
 
 stmt_common!(Result);
@@ -25,6 +30,201 @@ where
     }
 }
 
+impl<'a> Result<'a> {
+    binary_methods!(union union_all intersect intersect_all except except_all);
+
+    /// Set `LIMIT` clause.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    /// use qians_xql::union;
+    ///
+    /// assert_eq!(
+    ///     union(select([1]), select([2])).order_by([1]).limit(1).to_string(),
+    ///     "SELECT 1 UNION SELECT 2 ORDER BY 1 LIMIT 1",
+    /// );
+    /// ```
+    pub fn limit<L>(mut self, limit: L) -> Result<'a>
+    where
+        L: Into<clause::Limit<'a>>,
+    {
+        self.limit = Some(limit.into());
+        self
+    }
+
+    /// Set `LIMIT ALL` clause, which Postgres treats as no limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    ///
+    /// assert_eq!(
+    ///     select(["id"]).from("book").limit_all().offset(10).to_string(),
+    ///     "SELECT id FROM book LIMIT ALL OFFSET 10",
+    /// );
+    /// ```
+    pub fn limit_all(mut self) -> Result<'a> {
+        self.limit = Some(clause::Limit(None));
+        self
+    }
+
+    /// Set `OFFSET` clause.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    /// use qians_xql::union;
+    ///
+    /// assert_eq!(
+    ///     union(select([1]), select([2])).limit(1).offset(1).to_string(),
+    ///     "SELECT 1 UNION SELECT 2 LIMIT 1 OFFSET 1",
+    /// );
+    /// ```
+    pub fn offset<O>(mut self, offset: O) -> Result<'a>
+    where
+        O: Into<clause::Offset<'a>>,
+    {
+        self.offset = Some(offset.into());
+        self
+    }
+
+    /// Lock the selected rows with `FOR UPDATE`.
+    ///
+    /// SQL Server has no such clause, so it is left out when rendered for
+    /// [`SqlServer`](crate::dialect::SqlServer).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    ///
+    /// assert_eq!(
+    ///     select(["id"]).from("job").limit(1).for_update().to_string(),
+    ///     "SELECT id FROM job LIMIT 1 FOR UPDATE",
+    /// );
+    /// ```
+    pub fn for_update(mut self) -> Result<'a> {
+        self.lock = Some(clause::Lock("UPDATE".into(), Vec::new(), None));
+        self
+    }
+
+    /// Lock the selected rows of some tables with `FOR UPDATE OF`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    ///
+    /// assert_eq!(
+    ///     select(["id"]).from("job").limit(1).for_update_of(["job"]).to_string(),
+    ///     "SELECT id FROM job LIMIT 1 FOR UPDATE OF job",
+    /// );
+    /// ```
+    pub fn for_update_of<C, I>(mut self, tables: I) -> Result<'a>
+    where
+        C: Into<Ident<'a>>,
+        I: IntoIterator<Item = C>,
+    {
+        self.lock = Some(clause::Lock(
+            "UPDATE".into(),
+            tables.into_iter().map(Into::into).collect(),
+            None,
+        ));
+        self
+    }
+
+    /// Lock the selected rows with `FOR SHARE`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    ///
+    /// assert_eq!(
+    ///     select(["id"]).from("job").limit(1).for_share().to_string(),
+    ///     "SELECT id FROM job LIMIT 1 FOR SHARE",
+    /// );
+    /// ```
+    pub fn for_share(mut self) -> Result<'a> {
+        self.lock = Some(clause::Lock("SHARE".into(), Vec::new(), None));
+        self
+    }
+
+    /// Lock the selected rows of some tables with `FOR SHARE OF`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    ///
+    /// assert_eq!(
+    ///     select(["id"]).from("job").limit(1).for_share_of(["job"]).to_string(),
+    ///     "SELECT id FROM job LIMIT 1 FOR SHARE OF job",
+    /// );
+    /// ```
+    pub fn for_share_of<C, I>(mut self, tables: I) -> Result<'a>
+    where
+        C: Into<Ident<'a>>,
+        I: IntoIterator<Item = C>,
+    {
+        self.lock = Some(clause::Lock(
+            "SHARE".into(),
+            tables.into_iter().map(Into::into).collect(),
+            None,
+        ));
+        self
+    }
+
+    /// Skip rows that can't be locked immediately with `SKIP LOCKED`.
+    ///
+    /// Overrides [`nowait`](Result::nowait) and does nothing if the statement
+    /// has no locking clause.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    ///
+    /// assert_eq!(
+    ///     select(["id"]).from("job").for_update_of(["job"]).skip_locked().to_string(),
+    ///     "SELECT id FROM job FOR UPDATE OF job SKIP LOCKED",
+    /// );
+    /// ```
+    pub fn skip_locked(mut self) -> Result<'a> {
+        if let Some(lock) = self.lock.as_mut() {
+            lock.2 = Some("SKIP LOCKED".into());
+        }
+        self
+    }
+
+    /// Fail instead of waiting for rows that can't be locked immediately with
+    /// `NOWAIT`.
+    ///
+    /// Overrides [`skip_locked`](Result::skip_locked) and does nothing if the
+    /// statement has no locking clause.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    ///
+    /// assert_eq!(
+    ///     select(["id"]).from("job").for_share().nowait().to_string(),
+    ///     "SELECT id FROM job FOR SHARE NOWAIT",
+    /// );
+    /// ```
+    pub fn nowait(mut self) -> Result<'a> {
+        if let Some(lock) = self.lock.as_mut() {
+            lock.2 = Some("NOWAIT".into());
+        }
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::stmt::select;
@@ -38,6 +238,55 @@ mod tests {
         assert_eq!(query.to_string(), "VALUES (1), (2) LIMIT 10");
     }
 
+    #[test]
+    fn limit_expr() {
+        use crate::dialect::Postgres;
+        use crate::ops::mul;
+        use crate::value::Value;
+
+        let query = select(["id"]).from("data").pagination(10_u32, mul(2, 10));
+        assert_eq!(
+            query.to_string(),
+            "SELECT id FROM data LIMIT 10 OFFSET 2 * 10"
+        );
+
+        let (sql, args) = select(["id"])
+            .from("data")
+            .limit(10_u32)
+            .offset(20_u32)
+            .to_sql(Postgres);
+        assert_eq!(sql, r#"SELECT "id" FROM "data" LIMIT $1 OFFSET $2"#);
+        assert_eq!(args, [Value::BigInt(10), Value::BigInt(20)]);
+
+        let query = values([(1,), (2,)]).limit_all().offset(1);
+        assert_eq!(query.to_string(), "VALUES (1), (2) LIMIT ALL OFFSET 1");
+        let query = select(["id"]).from("data").limit(5).limit_all();
+        assert_eq!(query.to_string(), "SELECT id FROM data LIMIT ALL");
+    }
+
+    #[test]
+    fn lock() {
+        let query = select(["id"])
+            .from("job")
+            .order_by(["id"])
+            .pagination(1, 2)
+            .for_update_of(["job", "queue"]);
+        assert_eq!(
+            query.to_string(),
+            "SELECT id FROM job ORDER BY id LIMIT 1 OFFSET 2 FOR UPDATE OF job, queue"
+        );
+        let query = select(["id"]).from("job").for_share().limit(1);
+        assert_eq!(query.to_string(), "SELECT id FROM job LIMIT 1 FOR SHARE");
+        let query = select(["id"])
+            .from("job")
+            .for_update()
+            .nowait()
+            .skip_locked();
+        assert_eq!(query.to_string(), "SELECT id FROM job FOR UPDATE SKIP LOCKED");
+        let query = select(["id"]).from("job").limit(1).nowait();
+        assert_eq!(query.to_string(), "SELECT id FROM job LIMIT 1");
+    }
+
     #[test]
     fn offset() {
         let query = select(["id"]).from("data").offset(10);
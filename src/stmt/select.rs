@@ -1,17 +1,25 @@
 use crate::clause;
 use crate::expr::Expr;
+use crate::item::Grouping;
+use crate::item::Ident;
+use crate::item::Table;
+use crate::item::Window;
 use crate::ops::and;
 use crate::stmt::result::Result;
 
 /// `SELECT` statement builder.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 pub struct Select<'a> {
     pub(crate) with: Option<clause::With<'a>>,
+    pub(crate) distinct: Option<clause::Distinct<'a>>,
     pub(crate) fields: clause::Select<'a>,
     pub(crate) tables: Option<clause::From<'a>>,
     pub(crate) filter: Option<clause::Where<'a>>,
     pub(crate) groups: Option<clause::GroupBy<'a>>,
     pub(crate) having: Option<clause::Having<'a>>,
+    pub(crate) windows: Option<clause::Window<'a>>,
     pub(crate) orders: Option<clause::OrderBy<'a>>,
 }
 
@@ -20,6 +28,8 @@ stmt_common!(Select);
 crate::macros::gen_display!(Select<'_>);
 
 impl<'a> Select<'a> {
+    binary_methods!(union union_all intersect intersect_all except except_all);
+
     /// Add more column(s) to `SELECT` clause.
     ///
     /// # Examples
@@ -42,6 +52,85 @@ impl<'a> Select<'a> {
         self
     }
 
+    /// Drop the field(s) named any of `columns` from `SELECT` clause, see
+    /// [`clause::Select::exclude`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    ///
+    /// let query = select(["id", "name", "email", "password"])
+    ///     .from("user")
+    ///     .exclude(["password"]);
+    /// assert_eq!(query.to_string(), "SELECT id, name, email FROM user");
+    /// ```
+    pub fn exclude<C, I>(mut self, columns: I) -> Select<'a>
+    where
+        C: Into<Ident<'a>>,
+        I: IntoIterator<Item = C>,
+    {
+        self.fields = self.fields.exclude(columns);
+        self
+    }
+
+    /// Turn the statement into `SELECT DISTINCT`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    /// use qians_xql::count;
+    ///
+    /// assert_eq!(
+    ///     select(["city"]).from("user").distinct().to_string(),
+    ///     "SELECT DISTINCT city FROM user",
+    /// );
+    /// assert_eq!(
+    ///     select([count("id")]).select(["city"]).from("user").distinct().to_string(),
+    ///     "SELECT DISTINCT COUNT(id), city FROM user",
+    /// );
+    /// ```
+    pub fn distinct(mut self) -> Select<'a> {
+        self.distinct = self.distinct.or_else(|| Some(Default::default()));
+        self
+    }
+
+    /// Add more expression(s) to `DISTINCT ON` clause.
+    ///
+    /// Postgres requires `DISTINCT ON` expressions to match the leftmost
+    /// `ORDER BY` expressions, this is not checked by the builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    /// use qians_xql::desc;
+    ///
+    /// let query = select(["city", "name", "age"])
+    ///     .from("user")
+    ///     .distinct_on(["city"])
+    ///     .order_by(["city".into(), desc("age")]);
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "SELECT DISTINCT ON (city) city, name, age FROM user ORDER BY city, age DESC",
+    /// );
+    /// ```
+    pub fn distinct_on<E>(mut self, exprs: E) -> Select<'a>
+    where
+        E: Into<clause::Distinct<'a>>,
+    {
+        self.distinct = match self.distinct.take() {
+            Some(mut inner) => {
+                inner.0.extend(exprs.into().0);
+                Some(inner)
+            }
+            None => Some(exprs.into()),
+        };
+        self
+    }
+
     /// Add more table(s) to `FROM` clause.
     ///
     /// # Examples
@@ -75,6 +164,520 @@ impl<'a> Select<'a> {
         self
     }
 
+    /// Add a subquery (a derived table) to `FROM` clause under an alias.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    /// use qians_xql::count;
+    ///
+    /// let sub = select(["author_id"])
+    ///     .select([count("*").alias("books")])
+    ///     .from("book")
+    ///     .group_by("author_id");
+    ///
+    /// let query = select([("sub", "author_id")]).from_subquery(sub, "sub");
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "SELECT sub.author_id FROM \
+    ///     (SELECT author_id, COUNT(*) AS books FROM book GROUP BY author_id) AS sub",
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the alias is empty, as Postgres requires every subquery in
+    /// `FROM` clause to have one.
+    pub fn from_subquery<S, A>(self, subquery: S, alias: A) -> Select<'a>
+    where
+        S: Into<crate::stmt::data::Data<'a>>,
+        A: Into<Ident<'a>>,
+    {
+        let alias = alias.into();
+        assert!(
+            !alias.0.is_empty(),
+            "`Select::from_subquery` requires an alias"
+        );
+        self.from(crate::ops::as_table(subquery.into(), alias))
+    }
+
+    /// Add rows of `VALUES` to `FROM` clause, see
+    /// [`values_table`](crate::ops::values_table).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    ///
+    /// let (a, b) = (&"a".to_string(), &"b".to_string());
+    /// let query = select(["id", "name"]).from_values([(1, a), (2, b)], "t", ["id", "name"]);
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "SELECT id, name FROM (VALUES (1, 'a'), (2, 'b')) AS t(id, name)",
+    /// );
+    /// ```
+    #[inline]
+    pub fn from_values<V, A, C, I>(self, rows: V, alias: A, columns: I) -> Select<'a>
+    where
+        V: Into<clause::Values<'a>>,
+        A: Into<Ident<'a>>,
+        C: Into<Ident<'a>>,
+        I: IntoIterator<Item = C>,
+    {
+        self.from(crate::ops::values_table(rows, alias, columns))
+    }
+
+    /// Add a `LATERAL` subquery or function call to `FROM` clause.
+    ///
+    /// An alias given with [`as_table`](crate::ops::as_table) is kept after the
+    /// `LATERAL` item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    /// use qians_xql::as_table;
+    /// use qians_xql::eq;
+    ///
+    /// let sub = select(["title"])
+    ///     .from("book")
+    ///     .filter(eq(("book", "author_id"), ("author", "id")));
+    ///
+    /// let query = select([("author", "name"), ("sub", "title")])
+    ///     .from("author")
+    ///     .from_lateral(as_table(sub, "sub"));
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "SELECT author.name, sub.title FROM author, \
+    ///     LATERAL (SELECT title FROM book WHERE book.author_id = author.id) AS sub",
+    /// );
+    /// ```
+    pub fn from_lateral<T>(self, table: T) -> Select<'a>
+    where
+        T: Into<Table<'a>>,
+    {
+        let table = table.into();
+        self.from(Table {
+            table: crate::ops::lateral(table.table),
+            alias: table.alias,
+            sample: table.sample,
+        })
+    }
+
+    /// Join the last table in `FROM` clause with another table.
+    ///
+    /// Successive calls nest the joins to the left, so the previous join
+    /// becomes the left side of the next one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `FROM` clause is still empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    /// use qians_xql::eq;
+    ///
+    /// let query = select([("book", "title"), ("author", "name"), ("genre", "name")])
+    ///     .from("book")
+    ///     .join("author", eq(("book", "author_id"), ("author", "id")))
+    ///     .join("genre", eq(("book", "genre_id"), ("genre", "id")));
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "SELECT book.title, author.name, genre.name FROM book \
+    ///     JOIN author ON book.author_id = author.id \
+    ///     JOIN genre ON book.genre_id = genre.id",
+    /// );
+    /// ```
+    pub fn join<T, E>(mut self, table: T, on: E) -> Select<'a>
+    where
+        T: Into<Table<'a>>,
+        E: Into<Expr<'a>>,
+    {
+        let left = self.pop_table("join");
+        self.from(crate::ops::join(left, table, on))
+    }
+
+    /// Join the last table in `FROM` clause with another table on the
+    /// equally named `columns`.
+    ///
+    /// See [`join`](Select::join) for how successive joins are nested.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `FROM` clause is still empty or `columns` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    ///
+    /// let query = select(["title", "name"])
+    ///     .from("book")
+    ///     .join_using("author", ["author_id", "lang"]);
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "SELECT title, name FROM book JOIN author USING (author_id, lang)",
+    /// );
+    /// ```
+    pub fn join_using<T, C, I>(mut self, table: T, columns: I) -> Select<'a>
+    where
+        T: Into<Table<'a>>,
+        C: Into<Ident<'a>>,
+        I: IntoIterator<Item = C>,
+    {
+        let left = self.pop_table("join_using");
+        self.from(crate::ops::join_using(left, table, columns))
+    }
+
+    /// Left join the last table in `FROM` clause with another table.
+    ///
+    /// See [`join`](Select::join) for how successive joins are nested.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `FROM` clause is still empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    /// use qians_xql::eq;
+    ///
+    /// let query = select([("book", "title"), ("author", "name")])
+    ///     .from("book")
+    ///     .left_join("author", eq(("book", "author_id"), ("author", "id")));
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "SELECT book.title, author.name FROM book LEFT JOIN author ON book.author_id = author.id",
+    /// );
+    /// ```
+    pub fn left_join<T, E>(mut self, table: T, on: E) -> Select<'a>
+    where
+        T: Into<Table<'a>>,
+        E: Into<Expr<'a>>,
+    {
+        let left = self.pop_table("left_join");
+        self.from(crate::ops::left_join(left, table, on))
+    }
+
+    /// Right join the last table in `FROM` clause with another table.
+    ///
+    /// See [`join`](Select::join) for how successive joins are nested.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `FROM` clause is still empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    /// use qians_xql::eq;
+    ///
+    /// let query = select([("book", "title"), ("author", "name")])
+    ///     .from("book")
+    ///     .right_join("author", eq(("book", "author_id"), ("author", "id")));
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "SELECT book.title, author.name FROM book RIGHT JOIN author ON book.author_id = author.id",
+    /// );
+    /// ```
+    pub fn right_join<T, E>(mut self, table: T, on: E) -> Select<'a>
+    where
+        T: Into<Table<'a>>,
+        E: Into<Expr<'a>>,
+    {
+        let left = self.pop_table("right_join");
+        self.from(crate::ops::right_join(left, table, on))
+    }
+
+    /// Full join the last table in `FROM` clause with another table.
+    ///
+    /// See [`join`](Select::join) for how successive joins are nested.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `FROM` clause is still empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    /// use qians_xql::eq;
+    ///
+    /// let query = select([("book", "title"), ("author", "name")])
+    ///     .from("book")
+    ///     .full_join("author", eq(("book", "author_id"), ("author", "id")));
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "SELECT book.title, author.name FROM book FULL JOIN author ON book.author_id = author.id",
+    /// );
+    /// ```
+    pub fn full_join<T, E>(mut self, table: T, on: E) -> Select<'a>
+    where
+        T: Into<Table<'a>>,
+        E: Into<Expr<'a>>,
+    {
+        let left = self.pop_table("full_join");
+        self.from(crate::ops::full_join(left, table, on))
+    }
+
+    /// Cross join the last table in `FROM` clause with another table.
+    ///
+    /// See [`join`](Select::join) for how successive joins are nested.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `FROM` clause is still empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    /// use qians_xql::func;
+    ///
+    /// let query = select(["name", "n"])
+    ///     .from("book")
+    ///     .cross_join(func("generate_series", [1, 3]));
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "SELECT name, n FROM book CROSS JOIN generate_series(1, 3)",
+    /// );
+    /// ```
+    pub fn cross_join<T>(mut self, table: T) -> Select<'a>
+    where
+        T: Into<Table<'a>>,
+    {
+        let left = self.pop_table("cross_join");
+        self.from(crate::ops::cross_join(left, table))
+    }
+
+    /// Cross apply a subquery to the last table in `FROM` clause.
+    ///
+    /// It's rendered as `CROSS APPLY` under [`SqlServer`](crate::dialect::SqlServer)
+    /// and as `CROSS JOIN LATERAL` otherwise. See [`join`](Select::join) for
+    /// how successive joins are nested.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `FROM` clause is still empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::as_table;
+    /// use qians_xql::eq;
+    /// use qians_xql::select;
+    /// use qians_xql::dialect::SqlServer;
+    ///
+    /// let books = select(["title"]).from("book").filter(eq(("book", "author_id"), ("author", "id")));
+    /// let query = select([("author", "name"), ("b", "title")])
+    ///     .from("author")
+    ///     .cross_apply(as_table(books, "b"));
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "SELECT author.name, b.title FROM author CROSS JOIN LATERAL \
+    ///     (SELECT title FROM book WHERE book.author_id = author.id) AS b",
+    /// );
+    /// assert_eq!(
+    ///     query.to_sql(SqlServer).0,
+    ///     "SELECT [author].[name], [b].[title] FROM [author] CROSS APPLY \
+    ///     (SELECT [title] FROM [book] WHERE [book].[author_id] = [author].[id]) AS [b]",
+    /// );
+    /// ```
+    pub fn cross_apply<T>(mut self, table: T) -> Select<'a>
+    where
+        T: Into<Table<'a>>,
+    {
+        let left = self.pop_table("cross_apply");
+        self.from(crate::ops::cross_apply(left, table))
+    }
+
+    /// Outer apply a subquery to the last table in `FROM` clause, keeping the
+    /// rows the subquery returns nothing for.
+    ///
+    /// It's rendered as `OUTER APPLY` under [`SqlServer`](crate::dialect::SqlServer)
+    /// and as `LEFT JOIN LATERAL ... ON TRUE` otherwise. See
+    /// [`join`](Select::join) for how successive joins are nested.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `FROM` clause is still empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::as_table;
+    /// use qians_xql::eq;
+    /// use qians_xql::select;
+    /// use qians_xql::dialect::SqlServer;
+    ///
+    /// let books = select(["title"]).from("book").filter(eq(("book", "author_id"), ("author", "id")));
+    /// let query = select([("author", "name"), ("b", "title")])
+    ///     .from("author")
+    ///     .outer_apply(as_table(books, "b"));
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "SELECT author.name, b.title FROM author LEFT JOIN LATERAL \
+    ///     (SELECT title FROM book WHERE book.author_id = author.id) AS b ON TRUE",
+    /// );
+    /// assert_eq!(
+    ///     query.to_sql(SqlServer).0,
+    ///     "SELECT [author].[name], [b].[title] FROM [author] OUTER APPLY \
+    ///     (SELECT [title] FROM [book] WHERE [book].[author_id] = [author].[id]) AS [b]",
+    /// );
+    /// ```
+    pub fn outer_apply<T>(mut self, table: T) -> Select<'a>
+    where
+        T: Into<Table<'a>>,
+    {
+        let left = self.pop_table("outer_apply");
+        self.from(crate::ops::outer_apply(left, table))
+    }
+
+    /// Natural join the last table in `FROM` clause with another table.
+    ///
+    /// See [`join`](Select::join) for how successive joins are nested.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `FROM` clause is still empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    ///
+    /// let query = select(["title", "name"])
+    ///     .from("book")
+    ///     .natural_join("author");
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "SELECT title, name FROM book NATURAL JOIN author",
+    /// );
+    /// ```
+    pub fn natural_join<T>(mut self, table: T) -> Select<'a>
+    where
+        T: Into<Table<'a>>,
+    {
+        let left = self.pop_table("natural_join");
+        self.from(crate::ops::natural_join(left, table))
+    }
+
+    /// Natural left join the last table in `FROM` clause with another table.
+    ///
+    /// See [`join`](Select::join) for how successive joins are nested.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `FROM` clause is still empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    ///
+    /// let query = select(["title", "name"])
+    ///     .from("book")
+    ///     .natural_left_join("author");
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "SELECT title, name FROM book NATURAL LEFT JOIN author",
+    /// );
+    /// ```
+    pub fn natural_left_join<T>(mut self, table: T) -> Select<'a>
+    where
+        T: Into<Table<'a>>,
+    {
+        let left = self.pop_table("natural_left_join");
+        self.from(crate::ops::natural_left_join(left, table))
+    }
+
+    /// Natural right join the last table in `FROM` clause with another table.
+    ///
+    /// See [`join`](Select::join) for how successive joins are nested.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `FROM` clause is still empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    ///
+    /// let query = select(["title", "name"])
+    ///     .from("book")
+    ///     .natural_right_join("author");
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "SELECT title, name FROM book NATURAL RIGHT JOIN author",
+    /// );
+    /// ```
+    pub fn natural_right_join<T>(mut self, table: T) -> Select<'a>
+    where
+        T: Into<Table<'a>>,
+    {
+        let left = self.pop_table("natural_right_join");
+        self.from(crate::ops::natural_right_join(left, table))
+    }
+
+    /// Natural full join the last table in `FROM` clause with another table.
+    ///
+    /// See [`join`](Select::join) for how successive joins are nested.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `FROM` clause is still empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    ///
+    /// let query = select(["title", "name"])
+    ///     .from("book")
+    ///     .natural_full_join("author");
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "SELECT title, name FROM book NATURAL FULL JOIN author",
+    /// );
+    /// ```
+    pub fn natural_full_join<T>(mut self, table: T) -> Select<'a>
+    where
+        T: Into<Table<'a>>,
+    {
+        let left = self.pop_table("natural_full_join");
+        self.from(crate::ops::natural_full_join(left, table))
+    }
+
+    /// Take out the last table in `FROM` clause to become the left side of a
+    /// join.
+    fn pop_table(&mut self, method: &str) -> Table<'a> {
+        match self.tables.as_mut().and_then(|inner| inner.0.pop()) {
+            Some(table) => table,
+            None => panic!("`Select::{method}` requires a table in `FROM` clause"),
+        }
+    }
+
     /// Set condition to `WHERE` clause.
     ///
     /// Successive calls combine new condition with previous condition with
@@ -109,6 +712,38 @@ impl<'a> Select<'a> {
         self
     }
 
+    /// Set condition to `WHERE` clause, combining it with previous condition
+    /// with [`or`](crate::ops::or).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    /// use qians_xql::eq;
+    /// use qians_xql::ge;
+    ///
+    /// let query = select(["id"])
+    ///     .from("book")
+    ///     .filter(ge("year", 1970))
+    ///     .filter(eq("lang", 1))
+    ///     .filter_or(eq("featured", true));
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "SELECT id FROM book WHERE (year >= 1970 AND lang = 1) OR featured = true",
+    /// );
+    /// ```
+    pub fn filter_or<E>(mut self, expr: E) -> Select<'a>
+    where
+        E: Into<Expr<'a>>,
+    {
+        self.filter = match self.filter.take() {
+            Some(inner) => Some(crate::ops::or_grouped(inner.0, expr.into()).into()),
+            None => Some(expr.into().into()),
+        };
+        self
+    }
+
     /// Add more condition(s) to `GROUP BY` clause.
     ///
     /// # Examples
@@ -141,6 +776,88 @@ impl<'a> Select<'a> {
         self
     }
 
+    /// Add `GROUPING SETS` to `GROUP BY` clause.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    /// use qians_xql::sum;
+    ///
+    /// let query = select(("brand", "size", sum("sales")))
+    ///     .from("items")
+    ///     .grouping_sets([vec!["brand"], vec!["size"], vec![]]);
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "SELECT brand, size, SUM(sales) FROM items GROUP BY GROUPING SETS ((brand), (size), ())",
+    /// );
+    /// ```
+    pub fn grouping_sets<I, S, E>(self, sets: I) -> Select<'a>
+    where
+        I: IntoIterator<Item = S>,
+        S: IntoIterator<Item = E>,
+        E: Into<Expr<'a>>,
+    {
+        let sets = sets
+            .into_iter()
+            .map(|set| set.into_iter().map(Into::into).collect())
+            .collect();
+        self.group_by(Grouping::Sets(sets))
+    }
+
+    /// Add `ROLLUP` to `GROUP BY` clause.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    /// use qians_xql::sum;
+    ///
+    /// let query = select(("brand", "size", sum("sales")))
+    ///     .from("items")
+    ///     .rollup(["brand", "size"]);
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "SELECT brand, size, SUM(sales) FROM items GROUP BY ROLLUP(brand, size)",
+    /// );
+    /// ```
+    pub fn rollup<I, E>(self, exprs: I) -> Select<'a>
+    where
+        I: IntoIterator<Item = E>,
+        E: Into<Expr<'a>>,
+    {
+        let exprs = exprs.into_iter().map(Into::into).collect();
+        self.group_by(Grouping::Rollup(exprs))
+    }
+
+    /// Add `CUBE` to `GROUP BY` clause.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    /// use qians_xql::sum;
+    ///
+    /// let query = select(("brand", "size", sum("sales")))
+    ///     .from("items")
+    ///     .cube(["brand", "size"]);
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "SELECT brand, size, SUM(sales) FROM items GROUP BY CUBE(brand, size)",
+    /// );
+    /// ```
+    pub fn cube<I, E>(self, exprs: I) -> Select<'a>
+    where
+        I: IntoIterator<Item = E>,
+        E: Into<Expr<'a>>,
+    {
+        let exprs = exprs.into_iter().map(Into::into).collect();
+        self.group_by(Grouping::Cube(exprs))
+    }
+
     /// Set condition to `HAVING` clause.
     ///
     /// Successive calls combine new condition with previous condition with
@@ -175,6 +892,72 @@ impl<'a> Select<'a> {
         self
     }
 
+    /// Set condition to `HAVING` clause, combining it with previous condition
+    /// with [`or`](crate::ops::or).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    /// use qians_xql::count;
+    /// use qians_xql::gt;
+    /// use qians_xql::lt;
+    ///
+    /// let query = select(["author_id"])
+    ///     .from("book")
+    ///     .group_by("author_id")
+    ///     .having(gt(count("*"), 10))
+    ///     .having_or(lt(count("*"), 2));
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "SELECT author_id FROM book GROUP BY author_id HAVING COUNT(*) > 10 OR COUNT(*) < 2",
+    /// );
+    /// ```
+    pub fn having_or<E>(mut self, expr: E) -> Select<'a>
+    where
+        E: Into<Expr<'a>>,
+    {
+        self.having = match self.having.take() {
+            Some(inner) => Some(crate::ops::or_grouped(inner.0, expr.into()).into()),
+            None => Some(expr.into().into()),
+        };
+        self
+    }
+
+    /// Add a named window definition to `WINDOW` clause.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    /// use qians_xql::over;
+    /// use qians_xql::sum;
+    /// use qians_xql::avg;
+    /// use qians_xql::item::Window;
+    ///
+    /// let query = select([over(sum("salary"), "w"), over(avg("salary"), "w")])
+    ///     .from("employee")
+    ///     .window("w", Window::default().partition_by(["dept"]));
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "SELECT SUM(salary) OVER w, AVG(salary) OVER w FROM employee \
+    ///     WINDOW w AS (PARTITION BY dept)",
+    /// );
+    /// ```
+    pub fn window<N, W>(mut self, name: N, window: W) -> Select<'a>
+    where
+        N: Into<Ident<'a>>,
+        W: Into<Window<'a>>,
+    {
+        self.windows
+            .get_or_insert_with(Default::default)
+            .0
+            .push((name.into(), window.into()));
+        self
+    }
+
     pub fn order_by<O>(mut self, orders: O) -> Select<'a>
     where
         O: Into<clause::OrderBy<'a>>,
@@ -189,30 +972,120 @@ impl<'a> Select<'a> {
         self
     }
 
-    pub fn pagination(self, limit: u32, offset: u32) -> Result<'a> {
+    pub fn pagination<L, O>(self, limit: L, offset: O) -> Result<'a>
+    where
+        L: Into<clause::Limit<'a>>,
+        O: Into<clause::Offset<'a>>,
+    {
+        Result {
+            data: self.into(),
+            limit: Some(limit.into()),
+            offset: Some(offset.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn limit<L>(self, limit: L) -> Result<'a>
+    where
+        L: Into<clause::Limit<'a>>,
+    {
         Result {
             data: self.into(),
-            limit: Some(clause::Limit(limit)),
-            offset: Some(clause::Offset(offset)),
+            limit: Some(limit.into()),
             ..Default::default()
         }
     }
 
-    pub fn limit(self, limit: u32) -> Result<'a> {
+    pub fn limit_all(self) -> Result<'a> {
         Result {
             data: self.into(),
-            limit: Some(clause::Limit(limit)),
+            limit: Some(clause::Limit(None)),
             ..Default::default()
         }
     }
 
-    pub fn offset(self, offset: u32) -> Result<'a> {
+    pub fn offset<O>(self, offset: O) -> Result<'a>
+    where
+        O: Into<clause::Offset<'a>>,
+    {
         Result {
             data: self.into(),
-            offset: Some(clause::Offset(offset)),
+            offset: Some(offset.into()),
             ..Default::default()
         }
     }
+
+    /// Lock the selected rows with `FOR UPDATE`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    ///
+    /// assert_eq!(
+    ///     select(["id"]).from("job").for_update().to_string(),
+    ///     "SELECT id FROM job FOR UPDATE",
+    /// );
+    /// ```
+    pub fn for_update(self) -> Result<'a> {
+        Result::from(self).for_update()
+    }
+
+    /// Lock the selected rows of some tables with `FOR UPDATE OF`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    ///
+    /// assert_eq!(
+    ///     select(["id"]).from("job").for_update_of(["job"]).to_string(),
+    ///     "SELECT id FROM job FOR UPDATE OF job",
+    /// );
+    /// ```
+    pub fn for_update_of<C, I>(self, tables: I) -> Result<'a>
+    where
+        C: Into<Ident<'a>>,
+        I: IntoIterator<Item = C>,
+    {
+        Result::from(self).for_update_of(tables)
+    }
+
+    /// Lock the selected rows with `FOR SHARE`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    ///
+    /// assert_eq!(
+    ///     select(["id"]).from("job").for_share().to_string(),
+    ///     "SELECT id FROM job FOR SHARE",
+    /// );
+    /// ```
+    pub fn for_share(self) -> Result<'a> {
+        Result::from(self).for_share()
+    }
+
+    /// Lock the selected rows of some tables with `FOR SHARE OF`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::select;
+    ///
+    /// assert_eq!(
+    ///     select(["id"]).from("job").for_share_of(["job"]).to_string(),
+    ///     "SELECT id FROM job FOR SHARE OF job",
+    /// );
+    /// ```
+    pub fn for_share_of<C, I>(self, tables: I) -> Result<'a>
+    where
+        C: Into<Ident<'a>>,
+        I: IntoIterator<Item = C>,
+    {
+        Result::from(self).for_share_of(tables)
+    }
 }
 
 #[test]
@@ -244,3 +1117,457 @@ fn test() {
     let expect = "SELECT data.id, data.value, COUNT(id), MAX(age), MIN(age), AVG(age) FROM public.data, unnest(data.value) WHERE data.id = 1 AND data.name = \'name\' GROUP BY data.id HAVING true ORDER BY data.id DESC";
     assert_eq!(query.to_string(), expect);
 }
+
+#[test]
+#[cfg(test)]
+fn join() {
+    use crate::ops::as_table;
+    use crate::ops::eq;
+    use crate::stmt::select;
+
+    let query = select([("b", "title"), ("a", "name")])
+        .from(as_table("book", "b"))
+        .join(as_table("author", "a"), eq(("b", "author_id"), ("a", "id")));
+    assert_eq!(
+        query.to_string(),
+        "SELECT b.title, a.name FROM book AS b JOIN author AS a ON b.author_id = a.id"
+    );
+
+    let query = select(["id"])
+        .from(["tag", "book"])
+        .join("author", eq(("book", "author_id"), ("author", "id")));
+    assert_eq!(
+        query.to_string(),
+        "SELECT id FROM tag, book JOIN author ON book.author_id = author.id"
+    );
+
+    let query = select([("book", "title"), ("author", "name"), ("genre", "name")])
+        .from("book")
+        .left_join("author", eq(("book", "author_id"), ("author", "id")))
+        .join("genre", eq(("book", "genre_id"), ("genre", "id")));
+    assert_eq!(
+        query.to_string(),
+        "SELECT book.title, author.name, genre.name FROM book \
+        LEFT JOIN author ON book.author_id = author.id \
+        JOIN genre ON book.genre_id = genre.id"
+    );
+    let expect = crate::ops::join(
+        crate::ops::left_join("book", "author", eq(("book", "author_id"), ("author", "id"))),
+        "genre",
+        eq(("book", "genre_id"), ("genre", "id")),
+    );
+    assert_eq!(query.tables, Some(expect.into()));
+
+    let query = select(["id"])
+        .from("book")
+        .right_join("author", true)
+        .full_join("genre", false);
+    assert_eq!(
+        query.to_string(),
+        "SELECT id FROM book RIGHT JOIN author ON true FULL JOIN genre ON false"
+    );
+
+    let query = select(["id"])
+        .from("book")
+        .cross_join("tag")
+        .left_join("author", eq(("book", "author_id"), ("author", "id")));
+    assert_eq!(
+        query.to_string(),
+        "SELECT id FROM book CROSS JOIN tag LEFT JOIN author ON book.author_id = author.id"
+    );
+
+    let query = select(["id"])
+        .from("book")
+        .join_using("author", ["author_id"])
+        .join("genre", eq(("book", "genre_id"), ("genre", "id")));
+    assert_eq!(
+        query.to_string(),
+        "SELECT id FROM book JOIN author USING (author_id) JOIN genre ON book.genre_id = genre.id"
+    );
+
+    let query = select(["id"])
+        .from("book")
+        .natural_left_join("author")
+        .natural_join("genre");
+    assert_eq!(
+        query.to_string(),
+        "SELECT id FROM book NATURAL LEFT JOIN author NATURAL JOIN genre"
+    );
+    assert!(!query.to_string().contains(" ON "));
+}
+
+#[test]
+#[cfg(test)]
+#[should_panic(expected = "`USING` requires at least one column")]
+fn join_using_empty() {
+    let _ = crate::stmt::select(["id"])
+        .from("book")
+        .join_using("author", Vec::<&str>::new());
+}
+
+#[test]
+#[cfg(test)]
+fn distinct() {
+    use crate::stmt::select;
+
+    let query = select(["id", "name"]).distinct();
+    assert_eq!(query.to_string(), "SELECT DISTINCT id, name");
+    let query = select(Vec::<&str>::new()).distinct();
+    assert_eq!(query.to_string(), "SELECT DISTINCT");
+
+    let query = select(["a", "b", "c"])
+        .distinct_on(["a"])
+        .distinct_on([("t", "b")])
+        .from("t");
+    assert_eq!(
+        query.to_string(),
+        "SELECT DISTINCT ON (a, t.b) a, b, c FROM t"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn window() {
+    use crate::func::func;
+    use crate::func::sum;
+    use crate::item::Window;
+    use crate::stmt::select;
+
+    let query = select([
+        func("rank", Vec::<&str>::new()).over("w"),
+        sum("salary").over(Window::from("w").order_by(["salary"])),
+    ])
+    .from("employee")
+    .group_by(["dept", "salary"])
+    .having(true)
+    .window("w", Window::default().partition_by(["dept"]))
+    .window("v", "w")
+    .order_by(["dept"]);
+    assert_eq!(
+        query.to_string(),
+        "SELECT rank() OVER w, SUM(salary) OVER (w ORDER BY salary) FROM employee \
+        GROUP BY dept, salary HAVING true \
+        WINDOW w AS (PARTITION BY dept), v AS (w) ORDER BY dept"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn pretty() {
+    use crate::ops::{and, eq, in_subquery};
+    use crate::stmt::select;
+
+    let award = select(["book_id"]).from("award");
+    let book = select(["author_id"])
+        .from("book")
+        .filter(in_subquery("id", award));
+    let query = select(["id", "name"])
+        .from("author")
+        .filter(and(eq("active", true), in_subquery("id", book)))
+        .order_by(["name"]);
+    assert_eq!(
+        format!("{query:#}"),
+        "SELECT id, name\n\
+        FROM author\n\
+        WHERE active = true AND id IN (\n    \
+            SELECT author_id\n    \
+            FROM book\n    \
+            WHERE id IN (\n        \
+                SELECT book_id\n        \
+                FROM award\n    \
+            )\n\
+        )\n\
+        ORDER BY name"
+    );
+    assert_eq!(
+        query.to_string(),
+        "SELECT id, name FROM author WHERE active = true AND id IN \
+        (SELECT author_id FROM book WHERE id IN (SELECT book_id FROM award)) ORDER BY name"
+    );
+
+    let query = select(["id"])
+        .from("a")
+        .union(select(["id"]).from("b"))
+        .limit(1);
+    assert_eq!(
+        format!("{query:#}"),
+        "SELECT id\nFROM a\nUNION\nSELECT id\nFROM b\nLIMIT 1"
+    );
+}
+
+#[test]
+#[cfg(test)]
+#[cfg(feature = "serde")]
+fn serde() {
+    use crate::func::count;
+    use crate::item::Window;
+    use crate::ops::{and, as_field, desc, eq, gt, in_subquery, left_join, like};
+    use crate::stmt::select;
+
+    let pattern = "J%".to_string();
+    let query = select([
+        as_field(("author", "name"), "name"),
+        as_field(
+            count("book.id").over(Window::default().partition_by(["genre"])),
+            "total",
+        ),
+    ])
+    .from(left_join(
+        "author",
+        "book",
+        eq(("author", "id"), ("book", "author_id")),
+    ))
+    .filter(and(
+        like(("author", "name"), &pattern),
+        in_subquery(
+            "genre",
+            select(["name"]).from("genre").filter(gt("rank", 3)),
+        ),
+    ))
+    .group_by([("author", "name"), ("book", "genre")])
+    .order_by([desc("total")])
+    .limit(10);
+
+    let json = serde_json::to_string(&query).unwrap();
+    let output: crate::stmt::result::Result = serde_json::from_str(&json).unwrap();
+    assert_eq!(output, query);
+    assert_eq!(output.to_string(), query.to_string());
+}
+
+#[test]
+#[cfg(test)]
+#[cfg(feature = "serde")]
+fn serde_operators() {
+    use crate::func::{to_tsquery, to_tsvector};
+    use crate::ops::{
+        and, contained_by, contains, matches, not_regex_imatch, overlaps, regex_match,
+    };
+    use crate::stmt::select;
+
+    let word = "rust".to_string();
+    let query = select(["id"]).from("post").filter(and(
+        and(
+            matches(to_tsvector("body"), to_tsquery(&word)),
+            and(
+                regex_match("title", &word),
+                not_regex_imatch("title", &word),
+            ),
+        ),
+        and(
+            contains("tags", "wanted"),
+            and(contained_by("tags", "allowed"), overlaps("tags", "hot")),
+        ),
+    ));
+
+    let json = serde_json::to_string(&query).unwrap();
+    let output: crate::stmt::select::Select = serde_json::from_str(&json).unwrap();
+    assert_eq!(output, query);
+
+    // custom operators round-trip as well
+    let query = select([crate::ops::binop("point", "<->", "origin")])
+        .from("place")
+        .filter(crate::ops::postop("name", "ISNULL"));
+    let json = serde_json::to_string(&query).unwrap();
+    let output: crate::stmt::select::Select = serde_json::from_str(&json).unwrap();
+    assert_eq!(output, query);
+    assert_eq!(
+        output.to_string(),
+        "SELECT point <-> origin FROM place WHERE name ISNULL"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn grouping_sets() {
+    use crate::func::count;
+    use crate::stmt::select;
+
+    let query = select(["a", "b", "c"])
+        .select([count("*")])
+        .from("t")
+        .group_by("a")
+        .grouping_sets([vec!["b", "c"], vec!["b"], vec![]]);
+    assert_eq!(
+        query.to_string(),
+        "SELECT a, b, c, COUNT(*) FROM t GROUP BY a, GROUPING SETS ((b, c), (b), ())"
+    );
+
+    let query = select(["a"]).from("t").grouping_sets([[("t", "a")]]);
+    assert_eq!(
+        query.to_string(),
+        "SELECT a FROM t GROUP BY GROUPING SETS ((t.a))"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn rollup() {
+    use crate::func::count;
+    use crate::stmt::select;
+
+    let query = select(["a", "b", "c"])
+        .select([count("*")])
+        .from("t")
+        .group_by("a")
+        .rollup(["b", "c"]);
+    assert_eq!(
+        query.to_string(),
+        "SELECT a, b, c, COUNT(*) FROM t GROUP BY a, ROLLUP(b, c)"
+    );
+
+    let query = select(["a"]).from("t").rollup([("t", "a")]).group_by("b");
+    assert_eq!(query.to_string(), "SELECT a FROM t GROUP BY ROLLUP(t.a), b");
+}
+
+#[test]
+#[cfg(test)]
+fn cube() {
+    use crate::func::count;
+    use crate::stmt::select;
+
+    let query = select(["a", "b", "c"])
+        .select([count("*")])
+        .from("t")
+        .group_by("a")
+        .cube(["b", "c"]);
+    assert_eq!(
+        query.to_string(),
+        "SELECT a, b, c, COUNT(*) FROM t GROUP BY a, CUBE(b, c)"
+    );
+
+    let query = select(["a"])
+        .from("t")
+        .cube(["a"])
+        .grouping_sets([vec!["b"], vec![]]);
+    assert_eq!(
+        query.to_string(),
+        "SELECT a FROM t GROUP BY CUBE(a), GROUPING SETS ((b), ())"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn table_alias() {
+    use crate::blanket::TableExprExt;
+    use crate::item::Table;
+    use crate::ops::eq;
+    use crate::stmt::select;
+
+    let query = select([("u", "name")]).from("user".as_table("u"));
+    assert_eq!(query.to_string(), "SELECT u.name FROM user AS u");
+
+    let query = select([("u", "name"), ("o", "total")])
+        .from("user".as_table("u"))
+        .left_join(
+            Table::from("order").alias("o"),
+            eq(("o", "user_id"), ("u", "id")),
+        );
+    assert_eq!(
+        query.to_string(),
+        "SELECT u.name, o.total FROM user AS u LEFT JOIN order AS o ON o.user_id = u.id"
+    );
+
+    let query = select([("e", "name"), ("m", "name")])
+        .from("employee".as_table("e"))
+        .join(
+            "employee".as_table("m"),
+            eq(("e", "manager_id"), ("m", "id")),
+        );
+    assert_eq!(
+        query.to_string(),
+        "SELECT e.name, m.name FROM employee AS e JOIN employee AS m ON e.manager_id = m.id"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn from_subquery() {
+    use crate::func::avg;
+    use crate::func::sum;
+    use crate::ops::gt;
+    use crate::stmt::select;
+    use crate::stmt::values;
+
+    let totals = select(["user_id"])
+        .select([sum("amount").alias("total")])
+        .from("order")
+        .group_by("user_id");
+    let query = select([avg(("t", "total"))])
+        .from_subquery(totals, "t")
+        .filter(gt(("t", "total"), 100));
+    assert_eq!(
+        query.to_string(),
+        "SELECT AVG(t.total) FROM \
+        (SELECT user_id, SUM(amount) AS total FROM order GROUP BY user_id) AS t \
+        WHERE t.total > 100"
+    );
+
+    let query = select([("v", "n")]).from_subquery(values([(1,), (2,)]), "v");
+    assert_eq!(query.to_string(), "SELECT v.n FROM (VALUES (1), (2)) AS v");
+}
+
+#[test]
+#[cfg(test)]
+#[should_panic(expected = "`Select::from_subquery` requires an alias")]
+fn from_subquery_without_alias() {
+    use crate::stmt::select;
+
+    select(["id"]).from_subquery(select(["id"]).from("user"), "");
+}
+
+#[test]
+#[cfg(test)]
+fn without_from() {
+    use crate::func::now;
+    use crate::stmt::select;
+
+    assert_eq!(select([1]).to_string(), "SELECT 1");
+    assert_eq!(select([now()]).to_string(), "SELECT NOW()");
+    assert_eq!(select([true]).to_string(), "SELECT true");
+    assert_eq!(select([1]).from(Vec::<&str>::new()).to_string(), "SELECT 1");
+    assert_eq!(select([1]).limit(1).to_string(), "SELECT 1 LIMIT 1");
+    assert_eq!(
+        select([1]).pagination(1, 2).to_string(),
+        "SELECT 1 LIMIT 1 OFFSET 2"
+    );
+    assert_eq!(format!("{:#}", select([1])), "SELECT 1");
+}
+
+#[test]
+#[cfg(test)]
+fn or_combining() {
+    use crate::ops::and;
+    use crate::ops::eq;
+    use crate::stmt::select;
+
+    let query = select(["id"])
+        .from("t")
+        .filter(eq("a", 1))
+        .filter(eq("b", 2))
+        .filter_or(eq("c", 3));
+    assert_eq!(
+        query.to_string(),
+        "SELECT id FROM t WHERE (a = 1 AND b = 2) OR c = 3"
+    );
+
+    let query = select(["id"])
+        .from("t")
+        .filter_or(eq("a", 1))
+        .filter_or(and(eq("b", 2), eq("c", 3)))
+        .filter(eq("d", 4));
+    assert_eq!(
+        query.to_string(),
+        "SELECT id FROM t WHERE (a = 1 OR (b = 2 AND c = 3)) AND d = 4"
+    );
+
+    let query = select(["a"])
+        .from("t")
+        .group_by("a")
+        .having(eq("a", 1))
+        .having(eq("a", 2))
+        .having_or(eq("a", 3));
+    assert_eq!(
+        query.to_string(),
+        "SELECT a FROM t GROUP BY a HAVING (a = 1 AND a = 2) OR a = 3"
+    );
+}
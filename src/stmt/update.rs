@@ -5,6 +5,8 @@ use crate::ops::and;
 
 /// `UPDATE` statement builder.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 pub struct Update<'a> {
     pub(crate) with: Option<clause::With<'a>>,
     pub(crate) table: clause::Update<'a>,
@@ -84,6 +86,37 @@ impl<'a> Update<'a> {
         self
     }
 
+    /// Set condition to `WHERE` clause, combining it with previous condition
+    /// with [`or`](crate::ops::or).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::update;
+    /// use qians_xql::eq;
+    ///
+    /// let query = update("book")
+    ///     .set("stock", 0)
+    ///     .filter(eq("lang", 1))
+    ///     .filter(eq("year", 1970))
+    ///     .filter_or(eq("id", 1));
+    ///
+    /// assert_eq!(
+    ///     query.to_string(),
+    ///     "UPDATE book SET stock = 0 WHERE (lang = 1 AND year = 1970) OR id = 1",
+    /// );
+    /// ```
+    pub fn filter_or<E>(mut self, expr: E) -> Update<'a>
+    where
+        E: Into<Expr<'a>>,
+    {
+        self.filter = match self.filter.take() {
+            Some(inner) => Some(crate::ops::or_grouped(inner.0, expr.into()).into()),
+            None => Some(expr.into().into()),
+        };
+        self
+    }
+
     /// Set/Add field(s) to `RETURNING` clause.
     ///
     /// Successive calls combine adds more field into the clause.
@@ -117,6 +150,21 @@ impl<'a> Update<'a> {
         };
         self
     }
+
+    /// Add `*` to `RETURNING` clause, returning all columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qians_xql::update;
+    ///
+    /// let query = update("book").set("price", 10).returning_all();
+    /// assert_eq!(query.to_string(), "UPDATE book SET price = 10 RETURNING *");
+    /// ```
+    #[inline]
+    pub fn returning_all(self) -> Update<'a> {
+        self.returning([crate::ops::star()])
+    }
 }
 
 #[test]
@@ -131,3 +179,56 @@ fn test() {
         .returning(["id", "age"]);
     assert_eq!(query.to_string(), "UPDATE user SET id = 1, age = 30, name = 'someone' FROM data WHERE user.id = data.id RETURNING id, age");
 }
+
+#[test]
+#[cfg(test)]
+fn null() {
+    let query = crate::stmt::update("user")
+        .set("deleted_at", None::<i64>)
+        .filter(crate::ops::eq("deleted_at", None::<i64>));
+    assert_eq!(
+        query.to_string(),
+        "UPDATE user SET deleted_at = NULL WHERE deleted_at IS NULL"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn filter_or() {
+    use crate::ops;
+
+    let query = crate::stmt::update("user")
+        .set("active", false)
+        .filter_or(ops::eq("id", 1))
+        .filter_or(ops::eq("id", 2));
+    assert_eq!(
+        query.to_string(),
+        "UPDATE user SET active = false WHERE id = 1 OR id = 2"
+    );
+
+    let query = crate::stmt::update("user")
+        .set("active", false)
+        .filter(ops::eq("a", 1))
+        .filter(ops::eq("b", 2))
+        .filter_or(ops::eq("c", 3))
+        .filter(ops::eq("d", 4));
+    assert_eq!(
+        query.to_string(),
+        "UPDATE user SET active = false WHERE ((a = 1 AND b = 2) OR c = 3) AND d = 4"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn returning_all() {
+    use crate::dialect::Postgres;
+
+    let query = crate::stmt::update("user")
+        .set("age", 30)
+        .filter(crate::ops::eq("id", 1))
+        .returning_all();
+    assert_eq!(
+        query.to_sql(Postgres).0,
+        r#"UPDATE "user" SET "age" = $1 WHERE "id" = $2 RETURNING *"#
+    );
+}
@@ -3,6 +3,8 @@ use crate::stmt::result::Result;
 
 /// `VALUES` statement builder.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 pub struct Values<'a> {
     pub(crate) with: Option<clause::With<'a>>,
     pub(crate) rows: clause::Values<'a>,
@@ -13,18 +15,34 @@ stmt_common!(Values);
 crate::macros::gen_display!(Values<'_>);
 
 impl<'a> Values<'a> {
-    pub fn limit(self, limit: u32) -> Result<'a> {
+    binary_methods!(union union_all intersect intersect_all except except_all);
+
+    pub fn limit<L>(self, limit: L) -> Result<'a>
+    where
+        L: Into<clause::Limit<'a>>,
+    {
+        Result {
+            data: self.into(),
+            limit: Some(limit.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn limit_all(self) -> Result<'a> {
         Result {
             data: self.into(),
-            limit: Some(clause::Limit(limit)),
+            limit: Some(clause::Limit(None)),
             ..Default::default()
         }
     }
 
-    pub fn offset(self, offset: u32) -> Result<'a> {
+    pub fn offset<O>(self, offset: O) -> Result<'a>
+    where
+        O: Into<clause::Offset<'a>>,
+    {
         Result {
             data: self.into(),
-            offset: Some(clause::Offset(offset)),
+            offset: Some(offset.into()),
             ..Default::default()
         }
     }
@@ -1,23 +1,33 @@
-use crate::expr::Expr;
 use crate::item::FuncCall;
+use crate::item::Ident;
+use crate::item::JoinCond;
+use crate::item::JoinKind;
+use crate::item::Table;
 use crate::item::TableRef;
 use crate::stmt::data::Data;
 use crate::stmt::select::Select;
 use crate::stmt::values::Values;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 pub enum TableExpr<'a> {
     TableRef(TableRef<'a>),
     FuncCall(FuncCall<'a>),
-    Join(Box<TableExpr<'a>>, Box<TableExpr<'a>>, Expr<'a>),
-    LeftJoin(Box<TableExpr<'a>>, Box<TableExpr<'a>>, Expr<'a>),
-    RightJoin(Box<TableExpr<'a>>, Box<TableExpr<'a>>, Expr<'a>),
-    FullJoin(Box<TableExpr<'a>>, Box<TableExpr<'a>>, Expr<'a>),
-    NaturalJoin(Box<TableExpr<'a>>, Box<TableExpr<'a>>),
-    NaturalLeftJoin(Box<TableExpr<'a>>, Box<TableExpr<'a>>),
-    NaturalRightJoin(Box<TableExpr<'a>>, Box<TableExpr<'a>>),
-    NaturalFullJoin(Box<TableExpr<'a>>, Box<TableExpr<'a>>),
-    CrossJoin(Box<TableExpr<'a>>, Box<TableExpr<'a>>),
+    Join(Box<Table<'a>>, JoinKind, Box<Table<'a>>, JoinCond<'a>),
+    CrossJoin(Box<Table<'a>>, Box<Table<'a>>),
+    Lateral(Box<TableExpr<'a>>),
+    /// `CROSS APPLY`, rendered as `CROSS JOIN LATERAL` unless the dialect
+    /// supports it.
+    CrossApply(Box<Table<'a>>, Box<Table<'a>>),
+    /// `OUTER APPLY`, rendered as `LEFT JOIN LATERAL ... ON TRUE` unless the
+    /// dialect supports it.
+    OuterApply(Box<Table<'a>>, Box<Table<'a>>),
+    /// `(VALUES ...)`, with names of its columns written after the alias of
+    /// the [`Table`], see [`values_table`](crate::ops::values_table).
+    Values(crate::clause::Values<'a>, Vec<Ident<'a>>),
+    /// SQL written verbatim, see [`table_raw`](crate::ops::table_raw).
+    Raw(#[cfg_attr(feature = "serde", serde(borrow))] std::borrow::Cow<'a, str>),
 
     SubQuery(Data<'a>),
 }
@@ -78,16 +88,21 @@ impl<'a> std::convert::From<Values<'a>> for TableExpr<'a> {
 fn test() {
     use crate::ops::as_field;
     use crate::ops::as_table;
+    use crate::ops::cross_apply;
     use crate::ops::cross_join;
     use crate::ops::eq;
     use crate::ops::full_join;
     use crate::ops::join;
+    use crate::ops::join_using;
+    use crate::ops::lateral;
     use crate::ops::left_join;
     use crate::ops::natural_full_join;
     use crate::ops::natural_join;
     use crate::ops::natural_left_join;
     use crate::ops::natural_right_join;
+    use crate::ops::outer_apply;
     use crate::ops::right_join;
+    use crate::func::func;
     use crate::stmt::select;
 
     let query = join("a", "b", eq(("a", "id"), ("b", "id")));
@@ -99,6 +114,9 @@ fn test() {
     let query = full_join("a", "b", eq(("a", "id"), ("b", "id")));
     assert_eq!(query.to_string(), "a FULL JOIN b ON a.id = b.id");
 
+    let query = join_using("a", "b", ["id", "kind"]);
+    assert_eq!(query.to_string(), "a JOIN b USING (id, kind)");
+
     let query = natural_join("a", "b");
     assert_eq!(query.to_string(), "a NATURAL JOIN b");
     let query = natural_left_join("a", "b");
@@ -111,6 +129,20 @@ fn test() {
     let query = cross_join("a", "b");
     assert_eq!(query.to_string(), "a CROSS JOIN b");
 
+    let query = cross_apply("a", "b");
+    assert_eq!(query.to_string(), "a CROSS JOIN LATERAL b");
+    let query = outer_apply("a", lateral("b"));
+    assert_eq!(query.to_string(), "a LEFT JOIN LATERAL b ON TRUE");
+
+    let query = select([("book", "id"), ("tag", "name")]).from(["book"]).from(as_table(
+        lateral(func("unnest", [("book", "tags")])),
+        "tag",
+    ));
+    assert_eq!(
+        query.to_string(),
+        "SELECT book.id, tag.name FROM book, LATERAL unnest(book.tags) AS tag"
+    );
+
     let query = select([("sub", "one"), ("sub", "two")]).from(as_table(
         select([as_field(1, "one"), as_field(2, "two")]),
         "sub",
@@ -120,3 +152,92 @@ fn test() {
         "SELECT sub.one, sub.two FROM (SELECT 1 AS one, 2 AS two) AS sub"
     );
 }
+
+#[test]
+#[cfg(test)]
+fn tablesample() {
+    use crate::dialect::Postgres;
+    use crate::item::SampleMethod;
+    use crate::ops::as_table;
+    use crate::stmt::select;
+    use crate::value::Value;
+
+    let query = select(["id"]).from(Table::from("event").tablesample(SampleMethod::Bernoulli, 10));
+    assert_eq!(
+        query.to_string(),
+        "SELECT id FROM event TABLESAMPLE BERNOULLI (10)"
+    );
+
+    let query = select(["id"]).from(
+        as_table("event", "e")
+            .tablesample(SampleMethod::System, 2.5)
+            .repeatable(42),
+    );
+    assert_eq!(
+        query.to_string(),
+        "SELECT id FROM event AS e TABLESAMPLE SYSTEM (2.5) REPEATABLE (42)"
+    );
+    let (sql, args) = query.to_sql(Postgres);
+    assert_eq!(
+        sql,
+        r#"SELECT "id" FROM "event" AS "e" TABLESAMPLE SYSTEM ($1) REPEATABLE ($2)"#
+    );
+    assert_eq!(args, [Value::Double(2.5), Value::Int(42)]);
+}
+
+#[test]
+#[cfg(test)]
+#[should_panic(expected = "`Table::repeatable` requires `TABLESAMPLE`")]
+fn repeatable_without_tablesample() {
+    Table::from("event").repeatable(42);
+}
+
+#[test]
+#[cfg(test)]
+fn values_table() {
+    use crate::ops::as_table;
+    use crate::ops::eq;
+    use crate::ops::values_table;
+    use crate::stmt::select;
+
+    let (a, b) = (&"a".to_string(), &"b".to_string());
+    let query = select([("u", "name"), ("t", "label")])
+        .from(as_table("user", "u"))
+        .join(
+            values_table([(1, a), (2, b)], "t", ["id", "label"]),
+            eq(("u", "kind"), ("t", "id")),
+        );
+    assert_eq!(
+        query.to_string(),
+        "SELECT u.name, t.label FROM user AS u \
+        JOIN (VALUES (1, 'a'), (2, 'b')) AS t(id, label) ON u.kind = t.id"
+    );
+
+    let query = select(["n"]).from(values_table([(1,), (2,)], "t", Vec::<&str>::new()));
+    assert_eq!(query.to_string(), "SELECT n FROM (VALUES (1), (2)) AS t");
+
+    // renaming keeps a single alias, still followed by the columns
+    let query = select(["n"]).from(values_table([(1,), (2,)], "t", ["n"]).alias("v"));
+    assert_eq!(query.to_string(), "SELECT n FROM (VALUES (1), (2)) AS v(n)");
+}
+
+#[test]
+#[cfg(test)]
+fn raw() {
+    use crate::ops::as_table;
+    use crate::ops::eq;
+    use crate::ops::table_raw;
+    use crate::stmt::select;
+
+    let query = select([("u", "name"), ("s", "n")])
+        .from(as_table("user", "u"))
+        .join(
+            as_table(table_raw("generate_series(1, 10) WITH ORDINALITY"), "s"),
+            eq(("s", "n"), ("u", "rank")),
+        );
+    assert_eq!(
+        query.to_string(),
+        "SELECT u.name, s.n FROM user AS u \
+        JOIN generate_series(1, 10) WITH ORDINALITY AS s ON s.n = u.rank"
+    );
+}
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::marker::PhantomData;
 
 macro_rules! into_value {
@@ -20,6 +21,20 @@ macro_rules! into_value {
                     }
                 }
             }
+
+            impl<'a> std::convert::From<&'a $from> for Value<'a> {
+                #[inline]
+                fn from(val: &'a $from) -> Self {
+                    Value::$into(*val)
+                }
+            }
+
+            impl<'a> std::convert::From<&'a Option<$from>> for Value<'a> {
+                #[inline]
+                fn from(val: &'a Option<$from>) -> Self {
+                    (*val).into()
+                }
+            }
         )+
     };
 }
@@ -30,7 +45,7 @@ macro_rules! into_borrowed_value {
             impl<'a> std::convert::From<&'a $from> for Value<'a> {
                 #[inline]
                 fn from(val: &'a $from) -> Self {
-                    Value::$into(val)
+                    Value::$into(Cow::Borrowed(val))
                 }
             }
 
@@ -44,11 +59,20 @@ macro_rules! into_borrowed_value {
                     }
                 }
             }
+
+            impl<'a> std::convert::From<&'a Option<$from>> for Value<'a> {
+                #[inline]
+                fn from(val: &'a Option<$from>) -> Self {
+                    val.as_ref().into()
+                }
+            }
         )+
     };
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 pub enum Value<'a> {
     Null(Null<'a>),
     Bool(bool),
@@ -63,8 +87,21 @@ pub enum Value<'a> {
     UInt(u32),
     BigUInt(u64),
 
-    Text(&'a str),
-    Bytes(&'a [u8]),
+    Real(f32),
+    Double(f64),
+    Decimal(Decimal<'a>),
+
+    Text(#[cfg_attr(feature = "serde", serde(borrow))] Cow<'a, str>),
+    Bytes(#[cfg_attr(feature = "serde", serde(borrow))] Cow<'a, [u8]>),
+    Json(Json<'a>),
+
+    #[cfg(feature = "use-chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "use-chrono")))]
+    Date(chrono::NaiveDate),
+
+    #[cfg(feature = "use-chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "use-chrono")))]
+    Timestamp(chrono::NaiveDateTime),
 
     #[cfg(feature = "use-chrono")]
     #[cfg_attr(docsrs, doc(cfg(feature = "use-chrono")))]
@@ -75,10 +112,48 @@ pub enum Value<'a> {
     Numeric(rust_decimal::Decimal),
 }
 
+// Floats are compared by their bits, so that `NaN` equals itself as `Eq`
+// requires, while `0.0` and `-0.0` differ.
+impl PartialEq for Value<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Value::Null(a), Value::Null(b)) => a == b,
+            (Value::Bool(a), Value::Bool(b)) => a == b,
+            (Value::TinyInt(a), Value::TinyInt(b)) => a == b,
+            (Value::SmallInt(a), Value::SmallInt(b)) => a == b,
+            (Value::Int(a), Value::Int(b)) => a == b,
+            (Value::BigInt(a), Value::BigInt(b)) => a == b,
+            (Value::TinyUInt(a), Value::TinyUInt(b)) => a == b,
+            (Value::SmallUInt(a), Value::SmallUInt(b)) => a == b,
+            (Value::UInt(a), Value::UInt(b)) => a == b,
+            (Value::BigUInt(a), Value::BigUInt(b)) => a == b,
+            (Value::Real(a), Value::Real(b)) => a.to_bits() == b.to_bits(),
+            (Value::Double(a), Value::Double(b)) => a.to_bits() == b.to_bits(),
+            (Value::Decimal(a), Value::Decimal(b)) => a == b,
+            (Value::Text(a), Value::Text(b)) => a == b,
+            (Value::Bytes(a), Value::Bytes(b)) => a == b,
+            (Value::Json(a), Value::Json(b)) => a == b,
+            #[cfg(feature = "use-chrono")]
+            (Value::Date(a), Value::Date(b)) => a == b,
+            #[cfg(feature = "use-chrono")]
+            (Value::Timestamp(a), Value::Timestamp(b)) => a == b,
+            #[cfg(feature = "use-chrono")]
+            (Value::DateTime(a), Value::DateTime(b)) => a == b,
+            #[cfg(feature = "use-decimal")]
+            (Value::Numeric(a), Value::Numeric(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Value<'_> {}
+
 crate::macros::gen_display!(Value<'_>);
 
 /// 👻
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 pub enum Null<'a> {
     Bool(PhantomData<bool>),
     TinyInt(PhantomData<i8>),
@@ -89,8 +164,20 @@ pub enum Null<'a> {
     SmallUInt(PhantomData<u16>),
     UInt(PhantomData<u32>),
     BigUInt(PhantomData<u64>),
+    Real(PhantomData<f32>),
+    Double(PhantomData<f64>),
+    Decimal(PhantomData<Decimal<'a>>),
     Text(PhantomData<&'a str>),
     Bytes(PhantomData<&'a [u8]>),
+    Json(PhantomData<Json<'a>>),
+
+    #[cfg(feature = "use-chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "use-chrono")))]
+    Date(PhantomData<chrono::NaiveDate>),
+
+    #[cfg(feature = "use-chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "use-chrono")))]
+    Timestamp(PhantomData<chrono::NaiveDateTime>),
 
     #[cfg(feature = "use-chrono")]
     #[cfg_attr(docsrs, doc(cfg(feature = "use-chrono")))]
@@ -111,10 +198,14 @@ into_value!(
     u16 => SmallUInt,
     u32 => UInt,
     u64 => BigUInt,
+    f32 => Real,
+    f64 => Double,
 );
 
 #[cfg(feature = "use-chrono")]
 into_value!(
+    chrono::NaiveDate => Date,
+    chrono::NaiveDateTime => Timestamp,
     chrono::DateTime<chrono::Utc> => DateTime,
 );
 
@@ -127,3 +218,224 @@ into_borrowed_value!(
     String => Text,
     Vec<u8> => Bytes,
 );
+
+/// A decimal number kept in its textual form, e.g. `12.50`.
+///
+/// Unlike `f64`, no precision is lost on the way to the database.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::update;
+/// use qians_xql::value::Decimal;
+///
+/// let query = update("book").set("price", Decimal::new("12.50"));
+/// assert_eq!(query.to_string(), "UPDATE book SET price = 12.50");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Cow<'a, str>"))]
+pub struct Decimal<'a>(pub(crate) Cow<'a, str>);
+
+impl<'a> Decimal<'a> {
+    /// Create a decimal from its textual form.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `val` is not a decimal number, i.e. digits with an optional
+    /// sign, fraction and exponent.
+    pub fn new(val: &'a str) -> Self {
+        match Decimal::try_from(val) {
+            Ok(val) => val,
+            Err(err) => panic!("`{val}` {err}"),
+        }
+    }
+}
+
+impl<'a> std::convert::TryFrom<&'a str> for Decimal<'a> {
+    type Error = &'static str;
+
+    fn try_from(val: &'a str) -> Result<Self, Self::Error> {
+        Decimal::try_from(Cow::Borrowed(val))
+    }
+}
+
+impl<'a> std::convert::TryFrom<Cow<'a, str>> for Decimal<'a> {
+    type Error = &'static str;
+
+    fn try_from(val: Cow<'a, str>) -> Result<Self, Self::Error> {
+        fn digits(val: &str) -> (usize, &str) {
+            let len = val.len() - val.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            (len, &val[len..])
+        }
+
+        let rest = val.strip_prefix(['+', '-']).unwrap_or(&val);
+        let (int, rest) = digits(rest);
+        let (frac, rest) = match rest.strip_prefix('.') {
+            Some(rest) => digits(rest),
+            None => (0, rest),
+        };
+        let valid = int + frac > 0
+            && match rest.strip_prefix(['e', 'E']) {
+                Some(rest) => {
+                    let (exp, rest) = digits(rest.strip_prefix(['+', '-']).unwrap_or(rest));
+                    exp > 0 && rest.is_empty()
+                }
+                None => rest.is_empty(),
+            };
+        if valid {
+            Ok(Decimal(val))
+        } else {
+            Err("is not a decimal number")
+        }
+    }
+}
+
+/// A JSON document, rendered as a string literal.
+///
+/// # Examples
+///
+/// ```
+/// use qians_xql::update;
+/// use qians_xql::value::Json;
+///
+/// let query = update("book").set("meta", Json::new(r#"{"lang": "en"}"#));
+/// assert_eq!(query.to_string(), r#"UPDATE book SET meta = '{"lang": "en"}'"#);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
+pub struct Json<'a>(#[cfg_attr(feature = "serde", serde(borrow))] pub(crate) Cow<'a, str>);
+
+impl<'a> Json<'a> {
+    /// Create a JSON value from its serialized form.
+    pub fn new(val: &'a str) -> Self {
+        Json(Cow::Borrowed(val))
+    }
+}
+
+impl<'a> std::convert::From<Decimal<'a>> for Value<'a> {
+    #[inline]
+    fn from(val: Decimal<'a>) -> Self {
+        Value::Decimal(val)
+    }
+}
+
+impl<'a> std::convert::From<Option<Decimal<'a>>> for Value<'a> {
+    #[inline]
+    fn from(val: Option<Decimal<'a>>) -> Self {
+        match val {
+            None => Value::Null(Null::Decimal(PhantomData)),
+            Some(val) => val.into(),
+        }
+    }
+}
+
+impl<'a> std::convert::From<Json<'a>> for Value<'a> {
+    #[inline]
+    fn from(val: Json<'a>) -> Self {
+        Value::Json(val)
+    }
+}
+
+impl<'a> std::convert::From<Option<Json<'a>>> for Value<'a> {
+    #[inline]
+    fn from(val: Option<Json<'a>>) -> Self {
+        match val {
+            None => Value::Null(Null::Json(PhantomData)),
+            Some(val) => val.into(),
+        }
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn display() {
+    assert_eq!(Value::from(true).to_string(), "true");
+    assert_eq!(Value::from(-64_i64).to_string(), "-64");
+    assert_eq!(Value::from(3.5).to_string(), "3.5");
+    assert_eq!(Value::from(3.0).to_string(), "3.0");
+    assert_eq!(Value::from(0.1_f32).to_string(), "0.1");
+    assert_eq!(Value::from(1e300).to_string(), "1e300");
+    assert_eq!(Value::from(f64::NAN).to_string(), "'NaN'");
+    assert_eq!(Value::from(f64::INFINITY).to_string(), "'Infinity'");
+    assert_eq!(Value::from(f32::NEG_INFINITY).to_string(), "'-Infinity'");
+    assert_eq!(Value::from(Decimal::new("-12.50")).to_string(), "-12.50");
+    assert_eq!(Value::from(&"it's".to_string()).to_string(), "'it''s'");
+    assert_eq!(
+        Value::from(&vec![0xde, 0xad, 0x01]).to_string(),
+        r"'\xdead01'"
+    );
+    assert_eq!(
+        Value::from(Json::new(r#"{"title": "Rock 'n' Roll"}"#)).to_string(),
+        r#"'{"title": "Rock ''n'' Roll"}'"#
+    );
+    assert_eq!(Value::from(None::<f64>).to_string(), "NULL");
+    assert_eq!(Value::from(None::<Json>).to_string(), "NULL");
+}
+
+#[test]
+#[cfg(test)]
+fn decimal() {
+    for val in ["0", "-1", "+1.", ".5", "12.50", "1e10", "1.5E-3"] {
+        assert_eq!(Decimal::try_from(val), Ok(Decimal::new(val)));
+    }
+    for val in [
+        "",
+        "-",
+        ".",
+        "e5",
+        "1e",
+        "1.2.3",
+        "1 ",
+        "1; DROP TABLE book",
+        "NaN",
+    ] {
+        assert!(Decimal::try_from(val).is_err(), "{val}");
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn float_eq() {
+    assert_eq!(Value::Double(f64::NAN), Value::Double(f64::NAN));
+    assert_eq!(Value::Real(f32::NAN), Value::Real(f32::NAN));
+    assert_eq!(Value::Double(1.5), Value::Double(1.5));
+    assert_ne!(Value::Double(0.0), Value::Double(-0.0));
+    assert_ne!(Value::Real(1.0), Value::Double(1.0));
+}
+
+#[test]
+#[cfg(test)]
+#[should_panic(expected = "`1,5` is not a decimal number")]
+fn decimal_invalid() {
+    Decimal::new("1,5");
+}
+
+#[test]
+#[cfg(test)]
+#[cfg(feature = "use-chrono")]
+fn chrono() {
+    use chrono::{NaiveDate, TimeZone, Utc};
+
+    let date = NaiveDate::from_ymd(2022, 2, 24);
+    assert_eq!(Value::from(date).to_string(), "'2022-02-24'");
+    assert_eq!(
+        Value::from(date.and_hms(13, 5, 9)).to_string(),
+        "'2022-02-24T13:05:09'"
+    );
+    assert_eq!(
+        Value::from(date.and_hms_micro(13, 5, 9, 250)).to_string(),
+        "'2022-02-24T13:05:09.000250'"
+    );
+    assert_eq!(
+        Value::from(Utc.ymd(2022, 2, 24).and_hms_milli(13, 5, 9, 120)).to_string(),
+        "'2022-02-24T13:05:09.120Z'"
+    );
+    assert_eq!(Value::from(None::<NaiveDate>).to_string(), "NULL");
+
+    let (_, args) = crate::stmt::update("book")
+        .set("published_on", date)
+        .to_sql(crate::dialect::Postgres);
+    assert_eq!(args, [Value::Date(date)]);
+}
@@ -11,9 +11,9 @@ struct Book {
 }
 
 #[allow(clippy::from_over_into)]
-impl<'a> std::convert::Into<xql::item::Row<'a>> for &'a Book {
-    fn into(self) -> xql::item::Row<'a> {
-        let row: [xql::expr::Expr<'a>; 4] = [
+impl<'a> std::convert::Into<qians_xql::item::Row<'a>> for &'a Book {
+    fn into(self) -> qians_xql::item::Row<'a> {
+        let row: [qians_xql::expr::Expr<'a>; 4] = [
             self.id.into(),
             (&self.title).into(),
             (&self.author).into(),
@@ -24,13 +24,13 @@ impl<'a> std::convert::Into<xql::item::Row<'a>> for &'a Book {
 }
 
 #[allow(clippy::from_over_into)]
-impl<'a> std::convert::Into<xql::clause::Set<'a>> for &'a Book {
-    fn into(self) -> xql::clause::Set<'a> {
-        let sets: [(xql::item::Ident<'a>, xql::expr::Expr<'a>); 4] = [
-            (Book::id.into(), self.id.into()),
-            (Book::title.into(), (&self.title).into()),
-            (Book::author.into(), (&self.author).into()),
-            (Book::year.into(), self.year.into()),
+impl<'a> std::convert::Into<qians_xql::clause::Set<'a>> for &'a Book {
+    fn into(self) -> qians_xql::clause::Set<'a> {
+        let sets: [(qians_xql::item::Ident<'a>, qians_xql::expr::Expr<'a>); 4] = [
+            (Book::id.try_into().unwrap(), self.id.into()),
+            (Book::title.try_into().unwrap(), (&self.title).into()),
+            (Book::author.try_into().unwrap(), (&self.author).into()),
+            (Book::year.try_into().unwrap(), self.year.into()),
         ];
         sets.into()
     }
@@ -39,9 +39,9 @@ impl<'a> std::convert::Into<xql::clause::Set<'a>> for &'a Book {
 #[test]
 fn select() {
     let expect = "SELECT book.id, book.title, book.author, book.year FROM book";
-    let query = xql::select([Book::id, Book::title, Book::author, Book::year]).from(Book);
+    let query = qians_xql::select([Book::id, Book::title, Book::author, Book::year]).from(Book);
     assert_eq!(query.to_string(), expect);
-    let query = xql::select(Book::columns()).from(Book::table());
+    let query = qians_xql::select(Book::columns()).from(Book::table());
     assert_eq!(query.to_string(), expect);
 }
 
@@ -55,11 +55,12 @@ fn insert() {
     };
 
     let expect = "INSERT INTO book(id, title, author, year) VALUES (1, 'Dune', 'Frank Herbert', 1965) RETURNING book.id, book.title, book.author, book.year";
-    let query = xql::insert(Book, [Book::id, Book::title, Book::author, Book::year])
+    let query = qians_xql::insert(Book, ["id", "title", "author", "year"])
         .values([&book])
         .returning([Book::id, Book::title, Book::author, Book::year]);
     assert_eq!(query.to_string(), expect);
-    let query = xql::insert(Book::table(), Book::columns())
+    let columns = Book::columns().map(|col| qians_xql::item::Ident::try_from(col).unwrap());
+    let query = qians_xql::insert(Book::table(), columns)
         .values([&book])
         .returning(Book::columns());
     assert_eq!(query.to_string(), expect);
@@ -75,14 +76,14 @@ fn update() {
     };
 
     let expect = "UPDATE book SET id = 1, title = 'Dune', author = 'Frank Herbert', year = 1965 RETURNING book.id, book.title, book.author, book.year";
-    let query = xql::update(Book).set_values(&book).returning([
+    let query = qians_xql::update(Book).set_values(&book).returning([
         Book::id,
         Book::title,
         Book::author,
         Book::year,
     ]);
     assert_eq!(query.to_string(), expect);
-    let query = xql::update(Book::table())
+    let query = qians_xql::update(Book::table())
         .set_values(&book)
         .returning(Book::columns());
     assert_eq!(query.to_string(), expect);
@@ -91,8 +92,100 @@ fn update() {
 #[test]
 fn delete() {
     let expect = "DELETE FROM book";
-    let query = xql::delete(Book);
+    let query = qians_xql::delete(Book);
     assert_eq!(query.to_string(), expect);
-    let query = xql::delete(Book::table());
+    let query = qians_xql::delete(Book::table());
     assert_eq!(query.to_string(), expect);
 }
+
+#[derive(qians_xql::Columns)]
+struct Author {
+    id: i64,
+    #[column(rename = "full_name")]
+    name: String,
+    #[column(skip)]
+    cache: Vec<u8>,
+    bio: Option<String>,
+    rating: Option<f64>,
+}
+
+#[derive(qians_xql::Columns)]
+struct Tag {
+    #[column(rename = "label")]
+    name: String,
+    #[column(skip, rename = "ignored")]
+    count: u32,
+    hidden: bool,
+}
+
+#[test]
+fn columns() {
+    use qians_xql::schema::Columns;
+
+    assert_eq!(
+        Author::column_names().map(|name| name.0),
+        ["id", "full_name", "bio", "rating"]
+    );
+    let authors = [
+        Author {
+            id: 1,
+            name: "Frank Herbert".to_string(),
+            cache: vec![1, 2],
+            bio: None,
+            rating: Some(4.5),
+        },
+        Author {
+            id: 2,
+            name: "Ursula K. Le Guin".to_string(),
+            cache: Vec::new(),
+            bio: Some("Earthsea".to_string()),
+            rating: None,
+        },
+    ];
+    let query = qians_xql::insert("author", Author::column_names())
+        .values(authors.iter().map(Columns::as_row).collect::<Vec<_>>());
+    assert_eq!(
+        query.to_string(),
+        "INSERT INTO author(id, full_name, bio, rating) VALUES \
+        (1, 'Frank Herbert', NULL, 4.5), (2, 'Ursula K. Le Guin', 'Earthsea', NULL)"
+    );
+
+    let tag = Tag {
+        name: "scifi".to_string(),
+        count: 3,
+        hidden: false,
+    };
+    assert_eq!(Tag::column_names().map(|name| name.0), ["label", "hidden"]);
+    let query = qians_xql::insert("tag", Tag::column_names()).values([tag.as_row()]);
+    assert_eq!(
+        query.to_string(),
+        "INSERT INTO tag(label, hidden) VALUES ('scifi', false)"
+    );
+}
+
+#[test]
+fn values_from() {
+    let tags = vec![
+        Tag {
+            name: "scifi".to_string(),
+            count: 3,
+            hidden: false,
+        },
+        Tag {
+            name: "fantasy".to_string(),
+            count: 1,
+            hidden: true,
+        },
+    ];
+    let query = qians_xql::insert_into("tag").values_from(&tags);
+    assert_eq!(
+        query.to_string(),
+        "INSERT INTO tag(label, hidden) VALUES ('scifi', false), ('fantasy', true)"
+    );
+}
+
+#[test]
+#[should_panic(expected = "`Insert::values_from` requires at least one row")]
+fn values_from_empty() {
+    qians_xql::insert_into("tag").values_from(&Vec::<Tag>::new());
+}
@@ -0,0 +1,31 @@
+use rusqlite::Connection;
+
+use qians_xql::exec::rusqlite::ConnectionExt;
+use qians_xql::ops::eq;
+use qians_xql::{insert, select};
+
+#[test]
+fn test() -> Result<(), Box<dyn std::error::Error>> {
+    let conn = Connection::open_in_memory()?;
+    conn.execute_batch("CREATE TABLE book (id INTEGER, title TEXT, cover BLOB)")?;
+
+    let dune = "Dune".to_string();
+    let emma = "Emma".to_string();
+    let cover = vec![0xde, 0xad];
+    let query =
+        insert("book", ["id", "title", "cover"]).values([(1, &dune, &cover), (2, &emma, &cover)]);
+    assert_eq!(conn.execute_xql(query)?, 2);
+
+    let query = select(["id", "title", "cover"])
+        .from("book")
+        .filter(eq("title", &emma));
+    let rows = conn.query_xql(query, |row| {
+        Ok((
+            row.get::<_, i32>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, Vec<u8>>(2)?,
+        ))
+    })?;
+    assert_eq!(rows, [(2, emma, cover)]);
+    Ok(())
+}
@@ -0,0 +1,70 @@
+//! `select!` must only rely on `$crate` paths, whatever is in scope at the call
+//! site.
+
+#![no_implicit_prelude]
+#![allow(dead_code)]
+
+use ::std::string::ToString;
+
+fn select() {}
+fn desc() {}
+struct Field;
+struct Table;
+struct Expr;
+struct Order;
+mod ops {}
+mod item {}
+
+#[test]
+fn fields() {
+    ::std::assert_eq!(
+        ::qians_xql::select!(id, book.title, (1), ::qians_xql::func::count("id")),
+        ::qians_xql::select((
+            "id",
+            ("book", "title"),
+            1,
+            ::qians_xql::func::count("id"),
+        )),
+    );
+}
+
+#[test]
+fn sections() {
+    use ::qians_xql::ops::{and, as_field, eq, gt, like};
+
+    let author = "J%".to_string();
+    let year = 2000;
+    let query = ::qians_xql::select!(
+        book.id, as_field(::qians_xql::func::count("id"), "total")
+        FROM book, author
+        WHERE and(eq(("book", "author_id"), ("author", "id")), like(("author", "name"), &author))
+        GROUP BY book.id, (year)
+        ORDER BY total DESC, book.id ASC, (gt("year", year))
+    );
+    ::std::assert_eq!(
+        query.to_string(),
+        "SELECT book.id, COUNT(id) AS total FROM book, author \
+        WHERE book.author_id = author.id AND author.name LIKE 'J%' \
+        GROUP BY book.id, 2000 ORDER BY total DESC, book.id ASC, year > 2000"
+    );
+}
+
+#[test]
+fn partial() {
+    ::std::assert_eq!(
+        ::qians_xql::select!(id FROM book).to_string(),
+        "SELECT id FROM book"
+    );
+    ::std::assert_eq!(
+        ::qians_xql::select!(id FROM book ORDER BY id DESC).to_string(),
+        "SELECT id FROM book ORDER BY id DESC"
+    );
+    ::std::assert_eq!(
+        ::qians_xql::select!(id FROM book WHERE ::qians_xql::ops::gt("id", 1)).to_string(),
+        "SELECT id FROM book WHERE id > 1"
+    );
+    ::std::assert_eq!(
+        ::qians_xql::select!(1 WHERE true).to_string(),
+        "SELECT 1 WHERE true"
+    );
+}